@@ -46,6 +46,11 @@ pub struct CLI {
     #[arg(long, short = 'U', verbatim_doc_comment)]
     pub input_unsigned_integers: bool,
 
+    /// keep numbers that cannot be represented exactly as verbatim text;
+    /// for "yaml", "json", and "cbor" formats
+    #[arg(long, short = 'N', verbatim_doc_comment)]
+    pub input_lossless_numbers: bool,
+
     /// accept legacy syntax;
     /// for "yaml" format
     #[arg(long, short = 'L', verbatim_doc_comment)]
@@ -56,6 +61,11 @@ pub struct CLI {
     #[arg(long, short = 'B', verbatim_doc_comment)]
     pub input_base64: bool,
 
+    /// JSONPath query;
+    /// outputs a list of the matched values
+    #[arg(long, short = 'Q', verbatim_doc_comment)]
+    pub query: Option<String>,
+
     /// output file path;
     /// when absent will write to stdout
     #[arg(long = "output", short = 'o', verbatim_doc_comment)]
@@ -80,6 +90,11 @@ pub struct CLI {
     #[arg(long = "base64", short = 'b', verbatim_doc_comment)]
     pub output_base64: bool,
 
+    /// errors format;
+    /// "sarif" is SARIF 2.1.0 in JSON
+    #[arg(long, short = 'E', verbatim_doc_comment, default_value_t = ErrorsFormat::Depict, value_enum)]
+    pub errors_format: ErrorsFormat,
+
     /// suppress console output
     #[arg(long, short = 'q', verbatim_doc_comment)]
     pub quiet: bool,
@@ -142,6 +157,28 @@ impl ToString for OutputFormat {
     }
 }
 
+//
+// ErrorsFormat
+//
+
+#[derive(Clone, PartialEq, ValueEnum)]
+pub enum ErrorsFormat {
+    Depict,
+    YAML,
+    JSON,
+    XJSON,
+    CBOR,
+    #[value(name = "messagepack")]
+    MessagePack,
+    SARIF,
+}
+
+impl ToString for ErrorsFormat {
+    fn to_string(&self) -> String {
+        self.to_possible_value().expect("to_possible_value").get_name().into()
+    }
+}
+
 //
 // SubCommands
 //
@@ -2,7 +2,7 @@ use super::{cli::*, errors::*};
 
 use {
     clap::*,
-    compris::{annotate::*, normal::*, ser::*, *},
+    compris::{annotate::*, normal::*, query::*},
     kutil::cli::{depict::*, run::*},
     read_url::*,
     std::{
@@ -15,8 +15,67 @@ use {
 impl CLI {
     /// Convert.
     pub fn convert(&self) -> Result<(), MainError> {
-        let (content, input_format) = self.read::<WithAnnotations>()?;
-        self.write(content, input_format)
+        let (mut reader, input_url_extension) = self.get_reader()?;
+        let input_format = self.get_input_format(&input_url_extension)?;
+        let parser = self.get_parser(input_format.clone());
+
+        if let Some(query) = &self.query {
+            return self.query(query, &parser, &mut reader, &input_format);
+        }
+
+        match self.get_output_format(&input_format) {
+            Some(output_format) => {
+                // Stream events directly into the writer
+                let mut writer = self.get_writer(&Some(output_format.clone()));
+                let serializer = compris::ser::Serializer::new(output_format.clone())
+                    .with_pretty(!self.output_plain)
+                    .with_base64(self.output_base64);
+                let serialization_mode = compris::ser::SerializationMode::for_format(&output_format);
+                Ok(serializer.transcode(&parser, &mut reader, serialization_mode.as_ref(), &mut writer)?)
+            }
+
+            None => {
+                let content: Variant<WithAnnotations> = parser.parse_reader(&mut reader)?;
+                let mut writer = self.get_writer(&None);
+                Ok(content.write_default_depiction(&mut writer)?)
+            }
+        }
+    }
+
+    fn query(
+        &self,
+        query: &str,
+        parser: &compris::parse::Parser,
+        reader: &mut ReadRef,
+        input_format: &compris::Format,
+    ) -> Result<(), MainError> {
+        let query: Query = query.parse()?;
+        let content: Variant<WithAnnotations> = parser.parse_reader(reader)?;
+        let matches = content.query(&query);
+        tracing::info!("query matches: {}", matches.len());
+
+        match self.get_output_format(input_format) {
+            Some(output_format) => {
+                let matches: Variant<WithAnnotations> =
+                    List::from_iter(matches.into_iter().map(|found| found.variant.clone())).into();
+                let mut writer = self.get_writer(&Some(output_format.clone()));
+                let serializer = compris::ser::Serializer::new(output_format.clone())
+                    .with_pretty(!self.output_plain)
+                    .with_base64(self.output_base64);
+                Ok(match compris::ser::SerializationMode::for_format(&output_format) {
+                    Some(serialization_mode) => serializer.write_modal(&matches, &serialization_mode, &mut writer)?,
+                    None => serializer.write(&matches, &mut writer)?,
+                })
+            }
+
+            None => {
+                let mut writer = self.get_writer(&None);
+                for found in matches {
+                    found.write_default_depiction(&mut writer)?;
+                }
+                Ok(())
+            }
+        }
     }
 
     fn get_reader(&self) -> Result<(ReadRef, Option<String>), MainError> {
@@ -87,23 +146,14 @@ impl CLI {
         input_format.parse()
     }
 
-    fn read<AnnotatedT>(&self) -> Result<(Variant<AnnotatedT>, Format), MainError>
-    where
-        AnnotatedT: Annotated + Clone + Default,
-    {
-        let (mut reader, input_url_extension) = self.get_reader()?;
-        let input_format = self.get_input_format(&input_url_extension)?;
-
-        Ok((
-            compris::parse::Parser::new(input_format.clone())
-                .with_try_integers(self.input_integers)
-                .with_try_unsigned_integers(self.input_unsigned_integers)
-                .with_allow_legacy_words(self.input_legacy)
-                .with_allow_legacy_types(self.input_legacy)
-                .with_base64(self.input_base64)
-                .parse_reader(&mut reader)?,
-            input_format,
-        ))
+    fn get_parser(&self, input_format: compris::Format) -> compris::parse::Parser {
+        compris::parse::Parser::new(input_format)
+            .with_try_integers(self.input_integers)
+            .with_try_unsigned_integers(self.input_unsigned_integers)
+            .with_lossless_numbers(self.input_lossless_numbers)
+            .with_allow_legacy_words(self.input_legacy)
+            .with_allow_legacy_types(self.input_legacy)
+            .with_base64(self.input_base64)
     }
 
     fn get_output_format(&self, input_format: &compris::Format) -> Option<compris::Format> {
@@ -150,50 +200,4 @@ impl CLI {
             }
         }
     }
-
-    fn write<AnnotatedT>(&self, content: Variant<AnnotatedT>, input_format: compris::Format) -> Result<(), MainError>
-    where
-        AnnotatedT: Annotated + Clone + Default,
-    {
-        let output_format = self.get_output_format(&input_format);
-        let mut writer = self.get_writer(&output_format);
-
-        match output_format {
-            Some(output_format) => {
-                let serializer = compris::ser::Serializer::new(output_format.clone())
-                    .with_pretty(!self.output_plain)
-                    .with_base64(self.output_base64);
-
-                match output_format {
-                    compris::Format::YAML => {
-                        let serialization_mode = compris::ser::SerializationMode::for_yaml();
-                        let content = content.modal(&serialization_mode, &serializer);
-                        serializer.write(&content, &mut writer)?;
-                    }
-
-                    compris::Format::JSON => {
-                        let serialization_mode = compris::ser::SerializationMode::for_json();
-                        let content = content.modal(&serialization_mode, &serializer);
-                        serializer.write(&content, &mut writer)?;
-                    }
-
-                    compris::Format::XJSON => {
-                        let serialization_mode = compris::ser::SerializationMode::for_xjson();
-                        let content = content.modal(&serialization_mode, &serializer);
-                        serializer.write(&content, &mut writer)?;
-                    }
-
-                    _ => {
-                        serializer.write(&content, &mut writer)?;
-                    }
-                }
-            }
-
-            None => {
-                content.write_default_depiction(&mut writer)?;
-            }
-        }
-
-        Ok(())
-    }
 }
@@ -1,5 +1,5 @@
 use {
-    compris::{parse::*, ser::*, *},
+    compris::{parse::*, query::*, ser::*, *},
     kutil::cli::run::*,
     read_url::*,
     std::io,
@@ -33,11 +33,24 @@ pub enum MainError {
     #[error("write: {0}")]
     Write(#[from] SerializeError),
 
+    /// Query.
+    #[error("query: {0}")]
+    Query(#[from] QueryError),
+
     /// URL.
     #[error("URL: {0}")]
     Url(#[from] UrlError),
 }
 
+impl From<TranscodeError> for MainError {
+    fn from(transcode_error: TranscodeError) -> Self {
+        match transcode_error {
+            TranscodeError::Parse(parse_error) => Self::Read(parse_error),
+            TranscodeError::Serialize(serialize_error) => Self::Write(serialize_error),
+        }
+    }
+}
+
 impl RunError for MainError {
     fn handle(&self) -> (bool, u8) {
         (
@@ -31,6 +31,7 @@ J'ai compris!
 mod cli;
 mod convert;
 mod errors;
+mod report;
 mod run;
 
 use run::*;
@@ -0,0 +1,70 @@
+use super::{cli::*, errors::*};
+
+use {
+    compris::{annotate::*, normal::*, ser::*},
+    kutil::{cli::run::*, std::immutable::*},
+    std::{borrow::*, io},
+};
+
+impl CLI {
+    /// Report an error in the errors format.
+    ///
+    /// Returns the error to be handled by the runner (which will be an [ExitError] if we reported
+    /// it ourselves).
+    pub fn report(&self, error: MainError) -> MainError {
+        if (self.errors_format == ErrorsFormat::Depict) || matches!(error, MainError::Exit(_)) {
+            return error;
+        }
+
+        let report = Report { error: &error, source: self.input_path_or_url.clone().map(|source| source.into()) };
+
+        let (format, diagnostics): (compris::Format, Variant<WithoutAnnotations>) = match self.errors_format {
+            ErrorsFormat::SARIF => {
+                (compris::Format::JSON, [report].to_sarif_variant("compris", Some(env!("CARGO_PKG_VERSION"))))
+            }
+
+            _ => (self.errors_format.to_string().parse().expect("format"), [report].to_diagnostics_variant()),
+        };
+
+        let mut writer: Box<dyn io::Write> = if format.is_binary() && !self.output_base64 {
+            Box::new(io::stderr())
+        } else {
+            Box::new(anstream::stderr())
+        };
+
+        let serializer = Serializer::new(format).with_pretty(!self.output_plain).with_base64(self.output_base64);
+        match serializer.write(&diagnostics, &mut writer) {
+            Ok(_) => ExitError::new(1, None).into(),
+            Err(error) => error.into(),
+        }
+    }
+}
+
+//
+// Report
+//
+
+struct Report<'own> {
+    error: &'own MainError,
+    source: Option<ByteString>,
+}
+
+impl<'own> ReportDiagnostic for Report<'own> {
+    fn diagnostic_message(&self) -> String {
+        self.error.to_string()
+    }
+
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>> {
+        let mut annotations = match self.error {
+            MainError::Read(error) => error.diagnostic_annotations().map(|annotations| annotations.into_owned()),
+            _ => None,
+        }
+        .unwrap_or_default();
+
+        if annotations.source.is_none() {
+            annotations.source = self.source.clone();
+        }
+
+        annotations.has_some().then_some(Cow::Owned(annotations))
+    }
+}
@@ -11,7 +11,7 @@ pub fn run() -> Result<(), MainError> {
     }
 
     match &cli.subcommand {
-        None => cli.convert()?,
+        None => cli.convert().map_err(|error| cli.report(error))?,
         Some(SubCommand::Version(version)) => version.run::<CLI>(),
         Some(SubCommand::Completion(completion)) => completion.run::<CLI>(),
         Some(SubCommand::Manual(manual)) => manual.run::<CLI>()?,
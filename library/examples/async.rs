@@ -0,0 +1,135 @@
+mod utils;
+
+use {
+    compris::{annotate::*, normal::*, parse::*, ser::*, *},
+    kutil::cli::depict::*,
+    std::{
+        io,
+        pin::*,
+        task::{Context, Poll},
+    },
+    tokio::io::{AsyncRead, ReadBuf},
+};
+
+// Valid and invalid JSON
+const CONFORMANCE: &[&str] = &[
+    r#"{"a": [1, -2.5e3, "x\u00e9\ud83d\ude00\n", null, true, false], "b": {}}"#,
+    "{\r\n  \"a\":\n\t[\"é😀\", {\"b\": []}]\r}  \n",
+    r#""plain""#,
+    "123456789012345678901234567890",
+    "1e400",
+    "",
+    "  ",
+    "1 2",
+    "01",
+    "-",
+    "1.",
+    "1x",
+    "tru",
+    "truex",
+    "[1,]",
+    "[1,,2]",
+    "[1 2]",
+    "[1}",
+    r#"{"a" 1}"#,
+    r#"{"a":1,}"#,
+    "{,}",
+    "{:}",
+    "{]",
+    "[:]",
+    "[,]",
+    "{1: 2}",
+    ":",
+    r#"["ab"#,
+    "\"a\nb\"",
+    r#"["\x"]"#,
+    r#"["\ud800"]"#,
+    "\u{feff}1",
+    "[/* comment */]",
+];
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn main() {
+    let json = r#"{
+  "key1": [1, 2.5, "three", null, true],
+  "key2": {"nested": "value é 😀"}
+}"#;
+
+    // Parse asynchronously while streaming
+
+    let variant = with_annotations!(
+        Parser::new(Format::JSON)
+            .with_source("json".into())
+            .with_try_integers(true)
+            .parse_async_reader(&mut json.as_bytes())
+            .await
+            .expect("parse")
+    );
+
+    utils::heading("from JSON", true);
+    variant
+        .annotated_depict()
+        .print_depiction(&DEFAULT_DEPICTION_CONTEXT.child().with_format(DepictionFormat::Verbose));
+
+    // Round trip through the binary formats
+
+    for format in [Format::CBOR, Format::MessagePack] {
+        let mut buffer = Vec::default();
+        Serializer::new(format).write_async(&variant, &mut buffer).await.expect("write");
+
+        let variant: Variant<WithoutAnnotations> =
+            Parser::new(format).parse_async_reader(&mut buffer.as_slice()).await.expect("parse");
+
+        utils::heading(&format!("from {}", format), false);
+        variant.print_depiction(&DEFAULT_DEPICTION_CONTEXT);
+    }
+
+    // Conformance with the synchronous parser (one byte at a time, to cross buffer boundaries)
+
+    utils::heading("conformance", false);
+    let deep = "[".repeat(200);
+    for json in CONFORMANCE.iter().copied().chain([deep.as_str()]) {
+        let parser = Parser::new(Format::JSON).with_source("json".into()).with_try_integers(true);
+        let expected = outcome(parser.parse_string(json));
+        let actual = outcome(parser.parse_async_reader(&mut Trickle(json.as_bytes())).await);
+        assert_eq!(actual, expected, "{:?}", json);
+
+        let json: String = json.chars().take(20).collect();
+        match actual {
+            Ok(_) => println!("{:?}: ok", json),
+            Err(error) => println!("{:?}: {}", json, error.chars().take(100).collect::<String>()),
+        }
+    }
+}
+
+fn outcome(result: Result<Variant<WithAnnotations>, ParseError>) -> Result<String, String> {
+    match result {
+        Ok(variant) => Ok(variant
+            .annotated_depict()
+            .to_depiction(&DEFAULT_DEPICTION_CONTEXT.child().with_format(DepictionFormat::Verbose))
+            .expect("depict")),
+
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+//
+// Trickle
+//
+
+// Reads one byte at a time
+struct Trickle<'own>(&'own [u8]);
+
+impl AsyncRead for Trickle<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _context: &mut Context<'_>,
+        buffer: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if let Some((first, rest)) = self.0.split_first() {
+            buffer.put_slice(&[*first]);
+            self.0 = rest;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
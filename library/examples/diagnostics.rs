@@ -0,0 +1,65 @@
+mod utils;
+
+use {
+    compris::{annotate::*, normal::*, parse::*, path::*, resolve::*, *},
+    kutil::cli::depict::*,
+    std::collections::*,
+};
+
+#[derive(Debug, Default, Resolve)]
+#[allow(dead_code)]
+struct Service {
+    #[resolve(required)]
+    image: String,
+
+    #[resolve]
+    replicas: u32,
+}
+
+pub fn main() {
+    let yaml = r#"web:
+  image: nginx:1.25
+  replicas: many
+db:
+  replicas: 1
+"#;
+
+    // Diagnostics need the source texts, which the parser can register for us
+    // (a map from source names to texts would work, too)
+    let registry = SourceRegistry::default().into_shared();
+
+    let variant: Variant<WithAnnotations> = Parser::new(Format::YAML)
+        .with_source("services.yaml".into())
+        .with_source_registry(registry.clone())
+        .parse_string(yaml)
+        .expect("parse");
+
+    let sources = registry.lock().expect("lock");
+
+    let mut errors = ResolveErrors::default();
+    let _: Option<BTreeMap<String, Service>> = variant.clone().resolve_with_errors(&mut errors).expect("errors");
+
+    // Every error is shown with an excerpt of its source, with carets under its span
+
+    utils::heading("diagnostics", true);
+    Diagnostics::new(&errors, &*sources).print_default_depiction();
+
+    // A diagnostic can also have secondary labels
+
+    let web_image = variant.get_path(&"web.image".parse::<PathRepresentation>().expect("path")).expect("web.image");
+    let db_image = variant.get_path(&"db".parse::<PathRepresentation>().expect("path")).expect("db");
+
+    let error =
+        AnnotatedMessageError::<WithAnnotations>::new("missing \"image\"".into()).with_annotations_from(db_image);
+
+    utils::heading("diagnostic with label", false);
+    Diagnostic::new(&error, &*sources)
+        .with_label(web_image.annotations().cloned().unwrap_or_default(), "compare with this service".into())
+        .print_default_depiction();
+
+    // Errors can also be exported as a variant, which can be serialized into any format
+    // (there is also a SARIF profile: to_sarif_variant)
+
+    utils::heading("exported", false);
+    errors.to_diagnostics_variant::<WithoutAnnotations>().print_default_depiction();
+}
@@ -0,0 +1,66 @@
+mod utils;
+
+use {
+    compris::{annotate::*, diff::*, normal::*, parse::*, *},
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let yaml = r#"name: web
+replicas: 2
+timeout: 30
+ports:
+- name: http
+  port: 80
+- name: https
+  port: 443
+labels:
+  tier: frontend
+  owner: ops
+"#;
+
+    // Same document, from a different format and with some changes
+    let json = r#"{
+  "name": "web",
+  "replicas": 3,
+  "timeout": 30.0,
+  "ports": [
+    { "name": "https", "port": 8443 },
+    { "name": "http", "port": 80 },
+    { "name": "metrics", "port": 9090 }
+  ],
+  "labels": { "tier": "frontend" }
+}"#;
+
+    let old: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("old.yaml".into()).parse_string(yaml).expect("parse");
+    let new: Variant<WithAnnotations> =
+        Parser::new(Format::JSON).with_source("new.json".into()).parse_string(json).expect("parse");
+
+    // By default list items are matched by index and numbers must have the same type
+
+    utils::heading("default", true);
+    old.diff(&new, &Default::default()).print_default_depiction();
+
+    // JSON numbers are floats, so compare numbers by value; also match ports by their "name"
+
+    let options =
+        DiffOptions::default().with_semantic_numbers(true).with_list_matching(ListMatching::ByKey("name".into()));
+    let diff = old.diff(&new, &options);
+
+    utils::heading("semantic numbers, ports by name", false);
+    diff.print_default_depiction();
+
+    // Each change has the annotations of both sides
+
+    utils::heading("locations", false);
+    for change in &diff {
+        let location =
+            |annotations: Option<&Annotations>| match annotations.and_then(|annotations| annotations.span.as_ref()) {
+                Some(span) => span.to_string(),
+                None => "-".into(),
+            };
+
+        println!("{}: {} ⇒ {}", change.path(), location(change.old_annotations()), location(change.new_annotations()));
+    }
+}
@@ -0,0 +1,57 @@
+mod utils;
+
+use {
+    compris::{
+        annotate::*,
+        normal::*,
+        parse::{builder::*, *},
+        *,
+    },
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let yaml = r#"name: Linus
+groups: &groups [admins, users]
+previous_groups: *groups"#;
+
+    // Pull events one at a time
+
+    utils::heading("events", true);
+    let parser = Parser::new(Format::YAML).with_source("yaml".into());
+    let mut reader = yaml.as_bytes();
+    let mut depth = 0;
+    for event in parser.events::<_, WithAnnotations>(&mut reader).expect("events") {
+        let event = event.expect("event");
+        if matches!(event, Event::End) {
+            depth -= 1;
+        }
+
+        print!("{}", "  ".repeat(depth));
+        match &event {
+            Event::StartMap { span, .. } => {
+                println!("start map {}", span.as_ref().map(|span| span.to_string()).unwrap_or_default())
+            }
+            Event::StartList { span, .. } => {
+                println!("start list {}", span.as_ref().map(|span| span.to_string()).unwrap_or_default())
+            }
+            Event::Key(key) => println!("key {}", key),
+            Event::Scalar(scalar) => println!("scalar {}", scalar),
+            Event::End => println!("end"),
+        }
+
+        if event.is_start() {
+            depth += 1;
+        }
+    }
+
+    // VariantBuilder is just one consumer of events
+
+    let mut reader = yaml.as_bytes();
+    let mut variant_builder = VariantBuilder::new(Some("yaml".into()));
+    variant_builder.add_events(parser.events(&mut reader).expect("events"), None).expect("build");
+    let variant: Variant<WithAnnotations> = variant_builder.finalize();
+
+    utils::heading("built", false);
+    variant.annotated_depict().print_depiction(&DEFAULT_DEPICTION_CONTEXT);
+}
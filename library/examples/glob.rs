@@ -0,0 +1,73 @@
+mod utils;
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, path::*, *},
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let yaml = r#"services:
+  web:
+    image: nginx:1.25
+    env:
+      password: hunter2
+  db:
+    image: postgres:16
+    env:
+      user: admin
+      password: swordfish
+items:
+- name: first
+  tags: [a, b]
+- name: second
+"#;
+
+    let mut variant: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("yaml".into()).parse_string(yaml).expect("parse");
+
+    // Patterns use the path syntax with wildcards: "*" for any map key, "[*]" for any list index,
+    // and "**" for any number of segments
+
+    for pattern in ["services.*.image", "**.password", "items[*].name", "**[1]"] {
+        utils::heading(pattern, pattern == "services.*.image");
+        let pattern: PathPattern = pattern.parse().expect("pattern");
+        for (path, found) in variant.glob(&pattern) {
+            println!("{}: {}", path, found);
+        }
+    }
+
+    // A pattern can be matched against a path directly
+
+    let pattern: PathPattern = "**.env.*".parse().expect("pattern");
+    let path: PathRepresentation = "services.db.env.user".parse().expect("path");
+    utils::heading("does it match?", false);
+    println!("{} {} {}", pattern, path, pattern.matches(&path));
+
+    // Apply a function at every match, e.g. to redact secrets
+
+    let pattern: PathPattern = "**.password".parse().expect("pattern");
+    let count = variant
+        .apply_glob(&pattern, |found: &mut Variant<_>, _path: &PathRepresentation| {
+            *found = normal!("********");
+            Ok::<_, AnnotatedMessageError<WithAnnotations>>(())
+        })
+        .expect("apply_glob");
+
+    utils::heading(&format!("redacted {}", count), false);
+    variant.print_default_depiction();
+
+    // Errors abort and are annotated with the location of the matched variant (useful for policy
+    // checks)
+
+    let pattern: PathPattern = "services.*.image".parse().expect("pattern");
+    let error = variant
+        .apply_glob(&pattern, |found: &mut Variant<_>, _path: &PathRepresentation| match found {
+            Variant::Text(text) if !text.inner.contains(':') => Ok(()),
+            _ => Err(AnnotatedMessageError::<WithAnnotations>::new(format!("image must not be tagged: {}", found))),
+        })
+        .expect_err("apply_glob");
+
+    utils::heading("error", false);
+    error.annotated_depiction().print_default_depiction();
+}
@@ -0,0 +1,102 @@
+mod utils;
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, patch::*, *},
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let config = r#"name: web
+replicas: 2
+ports:
+- 80
+- 443
+labels:
+  tier: frontend
+  owner: ops
+"#;
+
+    // The patch does not have to be JSON
+
+    let patch = r#"- op: test
+  path: /replicas
+  value: 2.0
+- op: replace
+  path: /replicas
+  value: 3
+- op: add
+  path: /ports/-
+  value: 9090
+- op: move
+  from: /labels/owner
+  path: /labels/team
+- op: copy
+  from: /name
+  path: /labels/app
+- op: remove
+  path: /ports/0
+"#;
+
+    let mut variant: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("config".into()).parse_string(config).expect("parse");
+    let original = variant.clone();
+
+    let patch: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("patch".into()).parse_string(patch).expect("parse");
+    let patch = JsonPatch::try_from(&patch).expect("JSON Patch");
+
+    variant.apply_patch(&patch).expect("apply_patch");
+
+    utils::heading("patched", true);
+    variant.print_default_depiction();
+
+    // If any operation fails then nothing is changed, and the error points to the operation
+
+    let failing_patch = r#"[
+  { "op": "remove", "path": "/labels" },
+  { "op": "replace", "path": "/labels/tier", "value": "backend" }
+]"#;
+
+    let failing_patch: Variant<WithAnnotations> =
+        Parser::new(Format::JSON).with_source("failing patch".into()).parse_string(failing_patch).expect("parse");
+    let failing_patch = JsonPatch::try_from(&failing_patch).expect("JSON Patch");
+
+    let error = variant.apply_patch(&failing_patch).expect_err("apply_patch");
+
+    utils::heading("error", false);
+    error.annotated_depiction().print_default_depiction();
+    println!("unchanged: {}", variant.into_get("labels").is_some());
+
+    // Generate a patch from two variants
+
+    let generated = original.diff_patch(&variant);
+
+    utils::heading("generated", false);
+    Variant::from(generated.clone()).print_default_depiction();
+
+    let mut regenerated = original.clone();
+    regenerated.apply_patch(&generated).expect("apply_patch");
+    println!("same result: {}", regenerated == variant);
+
+    // JSON Merge Patch (RFC 7396) is simpler: null removes and anything other than a map replaces
+
+    let merge_patch = r#"replicas: 5
+ports: [8080]
+labels:
+  team: null
+  tier: backend
+"#;
+
+    let merge_patch: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("merge patch".into()).parse_string(merge_patch).expect("parse");
+
+    let mut merged = variant.clone();
+    merged.merge_patch(&merge_patch);
+
+    utils::heading("merge patched", false);
+    merged.print_default_depiction();
+
+    utils::heading("generated merge patch", false);
+    variant.diff_merge_patch(&merged).print_default_depiction();
+}
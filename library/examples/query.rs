@@ -0,0 +1,71 @@
+mod utils;
+
+use {
+    compris::{annotate::*, normal::*, parse::*, query::*, *},
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let yaml = r#"store:
+  book:
+  - category: reference
+    author: Nigel Rees
+    title: Sayings of the Century
+    price: 8.95
+  - category: fiction
+    author: Evelyn Waugh
+    title: Sword of Honour
+    price: 12.99
+  - category: fiction
+    author: Herman Melville
+    title: Moby Dick
+    isbn: 0-553-21311-3
+    price: 8.99
+  bicycle:
+    color: red
+    price: 399
+  # Non-text keys
+  lockers:
+    1: empty
+    2: [umbrella, boots]
+threshold: 10
+"#;
+
+    let store: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("store.yaml".into()).parse_string(yaml).expect("parse");
+
+    for query in [
+        // Names and wildcards
+        "$.store.book[*].author",
+        // Recursive descent
+        "$..price",
+        // Slices (negative indexes count from the end)
+        "$.store.book[-2:]",
+        // Filters can refer to the current node (@) and to the root ($)
+        "$.store.book[?@.price < $.threshold].title",
+        "$..book[?@.isbn && @.category == 'fiction']",
+        // Extension: integer keys can be matched by index (names match only text keys)
+        "$.store.lockers[2][0]",
+        "$.store.lockers[1]",
+    ] {
+        utils::heading(query, false);
+        let query: Query = query.parse().expect("query");
+        for found in store.query(&query) {
+            found.print_default_depiction();
+        }
+    }
+
+    utils::heading("errors", false);
+    // Integers must be within the I-JSON range (±(2^53-1))
+    for query in ["$.store.book[?@.price <]", "$.store.book[-9223372036854775808]", "$.store.book[1::9007199254740992]"]
+    {
+        match query.parse::<Query>() {
+            Ok(_) => panic!("should fail: {}", query),
+            Err(error) => println!("{}", error),
+        }
+    }
+
+    // Extreme slices are clamped to the list
+    let query: Query = "$.store.book[9007199254740991::-9007199254740991]".parse().expect("query");
+    assert_eq!(store.query(&query).len(), 1);
+}
@@ -31,7 +31,9 @@ where
     #[depict(as(display), style(number), tag(tag::span))]
     credit: Annotate<i32, AnnotatedT>,
 
-    #[resolve(required, key = "enabled")]
+    // A deprecated key can be used instead of the key, but will be reported as a warning
+    // (Use #[resolve(deprecated)] to report a warning for the field's key itself)
+    #[resolve(required, key = "enabled", deprecated_key = "active")]
     #[depict(style(symbol))]
     is_enabled: bool,
 
@@ -70,6 +72,7 @@ pub fn main() {
     let json = r#"[{
     "name": "Tal",
     "credit": "wrong type",
+    "active": false,
     "group": null,
     "metadata": {"anything": "we want"}
 }, {
@@ -84,7 +87,8 @@ pub fn main() {
         Parser::new(Format::JSON).with_source("json".into()).with_try_integers(true).parse_string(json).expect("parse")
     );
 
-    let mut errors = ResolveErrors::default();
+    // PartitionedErrors accumulates warnings separately from errors
+    let mut errors = PartitionedErrors::default();
     let users: Vec<User<_, isize>> =
         variant.resolve_with_errors(&mut errors).expect("errors should be accumulated").expect("some");
 
@@ -93,9 +97,14 @@ pub fn main() {
         user.print_default_depiction();
     }
 
-    if !errors.is_empty() {
+    if !errors.errors.is_empty() {
         println!();
-        errors.annotated_depictions(Some("accumulated errors".into())).print_default_depiction();
+        errors.errors.annotated_depictions(Some("accumulated errors".into())).print_default_depiction();
+    }
+
+    if !errors.warnings.is_empty() {
+        println!();
+        errors.warnings.annotated_depictions(Some("accumulated warnings".into())).print_default_depiction();
     }
 
     utils::heading("annotations", false);
@@ -98,7 +98,7 @@ pub fn main() {
 
     let mut errors = ResolveErrors::default();
     let users: Vec<User> =
-        variant.resolve_with_errors(&mut errors).expect("errors should be accumulated").expect("some");
+        variant.clone().resolve_with_errors(&mut errors).expect("errors should be accumulated").expect("some");
 
     utils::heading("partially resolved", false);
     println!("{:#?}", users);
@@ -108,5 +108,18 @@ pub fn main() {
         errors.annotated_depictions(Some("accumulated errors".into())).print_default_depiction();
     }
 
+    // Big documents can produce many errors, so we can wrap the error recipient in order to stop
+    // after a limit (we would then get a "too many errors" error) and to drop duplicates,
+    // while SortedErrors sorts them by source and span when finished
+
+    let mut errors = SortedErrors::default();
+    let result: Result<Option<Vec<User>>, _> = variant.resolve_with_errors(&mut errors.deduplicated().with_limit(2));
+
+    utils::heading("limited errors", false);
+    if let Err(error) = result {
+        error.annotated_depiction().print_default_depiction();
+    }
+    errors.finish().annotated_depictions(None).print_default_depiction();
+
     // Continue to examples/resolve_advanced.rs to learn more
 }
@@ -0,0 +1,144 @@
+mod utils;
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, resolve::*, *},
+    kutil::{cli::depict::*, std::error::*},
+    std::path::*,
+};
+
+// See first: examples/resolve_basic.rs
+
+// The context can be anything we want
+// Here we use it to turn relative paths into absolute ones and to validate them against a list of
+// known themes
+struct Context {
+    base_directory: PathBuf,
+    themes: Vec<&'static str>,
+}
+
+// #[resolve(context = ...)] will implement ResolveWithContext instead of Resolve
+// and will pass the context on to all the fields
+#[derive(Debug, Default, Resolve)]
+#[resolve(context = Context)]
+#[allow(dead_code)]
+struct Site {
+    // String does not need the context, but all Resolve types also support ResolveWithContext
+    // (they simply ignore the context)
+    #[resolve(required)]
+    name: String,
+
+    #[resolve]
+    root: AbsolutePath,
+
+    // This struct needs the context, too
+    #[resolve]
+    theme: Theme,
+
+    // Collections (and Option) pass the context on to their items
+    #[resolve]
+    mirrors: Vec<AbsolutePath>,
+}
+
+#[derive(Debug, Default, Resolve)]
+#[resolve(context = Context)]
+#[allow(dead_code)]
+struct Theme {
+    #[resolve(required)]
+    name: ThemeName,
+
+    #[resolve]
+    stylesheet: AbsolutePath,
+}
+
+// We can also implement ResolveWithContext ourselves
+
+#[derive(Debug, Default)]
+struct AbsolutePath(#[allow(dead_code)] PathBuf);
+
+impl<AnnotatedT> ResolveWithContext<AbsolutePath, Context, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &Context,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<AbsolutePath, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let path: Option<PathBuf> = self.resolve_with_errors(errors)?;
+        Ok(path.map(|path| AbsolutePath(context.base_directory.join(path))))
+    }
+}
+
+#[derive(Debug, Default)]
+struct ThemeName(#[allow(dead_code)] String);
+
+impl<AnnotatedT> ResolveWithContext<ThemeName, Context, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &Context,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ThemeName, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let maybe_annotations = self.maybe_annotations();
+        let name: Option<String> = self.resolve_with_errors(errors)?;
+
+        Ok(match name {
+            Some(name) => {
+                if context.themes.contains(&name.as_str()) {
+                    Some(ThemeName(name))
+                } else {
+                    errors.give(
+                        MalformedError::new("theme".into(), format!("unknown: {}", name))
+                            .with_annotations_from(&maybe_annotations),
+                    )?;
+                    None
+                }
+            }
+
+            None => None,
+        })
+    }
+}
+
+pub fn main() {
+    let context = Context { base_directory: "/srv/www".into(), themes: vec!["light", "dark"] };
+
+    let variant = without_annotations!(normal_map![
+        ("name", "My Site"),
+        ("root", "public"),
+        ("mirrors", normal_list!["mirror1", "mirror2"]),
+        ("theme", normal_map![("name", "dark"), ("stylesheet", "css/dark.css")])
+    ]);
+
+    let site: Site = variant.resolve_in_context(&context).expect("resolve");
+
+    utils::heading("resolved", true);
+    println!("{:#?}", site);
+
+    // The context is available to the errors, too
+
+    let yaml = r#"
+name: My Site
+theme:
+  name: neon
+  stylesheet: css/neon.css
+"#;
+
+    let variant =
+        with_annotations!(Parser::new(Format::YAML).with_source("yaml".into()).parse_string(yaml).expect("parse"));
+
+    let mut errors = Errors::default();
+    let _: Option<Site> = variant.resolve_with_context(&context, &mut errors).expect("errors should be accumulated");
+
+    utils::heading("errors", false);
+    errors.annotated_depictions(None).print_default_depiction();
+}
@@ -3,13 +3,15 @@ mod utils;
 use {
     anstream::println,
     compris::{resolve::*, *},
-    std::path::*,
+    std::{collections::*, path::*},
 };
 
 // See first: examples/resolve_basic.rs
 
 // We can #[derive(Resolve)] for enums, too
+// #[resolve(by_reference)] will also implement ResolveRef (all the variants must support it)
 #[derive(Debug, Resolve)]
+#[resolve(by_reference)]
 #[allow(dead_code)]
 enum Data {
     // We must use #[derive(resolve)] for variants we want to support
@@ -45,4 +47,15 @@ pub fn main() {
 
     utils::heading("resolved empty", false);
     println!("{:#?}", data);
+
+    // Because of #[resolve(by_reference)] we can also resolve by reference
+    // This lets us resolve the same variant more than once without having to clone it
+
+    let variant = without_annotations!(normal_map![("path", "/tmp/file")]);
+    let data: Data = variant.resolve_ref().expect("resolve");
+    let paths: BTreeMap<String, PathBuf> = variant.resolve_ref().expect("resolve");
+
+    utils::heading("resolved by reference", false);
+    println!("{:#?}", data);
+    println!("{:#?}", paths);
 }
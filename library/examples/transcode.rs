@@ -0,0 +1,166 @@
+mod utils;
+
+use {
+    compris::{parse::*, ser::*, *},
+    std::{
+        alloc::*,
+        io,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+};
+
+pub fn main() {
+    let yaml = r#"name: Linus
+groups: [admins, users]
+? [complex, key]
+: 123"#;
+
+    // Transcoding pipes parser events straight into the serializer, so that the data never has
+    // to be fully in memory
+
+    // Only subtrees that must be seen in full are buffered; here the XJSON serialization mode
+    // requires it for the map with the complex key (it's serialized as a "$hint.map" list)
+
+    utils::heading("YAML to XJSON", true);
+    Serializer::new(Format::XJSON)
+        .with_pretty(true)
+        .transcode(
+            &Parser::new(Format::YAML),
+            &mut yaml.as_bytes(),
+            Some(&SerializationMode::for_xjson()),
+            &mut io::stdout(),
+        )
+        .expect("transcode");
+
+    // Hints in XJSON input are processed, too
+
+    let xjson = r#"{"id": {"$hint.uint": "123"}, "data": {"$hint.bytes": "SGVsbG8sIHdvcmxk"}}"#;
+
+    utils::heading("XJSON to YAML", false);
+    Serializer::new(Format::YAML)
+        .with_pretty(true)
+        .transcode(
+            &Parser::new(Format::XJSON),
+            &mut xjson.as_bytes(),
+            Some(&SerializationMode::for_yaml()),
+            &mut io::stdout(),
+        )
+        .expect("transcode");
+
+    // CBOR containers are streamed with indefinite lengths, which read back exactly like the
+    // definite lengths used when writing a Variant
+
+    let mut cbor = Vec::default();
+    Serializer::new(Format::CBOR)
+        .transcode(&Parser::new(Format::YAML), &mut yaml.as_bytes(), None, &mut cbor)
+        .expect("transcode");
+
+    let mut expected_cbor = Vec::default();
+    let variant = without_annotations!(Parser::new(Format::YAML).parse_string(yaml).expect("parse"));
+    Serializer::new(Format::CBOR).write(&variant, &mut expected_cbor).expect("write");
+    let expected =
+        without_annotations!(Parser::new(Format::CBOR).parse_reader(&mut expected_cbor.as_slice()).expect("parse"));
+
+    let variant = without_annotations!(Parser::new(Format::CBOR).parse_reader(&mut cbor.as_slice()).expect("parse"));
+    assert_eq!(variant, expected);
+
+    utils::heading("YAML to CBOR and back", false);
+    println!("{}", variant);
+
+    // Including indefinite-length containers nested in indefinite-length maps
+
+    let json = r#"{"a": [1], "b": {"c": true}}"#;
+    let mut cbor = Vec::default();
+    Serializer::new(Format::CBOR)
+        .transcode(&Parser::new(Format::JSON), &mut json.as_bytes(), None, &mut cbor)
+        .expect("transcode");
+
+    let expected = without_annotations!(Parser::new(Format::JSON).parse_string(json).expect("parse"));
+    let variant = without_annotations!(Parser::new(Format::CBOR).parse_reader(&mut cbor.as_slice()).expect("parse"));
+    assert_eq!(variant, expected);
+
+    // Peak memory use does not grow with the size of the input
+
+    let small = transcode_peak(1_000);
+    let large = transcode_peak(100_000);
+
+    utils::heading("Peak memory when transcoding JSON to CBOR", false);
+    println!("1,000 items: {} bytes", small);
+    println!("100,000 items: {} bytes", large);
+    assert!(large < small * 2, "peak memory grows with input size");
+}
+
+// Transcodes a generated JSON list straight into the void and returns the peak memory used
+fn transcode_peak(count: usize) -> usize {
+    let mut reader = JsonList { count, index: 0, pending: b"[".to_vec() };
+    let baseline = PEAK.reset();
+    Serializer::new(Format::CBOR)
+        .transcode(&Parser::new(Format::JSON), &mut reader, None, &mut io::sink())
+        .expect("transcode");
+    PEAK.peak() - baseline
+}
+
+// Generates a JSON list of maps on the fly, so that the input is never fully in memory
+struct JsonList {
+    count: usize,
+    index: usize,
+    pending: Vec<u8>,
+}
+
+impl io::Read for JsonList {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() && (self.index <= self.count) {
+            self.pending = if self.index == self.count {
+                b"]".to_vec()
+            } else {
+                let separator = if self.index == 0 { "" } else { "," };
+                format!(r#"{}{{"index":{},"tags":["a","b"]}}"#, separator, self.index).into_bytes()
+            };
+            self.index += 1;
+        }
+
+        let size = buffer.len().min(self.pending.len());
+        buffer[..size].copy_from_slice(&self.pending[..size]);
+        self.pending.drain(..size);
+        Ok(size)
+    }
+}
+
+// Allocator that keeps track of peak memory use
+
+#[global_allocator]
+static PEAK: PeakAllocator = PeakAllocator { current: AtomicUsize::new(0), peak: AtomicUsize::new(0) };
+
+struct PeakAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl PeakAllocator {
+    // Resets the peak to the current use and returns it
+    fn reset(&self) -> usize {
+        let current = self.current.load(Ordering::SeqCst);
+        self.peak.store(current, Ordering::SeqCst);
+        current
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            let current = self.current.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            self.peak.fetch_max(current, Ordering::SeqCst);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        self.current.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
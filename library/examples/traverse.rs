@@ -15,7 +15,7 @@ pub fn main() {
           doing
 "#;
 
-    let variant = without_annotations!(Parser::new(Format::YAML).parse_string(yaml).expect("parse"));
+    let mut variant = without_annotations!(Parser::new(Format::YAML).parse_string(yaml).expect("parse"));
 
     // The first argument for "traverse!" is our starting point and then it's a sequence of
     // map keys or list indexes as bare primitive expressions or normal types
@@ -76,4 +76,68 @@ pub fn main() {
 
     utils::heading("route to found value", false);
     route.print_default_depiction();
+
+    // Paths can also be parsed from the same syntax in which they are displayed
+
+    let path: PathRepresentation = r#"hello.world.10i64.how[0]."are you""#.parse().expect("path");
+    let found_value = variant.get_path(&path).expect("get_path");
+
+    utils::heading("found by path", false);
+    found_value.print_default_depiction();
+
+    // Text keys that could be read as other keys are quoted, so they are never confused
+
+    let mut path = PathRepresentation::default();
+    path.push_variant_key(&without_annotations!(normal!("10i64")));
+    path.push_variant_key(&without_annotations!(normal!(10)));
+    assert_eq!(path.to_string().parse::<PathRepresentation>().expect("path"), path);
+
+    utils::heading("text key \"10i64\" and integer key 10", false);
+    println!("{}", path);
+
+    // JSON Pointers (RFC 6901) address map keys by their string representation, so the integer
+    // key 10 is "10i64" (the same as in the route above)
+
+    let pointer: JsonPointer = "/hello/world/10i64/how/0/are you".parse().expect("JSON Pointer");
+    let found_value = variant.pointer_get(&pointer).expect("pointer_get");
+
+    utils::heading("found by JSON Pointer", false);
+    found_value.print_default_depiction();
+
+    // A plain "10" matches a numeric key of any type
+
+    let pointer: JsonPointer = "/hello/world/10/how/0/are you".parse().expect("JSON Pointer");
+    let found_value = variant.pointer_get(&pointer).expect("pointer_get");
+
+    utils::heading("found by plain JSON Pointer", false);
+    found_value.print_default_depiction();
+
+    // A route can be converted to a JSON Pointer
+
+    let mut route = route.into_representation();
+    utils::heading("route as JSON Pointer", false);
+    println!("{}", route.to_json_pointer());
+
+    // "set_path" creates missing intermediate maps and lists (according to a policy), whereas
+    // "traverse_mut" would stop at the first missing key
+
+    let path = normal_vec!["hello", "there", "friend"];
+    variant.set_path(path.iter(), normal!("new"), Default::default()).expect("set_path");
+
+    // It also accepts a path representation; here we will set a sibling of our found value
+
+    route.segments.pop();
+    route.push_map_key("and you".into());
+    variant.set_path(&route, normal!("also new"), Default::default()).expect("set_path");
+
+    utils::heading("after set_path", false);
+    variant.print_default_depiction();
+
+    // An intermediate node that is neither a collection nor missing is an error
+
+    let path = normal_vec!["hello", "there", "friend", "name"];
+    let error = variant.set_path(path.iter(), normal!("nope"), Default::default()).expect_err("set_path");
+
+    utils::heading("set_path error", false);
+    println!("{}", error);
 }
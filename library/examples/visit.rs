@@ -0,0 +1,114 @@
+mod utils;
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, path::*, visit::*, *},
+    kutil::cli::depict::*,
+};
+
+pub fn main() {
+    let yaml = r#"name: Linus
+homepage: https://example.org
+groups:
+- admins
+- users
+contact:
+  email: linus@example.org
+  web: http://example.org/linus
+"#;
+
+    let variant: Variant<WithAnnotations> =
+        Parser::new(Format::YAML).with_source("yaml".into()).parse_string(yaml).expect("parse");
+
+    // A closure can be a visitor; it receives the path of each variant
+
+    utils::heading("URLs", true);
+    variant
+        .visit(&mut |variant: &Variant<_>, path: &PathRepresentation| {
+            if let Variant::Text(text) = variant
+                && text.inner.starts_with("http")
+            {
+                println!("{}: {}", path, text);
+            }
+            Ok::<_, AnnotatedMessageError<WithAnnotations>>(Visit::Continue)
+        })
+        .expect("visit");
+
+    // Or, without a callback, walk the tree as an iterator
+
+    utils::heading("URLs (walk)", false);
+    for (path, variant) in variant.walk() {
+        if let Variant::Text(text) = variant
+            && text.inner.starts_with("http")
+        {
+            println!("{}: {}", path, text);
+        }
+    }
+
+    utils::heading("walk (post-order with keys)", false);
+    for (path, variant) in variant.walk().with_order(WalkOrder::PostOrder).with_keys(true) {
+        println!("{}: {}", path, variant);
+    }
+
+    // Fold is a shortcut for accumulating a value
+
+    let count = variant
+        .fold(0, |count, variant, _path| {
+            Ok::<_, AnnotatedMessageError<WithAnnotations>>(if variant.is_collection() { count } else { count + 1 })
+        })
+        .expect("fold");
+
+    utils::heading("number of leaves", false);
+    println!("{}", count);
+
+    // A mutable visitor can modify variants in place and skip subtrees
+
+    let mut upgraded = variant.clone();
+    upgraded
+        .visit_mut(&mut |variant: &mut Variant<_>, path: &PathRepresentation| {
+            if path.to_string() == "groups" {
+                return Ok::<_, AnnotatedMessageError<WithAnnotations>>(Visit::SkipChildren);
+            }
+
+            if let Variant::Text(text) = variant
+                && let Some(rest) = text.inner.strip_prefix("http://")
+            {
+                *variant = normal!(format!("https://{}", rest));
+            }
+
+            Ok(Visit::Continue)
+        })
+        .expect("visit_mut");
+
+    utils::heading("upgraded", false);
+    upgraded.print_default_depiction();
+
+    // Transform rebuilds the tree bottom-up (children first)
+
+    let transformed = variant
+        .clone()
+        .transform(|variant, _path| {
+            Ok::<_, AnnotatedMessageError<WithAnnotations>>(match variant {
+                Variant::Text(text) => normal!(text.inner.to_uppercase()),
+                variant => variant,
+            })
+        })
+        .expect("transform");
+
+    utils::heading("transformed", false);
+    transformed.print_default_depiction();
+
+    // Errors abort and are annotated with the location of the variant that caused them
+
+    let error = variant
+        .visit(&mut |variant: &Variant<_>, _path: &PathRepresentation| match variant {
+            Variant::Text(text) if text.inner.contains('@') => {
+                Err(AnnotatedMessageError::<WithAnnotations>::new(format!("email not allowed: {}", text)))
+            }
+            _ => Ok(Visit::Continue),
+        })
+        .expect_err("visit");
+
+    utils::heading("error", false);
+    error.annotated_depiction().print_default_depiction();
+}
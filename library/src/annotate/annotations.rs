@@ -1,4 +1,4 @@
-use super::{super::path::*, label::*, span::*};
+use super::{super::path::*, label::*, sources::*, span::*};
 
 use {
     kutil::{cli::depict::*, std::immutable::*},
@@ -21,6 +21,9 @@ pub struct Annotations {
     /// Source.
     pub source: Option<ByteString>,
 
+    /// Source ID in a [SourceRegistry].
+    pub source_id: Option<SourceId>,
+
     /// Span.
     pub span: Option<Span>,
 
@@ -34,7 +37,11 @@ pub struct Annotations {
 impl Annotations {
     /// True if any field is [Some].
     pub fn has_some(&self) -> bool {
-        self.source.is_some() || self.span.is_some() || self.path.is_some() || self.label.is_some()
+        self.source.is_some()
+            || self.source_id.is_some()
+            || self.span.is_some()
+            || self.path.is_some()
+            || self.label.is_some()
     }
 
     /// Whether [Depict] will have output.
@@ -42,6 +49,7 @@ impl Annotations {
         match format {
             DepictionFormat::Compact => {
                 self.source.is_some()
+                    || self.source_id.is_some()
                     || match &self.span {
                         Some(span) => span.has_debug(),
                         None => false,
@@ -58,6 +66,7 @@ impl Annotations {
 
             DepictionFormat::Verbose => {
                 self.source.is_some()
+                    || self.source_id.is_some()
                     || self.path.is_some()
                     || match &self.span {
                         Some(span) => span.has_debug(),
@@ -73,6 +82,12 @@ impl Annotations {
         self
     }
 
+    /// Set source ID.
+    pub fn with_source_id(mut self, source_id: SourceId) -> Self {
+        self.source_id = Some(source_id);
+        self
+    }
+
     /// Set span.
     pub fn with_span(mut self, span: Span) -> Self {
         self.span = Some(span);
@@ -125,6 +140,9 @@ impl Depict for Annotations {
                 if let Some(source) = &self.source {
                     context.theme.write_meta(writer, source)?;
                     separate = true;
+                } else if let Some(source_id) = &self.source_id {
+                    source_id.depict(writer, &context.clone().with_separator(false))?;
+                    separate = true;
                 }
 
                 if let Some(span) = &self.span
@@ -147,6 +165,9 @@ impl Depict for Annotations {
                 if let Some(source) = &self.source {
                     context.theme.write_meta(writer, source)?;
                     separate = true;
+                } else if let Some(source_id) = &self.source_id {
+                    source_id.depict(writer, &context.clone().with_separator(false))?;
+                    separate = true;
                 }
 
                 if let Some(span) = &self.span
@@ -202,6 +223,9 @@ impl fmt::Display for Annotations {
         if let Some(source) = &self.source {
             write!(formatter, "{}", source)?;
             separate = true;
+        } else if let Some(source_id) = &self.source_id {
+            write!(formatter, "{}", source_id)?;
+            separate = true;
         }
 
         if let Some(span) = &self.span {
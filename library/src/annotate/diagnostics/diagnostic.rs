@@ -0,0 +1,118 @@
+use super::{
+    super::{annotated::*, annotations::*},
+    excerpt::*,
+    label::*,
+    severity::*,
+    sources::*,
+};
+
+use {kutil::cli::depict::*, std::io};
+
+//
+// Diagnostic
+//
+
+/// A [Depict] wrapper for an [Annotated] [Depict] (usually an error) that shows excerpts of the
+/// source text.
+///
+/// The excerpt has line numbers and carets under the [Span](super::super::span::Span). Secondary
+/// labels, possibly in other sources, are shown likewise. When a source's text is not available
+/// only its [Annotations] are shown.
+pub struct Diagnostic<'own, InnerT, SourceTextsT> {
+    /// Inner.
+    pub inner: &'own InnerT,
+
+    /// Source texts.
+    pub sources: &'own SourceTextsT,
+
+    /// Severity.
+    pub severity: DiagnosticSeverity,
+
+    /// Secondary labels.
+    pub labels: Vec<DiagnosticLabel>,
+}
+
+impl<'own, InnerT, SourceTextsT> Diagnostic<'own, InnerT, SourceTextsT> {
+    /// Constructor.
+    pub fn new(inner: &'own InnerT, sources: &'own SourceTextsT) -> Self {
+        Self { inner, sources, severity: Default::default(), labels: Default::default() }
+    }
+
+    /// Set severity.
+    pub fn with_severity(mut self, severity: DiagnosticSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Add a secondary label.
+    pub fn with_label(mut self, annotations: Annotations, message: String) -> Self {
+        self.labels.push(DiagnosticLabel::new(annotations, message));
+        self
+    }
+}
+
+impl<'own, InnerT, SourceTextsT> Depict for Diagnostic<'own, InnerT, SourceTextsT>
+where
+    InnerT: Annotated + Depict,
+    SourceTextsT: SourceTexts,
+{
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        self.severity.depict(writer, context)?;
+        context.theme.write_delimiter(writer, ": ")?;
+        self.inner.depict(writer, &context.clone().with_separator(false))?;
+
+        // Group by source, with the primary source first
+        let mut groups: Vec<(&Annotations, Vec<Marker>, Vec<&str>)> = Vec::default();
+
+        let annotations = self.inner.annotations().into_iter().map(|annotations| (annotations, true, None));
+        let labels = self.labels.iter().map(|label| (&label.annotations, false, Some(label.message.clone())));
+
+        for (annotations, primary, label) in annotations.chain(labels) {
+            let index = match groups.iter().position(|(group, _, _)| {
+                (group.source == annotations.source) && (group.source_id == annotations.source_id)
+            }) {
+                Some(index) => index,
+                None => {
+                    let lines = self
+                        .sources
+                        .annotations_source_text(annotations)
+                        .map(|text| text.lines().collect())
+                        .unwrap_or_default();
+                    groups.push((annotations, Vec::default(), lines));
+                    groups.len() - 1
+                }
+            };
+
+            let (_, markers, lines) = &mut groups[index];
+            if let Some(marker) = annotations.span.as_ref().and_then(|span| Marker::new(span, primary, label, lines)) {
+                markers.push(marker);
+            }
+        }
+
+        let width = groups.iter().map(|(_, markers, _)| gutter_width(markers)).max().unwrap_or(1);
+        let has_primary = self.inner.annotations().is_some();
+        let annotations_context = context.clone().with_separator(false).with_format(DepictionFormat::Verbose);
+
+        for (index, (annotations, markers, lines)) in groups.iter().enumerate() {
+            if !annotations.has_depiction(DepictionFormat::Verbose) {
+                continue;
+            }
+
+            context.indent(writer)?;
+            context.theme.write_delimiter(
+                writer,
+                format!("{:width$}{} ", "", if (index == 0) && has_primary { "-->" } else { ":::" }),
+            )?;
+            annotations.depict(writer, &annotations_context)?;
+
+            if !markers.is_empty() {
+                write_excerpt(writer, context, lines, markers, width)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use super::super::{location::*, span::*};
+
+use {
+    kutil::cli::depict::*,
+    std::{collections::*, io},
+};
+
+const TAB_WIDTH: usize = 4;
+
+// Multiline spans longer than this will be shortened
+const MAX_MARKER_ROWS: usize = 4;
+
+//
+// Marker
+//
+
+// Marks a span in an excerpt
+pub(crate) struct Marker {
+    start_row: usize,
+    start_column: usize,
+    end_row: usize,
+
+    // Exclusive
+    end_column: usize,
+
+    primary: bool,
+    label: Option<String>,
+}
+
+impl Marker {
+    // None if the span has no row or is outside the lines
+    pub(crate) fn new(span: &Span, primary: bool, label: Option<String>, lines: &[&str]) -> Option<Self> {
+        let start_row = span.start.row?;
+        if start_row >= lines.len() {
+            return None;
+        }
+        let start_column = span.start.column.unwrap_or_default();
+
+        let (mut end_row, mut end_column) = match &span.end {
+            Some(Location { row: Some(row), column, .. }) => ((*row).min(lines.len() - 1), column.unwrap_or_default()),
+            _ => (start_row, start_column + 1),
+        };
+
+        // An end at the beginning of a line belongs to the previous line
+        if (end_row > start_row) && (end_column == 0) {
+            end_row -= 1;
+            end_column = lines[end_row].chars().count();
+        }
+
+        if (end_row < start_row) || ((end_row == start_row) && (end_column <= start_column)) {
+            end_row = start_row;
+            end_column = start_column + 1;
+        }
+
+        Some(Self { start_row, start_column, end_row, end_column, primary, label })
+    }
+
+    // Rows to show
+    fn rows(&self) -> Vec<usize> {
+        if self.end_row - self.start_row < MAX_MARKER_ROWS {
+            (self.start_row..=self.end_row).collect()
+        } else {
+            vec![self.start_row, self.start_row + 1, self.end_row - 1, self.end_row]
+        }
+    }
+
+    // Display columns for a row
+    fn columns(&self, row: usize, line: &str) -> (usize, usize) {
+        let start = if row == self.start_row {
+            self.start_column
+        } else {
+            line.chars().take_while(|c| c.is_whitespace()).count()
+        };
+
+        let end = if row == self.end_row { self.end_column } else { line.chars().count() };
+
+        let start = display_column(line, start);
+        (start, display_column(line, end).max(start + 1))
+    }
+}
+
+// Utils
+
+/// The width of the line number gutter.
+pub(crate) fn gutter_width(markers: &[Marker]) -> usize {
+    let row = markers.iter().map(|marker| marker.end_row).max().unwrap_or_default();
+    (row + 1).to_string().len()
+}
+
+/// Write an empty gutter line.
+pub(crate) fn write_gutter<WriteT>(writer: &mut WriteT, context: &DepictionContext, width: usize) -> io::Result<()>
+where
+    WriteT: io::Write,
+{
+    context.indent(writer)?;
+    context.theme.write_delimiter(writer, format!("{:width$} |", ""))
+}
+
+/// Write the marked lines.
+pub(crate) fn write_excerpt<WriteT>(
+    writer: &mut WriteT,
+    context: &DepictionContext,
+    lines: &[&str],
+    markers: &[Marker],
+    width: usize,
+) -> io::Result<()>
+where
+    WriteT: io::Write,
+{
+    let rows: BTreeSet<_> = markers.iter().flat_map(|marker| marker.rows()).collect();
+
+    write_gutter(writer, context, width)?;
+
+    let mut previous_row = None;
+    for row in rows {
+        if let Some(previous_row) = previous_row
+            && row > previous_row + 1
+        {
+            context.indent(writer)?;
+            context.theme.write_delimiter(writer, "...")?;
+        }
+        previous_row = Some(row);
+
+        let line = lines[row];
+        context.indent(writer)?;
+        context.theme.write_delimiter(writer, format!("{:>width$} | ", row + 1))?;
+        write!(writer, "{}", expand_tabs(line))?;
+
+        // Primary markers first
+        for primary in [true, false] {
+            for marker in markers {
+                if (marker.primary != primary) || !marker.rows().contains(&row) {
+                    continue;
+                }
+
+                let (start, end) = marker.columns(row, line);
+                let carets = if primary { "^" } else { "-" }.repeat(end - start);
+
+                context.indent(writer)?;
+                context.theme.write_delimiter(writer, format!("{:width$} | {:start$}", "", ""))?;
+
+                if primary {
+                    context.theme.write_error(writer, carets)?;
+                } else {
+                    context.theme.write_meta(writer, carets)?;
+                }
+
+                if row == marker.end_row
+                    && let Some(label) = &marker.label
+                {
+                    write!(writer, " ")?;
+                    if primary {
+                        context.theme.write_error(writer, label)?;
+                    } else {
+                        context.theme.write_meta(writer, label)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn display_column(line: &str, column: usize) -> usize {
+    let mut display_column = 0;
+    let mut chars = line.chars();
+    for _ in 0..column {
+        display_column += match chars.next() {
+            Some('\t') => TAB_WIDTH,
+            _ => 1,
+        };
+    }
+    display_column
+}
+
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', &" ".repeat(TAB_WIDTH))
+}
@@ -0,0 +1,218 @@
+use super::{
+    super::{super::normal::*, annotations::*, label::*, location::*, span::*},
+    report::*,
+};
+
+/// SARIF version.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF schema.
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+//
+// ToDiagnosticsVariant
+//
+
+/// Export an [Iterator] of [ReportDiagnostic] as a [Variant], which can then be serialized into
+/// any format.
+pub trait ToDiagnosticsVariant<'own, ItemT>
+where
+    Self: 'own,
+    &'own Self: IntoIterator<Item = &'own ItemT>,
+    ItemT: 'own,
+{
+    /// To a [List] of diagnostics.
+    ///
+    /// Each diagnostic is a [Map] with "message", "severity", and, when available, "source",
+    /// "span", "path", and "label". Rows and columns in the span are 0-based.
+    fn to_diagnostics_variant<AnnotatedT>(&'own self) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Default;
+
+    /// To a SARIF 2.1.0 log with a single run.
+    ///
+    /// Rows and columns are 1-based, as required by SARIF.
+    fn to_sarif_variant<AnnotatedT>(&'own self, tool_name: &str, tool_version: Option<&str>) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Default;
+}
+
+impl<'own, IterableT, ItemT> ToDiagnosticsVariant<'own, ItemT> for IterableT
+where
+    IterableT: 'own + ?Sized,
+    &'own IterableT: IntoIterator<Item = &'own ItemT>,
+    ItemT: 'own + ReportDiagnostic,
+{
+    fn to_diagnostics_variant<AnnotatedT>(&'own self) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Default,
+    {
+        self.into_iter()
+            .map(|item| {
+                let mut entries: Vec<Entry<AnnotatedT>> = vec![
+                    entry("message", item.diagnostic_message().into()),
+                    entry("severity", item.diagnostic_severity().to_string().into()),
+                ];
+
+                if let Some(annotations) = item.diagnostic_annotations() {
+                    let Annotations { source, span, path, label, .. } = annotations.as_ref();
+
+                    if let Some(source) = source {
+                        entries.push(entry("source", source.clone().into()));
+                    }
+
+                    if let Some(span) = span {
+                        entries.push(entry("span", span_variant(span)));
+                    }
+
+                    if let Some(path) = path {
+                        entries.push(entry("path", path.to_string().into()));
+                    }
+
+                    if let Some(label) = label {
+                        entries.push(entry("label", label_variant(label)));
+                    }
+                }
+
+                entries.into_iter().collect::<Variant<_>>()
+            })
+            .collect()
+    }
+
+    fn to_sarif_variant<AnnotatedT>(&'own self, tool_name: &str, tool_version: Option<&str>) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Default,
+    {
+        let results: Variant<AnnotatedT> = self
+            .into_iter()
+            .map(|item| {
+                let mut entries: Vec<Entry<AnnotatedT>> = vec![
+                    entry("level", item.diagnostic_severity().sarif_level().into()),
+                    entry("message", [entry("text", item.diagnostic_message().into())].into()),
+                ];
+
+                if let Some(annotations) = item.diagnostic_annotations() {
+                    let mut location = Vec::default();
+
+                    let mut physical_location = Vec::default();
+                    if let Some(source) = &annotations.source {
+                        physical_location.push(entry("artifactLocation", [entry("uri", source.clone().into())].into()));
+                    }
+                    if let Some(span) = &annotations.span
+                        && let Some(region) = sarif_region(span)
+                    {
+                        physical_location.push(entry("region", region));
+                    }
+                    if !physical_location.is_empty() {
+                        location.push(entry("physicalLocation", physical_location.into_iter().collect()));
+                    }
+
+                    if let Some(path) = &annotations.path {
+                        location.push(entry(
+                            "logicalLocations",
+                            normal_list![[entry("fullyQualifiedName", path.to_string().into())]],
+                        ));
+                    }
+
+                    if !location.is_empty() {
+                        entries.push(entry("locations", normal_list![location.into_iter().collect::<Variant<_>>()]));
+                    }
+
+                    if let Some(label) = &annotations.label {
+                        entries.push(entry("properties", [entry("label", label_variant(label))].into()));
+                    }
+                }
+
+                entries.into_iter().collect::<Variant<_>>()
+            })
+            .collect();
+
+        let mut driver = vec![entry("name", tool_name.to_string().into())];
+        if let Some(tool_version) = tool_version {
+            driver.push(entry("version", tool_version.to_string().into()));
+        }
+
+        let run: Variant<_> =
+            [entry("tool", [entry("driver", driver.into_iter().collect())].into()), entry("results", results)].into();
+
+        [
+            entry("$schema", SARIF_SCHEMA.into()),
+            entry("version", SARIF_VERSION.into()),
+            entry("runs", normal_list![run]),
+        ]
+        .into()
+    }
+}
+
+// Utils
+
+type Entry<AnnotatedT> = (Variant<AnnotatedT>, Variant<AnnotatedT>);
+
+fn entry<AnnotatedT>(key: &'static str, value: Variant<AnnotatedT>) -> Entry<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    (key.into(), value)
+}
+
+fn span_variant<AnnotatedT>(span: &Span) -> Variant<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    let mut entries = vec![entry("start", location_variant(&span.start))];
+    if let Some(end) = &span.end {
+        entries.push(entry("end", location_variant(end)));
+    }
+    entries.into_iter().collect()
+}
+
+fn location_variant<AnnotatedT>(location: &Location) -> Variant<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    let mut entries = Vec::default();
+    if let Some(index) = location.index {
+        entries.push(entry("index", index.into()));
+    }
+    if let Some(row) = location.row {
+        entries.push(entry("row", row.into()));
+        if let Some(column) = location.column {
+            entries.push(entry("column", column.into()));
+        }
+    }
+    entries.into_iter().collect()
+}
+
+fn label_variant<AnnotatedT>(label: &Label) -> Variant<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    match label {
+        Label::Integer(integer) => (*integer).into(),
+        Label::String(string) => string.clone().into(),
+    }
+}
+
+fn sarif_region<AnnotatedT>(span: &Span) -> Option<Variant<AnnotatedT>>
+where
+    AnnotatedT: Default,
+{
+    let start_row = span.start.row?;
+
+    let mut entries = vec![entry("startLine", (start_row + 1).into())];
+    if let Some(column) = span.start.column {
+        entries.push(entry("startColumn", (column + 1).into()));
+    }
+
+    if let Some(end) = &span.end
+        && let Some(end_row) = end.row
+    {
+        entries.push(entry("endLine", (end_row + 1).into()));
+        if let Some(column) = end.column {
+            // SARIF's end column is exclusive, like ours
+            entries.push(entry("endColumn", (column + 1).into()));
+        }
+    }
+
+    Some(entries.into_iter().collect())
+}
@@ -0,0 +1,22 @@
+use super::super::annotations::*;
+
+//
+// DiagnosticLabel
+//
+
+/// Secondary label for a [Diagnostic](super::diagnostic::Diagnostic).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DiagnosticLabel {
+    /// Annotations.
+    pub annotations: Annotations,
+
+    /// Message.
+    pub message: String,
+}
+
+impl DiagnosticLabel {
+    /// Constructor.
+    pub fn new(annotations: Annotations, message: String) -> Self {
+        Self { annotations, message }
+    }
+}
@@ -0,0 +1,61 @@
+use super::{super::annotated::*, diagnostic::*, report::*, sources::*};
+
+use {
+    kutil::{cli::depict::*, std::iter::*},
+    std::io,
+};
+
+//
+// Diagnostics
+//
+
+/// A [Depict] wrapper for an [Iterator] of [Annotated] [Depict] (usually errors) that shows
+/// each as a [Diagnostic] with its [ReportDiagnostic] severity.
+pub struct Diagnostics<'own, InnerT, ItemT, SourceTextsT>
+where
+    &'own InnerT: IntoIterator<Item = &'own ItemT>,
+    ItemT: 'own,
+{
+    /// Inner.
+    pub inner: &'own InnerT,
+
+    /// Source texts.
+    pub sources: &'own SourceTextsT,
+}
+
+impl<'own, InnerT, ItemT, SourceTextsT> Diagnostics<'own, InnerT, ItemT, SourceTextsT>
+where
+    &'own InnerT: IntoIterator<Item = &'own ItemT>,
+    ItemT: 'own,
+{
+    /// Constructor.
+    pub fn new(inner: &'own InnerT, sources: &'own SourceTextsT) -> Self {
+        Self { inner, sources }
+    }
+}
+
+impl<'own, InnerT, ItemT, SourceTextsT> Depict for Diagnostics<'own, InnerT, ItemT, SourceTextsT>
+where
+    &'own InnerT: IntoIterator<Item = &'own ItemT>,
+    ItemT: 'own + Annotated + Depict + ReportDiagnostic,
+    SourceTextsT: SourceTexts,
+{
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        for (item, first) in IterateWithFirst::new(self.inner) {
+            if !first {
+                // Blank line between diagnostics
+                context.indent(writer)?;
+                context.indent(writer)?;
+            }
+
+            Diagnostic::new(item, self.sources)
+                .with_severity(item.diagnostic_severity())
+                .depict(writer, &context.clone().with_separator(false))?;
+        }
+
+        Ok(())
+    }
+}
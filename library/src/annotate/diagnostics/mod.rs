@@ -0,0 +1,11 @@
+mod diagnostic;
+mod excerpt;
+mod export;
+mod label;
+mod list;
+mod report;
+mod severity;
+mod sources;
+
+#[allow(unused_imports)]
+pub use {diagnostic::*, export::*, label::*, list::*, report::*, severity::*, sources::*};
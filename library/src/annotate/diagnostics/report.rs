@@ -0,0 +1,23 @@
+use super::{super::annotations::*, severity::*};
+
+use std::borrow::*;
+
+//
+// ReportDiagnostic
+//
+
+/// Can be reported as a machine-readable diagnostic.
+///
+/// See [ToDiagnosticsVariant](super::export::ToDiagnosticsVariant).
+pub trait ReportDiagnostic {
+    /// Message.
+    fn diagnostic_message(&self) -> String;
+
+    /// Severity. Defaults to [DiagnosticSeverity::Error].
+    fn diagnostic_severity(&self) -> DiagnosticSeverity {
+        DiagnosticSeverity::Error
+    }
+
+    /// Annotations.
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>>;
+}
@@ -0,0 +1,48 @@
+use {
+    kutil::{cli::depict::*, std::*},
+    std::io,
+};
+
+//
+// DiagnosticSeverity
+//
+
+/// Diagnostic severity.
+#[derive(Clone, Copy, Debug, Default, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[display(lowercase)]
+pub enum DiagnosticSeverity {
+    /// Info.
+    Info,
+
+    /// Warning.
+    Warning,
+
+    /// Error.
+    #[default]
+    Error,
+}
+
+impl DiagnosticSeverity {
+    /// SARIF level.
+    pub fn sarif_level(&self) -> &'static str {
+        match self {
+            Self::Info => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl Depict for DiagnosticSeverity {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+        match self {
+            Self::Info => context.theme.write_meta(writer, self),
+            Self::Warning => context.theme.write_symbol(writer, self),
+            Self::Error => context.theme.write_error(writer, self),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::super::annotations::*;
+
+use {
+    kutil::std::immutable::*,
+    std::{collections::*, hash::*},
+};
+
+//
+// SourceTexts
+//
+
+/// Provides the texts of sources by their names (as in [Annotations::source]).
+///
+/// See [Diagnostic].
+///
+/// [Diagnostic]: super::diagnostic::Diagnostic
+pub trait SourceTexts {
+    /// The text of a source, if available.
+    fn source_text(&self, source: &ByteString) -> Option<&str>;
+
+    /// The text of the source of [Annotations], if available.
+    fn annotations_source_text(&self, annotations: &Annotations) -> Option<&str> {
+        self.source_text(annotations.source.as_ref()?)
+    }
+}
+
+impl<TextT, BuildHasherT> SourceTexts for HashMap<ByteString, TextT, BuildHasherT>
+where
+    TextT: AsRef<str>,
+    BuildHasherT: BuildHasher,
+{
+    fn source_text(&self, source: &ByteString) -> Option<&str> {
+        self.get(source).map(|text| text.as_ref())
+    }
+}
+
+impl<TextT> SourceTexts for BTreeMap<ByteString, TextT>
+where
+    TextT: AsRef<str>,
+{
+    fn source_text(&self, source: &ByteString) -> Option<&str> {
+        self.get(source).map(|text| text.as_ref())
+    }
+}
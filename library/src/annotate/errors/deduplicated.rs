@@ -0,0 +1,67 @@
+use super::super::{annotated::*, annotations::*};
+
+use {
+    kutil::std::{collections::*, error::*},
+    std::fmt,
+};
+
+//
+// DeduplicatedErrorRecipient
+//
+
+/// An [ErrorRecipient] wrapper that drops errors identical to ones already given.
+///
+/// Errors are identical if they have the same message (their [Display](fmt::Display)) and the
+/// same source and span in their [Annotations].
+pub struct DeduplicatedErrorRecipient<'own, InnerT> {
+    /// Inner.
+    pub inner: &'own mut InnerT,
+
+    /// Seen.
+    pub seen: FastHashSet<(String, Option<Annotations>)>,
+}
+
+impl<'own, InnerT> DeduplicatedErrorRecipient<'own, InnerT> {
+    /// Constructor.
+    pub fn new(inner: &'own mut InnerT) -> Self {
+        Self { inner, seen: Default::default() }
+    }
+}
+
+impl<'own, ErrorT, InnerT> ErrorRecipient<ErrorT> for DeduplicatedErrorRecipient<'own, InnerT>
+where
+    ErrorT: Annotated + fmt::Display,
+    InnerT: ErrorRecipient<ErrorT>,
+{
+    fn give_error(&mut self, error: ErrorT) -> Result<(), ErrorT> {
+        // Only the source and span matter
+        let annotations = error.annotations().map(|annotations| Annotations {
+            source: annotations.source.clone(),
+            source_id: annotations.source_id,
+            span: annotations.span.clone(),
+            ..Default::default()
+        });
+
+        if self.seen.insert((error.to_string(), annotations)) { self.inner.give_error(error) } else { Ok(()) }
+    }
+}
+
+//
+// DeduplicateErrors
+//
+
+/// Wrap in a [DeduplicatedErrorRecipient].
+pub trait DeduplicateErrors<'own, ErrorT, InnerT> {
+    /// Wrap in a [DeduplicatedErrorRecipient].
+    fn deduplicated(&'own mut self) -> DeduplicatedErrorRecipient<'own, InnerT>;
+}
+
+impl<'own, ErrorT, ErrorRecipientT> DeduplicateErrors<'own, ErrorT, ErrorRecipientT> for ErrorRecipientT
+where
+    ErrorT: Annotated + fmt::Display,
+    ErrorRecipientT: ErrorRecipient<ErrorT>,
+{
+    fn deduplicated(&'own mut self) -> DeduplicatedErrorRecipient<'own, ErrorRecipientT> {
+        DeduplicatedErrorRecipient::new(self)
+    }
+}
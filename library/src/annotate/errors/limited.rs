@@ -0,0 +1,69 @@
+use super::too_many::*;
+
+use kutil::std::error::*;
+
+//
+// LimitedErrorRecipient
+//
+
+/// An [ErrorRecipient] wrapper that stops after a limit.
+///
+/// Errors beyond the limit are not given to the inner recipient. Instead, a [TooManyErrorsError]
+/// is returned, so that the caller can fail fast.
+pub struct LimitedErrorRecipient<'own, InnerT> {
+    /// Inner.
+    pub inner: &'own mut InnerT,
+
+    /// Limit.
+    pub limit: usize,
+
+    /// Count of errors given to the inner recipient.
+    pub count: usize,
+}
+
+impl<'own, InnerT> LimitedErrorRecipient<'own, InnerT> {
+    /// Constructor.
+    pub fn new(inner: &'own mut InnerT, limit: usize) -> Self {
+        Self { inner, limit, count: 0 }
+    }
+
+    /// True if the limit has been reached.
+    pub fn is_full(&self) -> bool {
+        self.count >= self.limit
+    }
+}
+
+impl<'own, ErrorT, InnerT> ErrorRecipient<ErrorT> for LimitedErrorRecipient<'own, InnerT>
+where
+    ErrorT: From<TooManyErrorsError>,
+    InnerT: ErrorRecipient<ErrorT>,
+{
+    fn give_error(&mut self, error: ErrorT) -> Result<(), ErrorT> {
+        if self.is_full() {
+            return Err(TooManyErrorsError::new(self.limit).into());
+        }
+
+        self.count += 1;
+        self.inner.give_error(error)
+    }
+}
+
+//
+// LimitErrors
+//
+
+/// Wrap in a [LimitedErrorRecipient].
+pub trait LimitErrors<'own, ErrorT, InnerT> {
+    /// Wrap in a [LimitedErrorRecipient].
+    fn with_limit(&'own mut self, limit: usize) -> LimitedErrorRecipient<'own, InnerT>;
+}
+
+impl<'own, ErrorT, ErrorRecipientT> LimitErrors<'own, ErrorT, ErrorRecipientT> for ErrorRecipientT
+where
+    ErrorT: From<TooManyErrorsError>,
+    ErrorRecipientT: ErrorRecipient<ErrorT>,
+{
+    fn with_limit(&'own mut self, limit: usize) -> LimitedErrorRecipient<'own, ErrorRecipientT> {
+        LimitedErrorRecipient::new(self, limit)
+    }
+}
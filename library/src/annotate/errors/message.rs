@@ -1,8 +1,8 @@
-use super::super::impl_dyn_annotated_error;
+use super::super::{annotated::*, annotations::*, diagnostics::*, impl_dyn_annotated_error};
 
 use {
     kutil::cli::depict::*,
-    std::{error::*, fmt, io},
+    std::{borrow::*, error::*, fmt, io},
 };
 
 //
@@ -27,6 +27,15 @@ impl<AnnotatedT> AnnotatedMessageError<AnnotatedT> {
     {
         Self { message, annotated: Default::default() }
     }
+
+    /// Into different [Annotated] implementation.
+    pub fn into_annotated<NewAnnotationsT>(self) -> AnnotatedMessageError<NewAnnotationsT>
+    where
+        AnnotatedT: Annotated,
+        NewAnnotationsT: Annotated + Default,
+    {
+        AnnotatedMessageError::new(self.message).with_annotations_from(&self.annotated)
+    }
 }
 
 impl_dyn_annotated_error!(AnnotatedMessageError);
@@ -48,6 +57,19 @@ impl<AnnotatedT> fmt::Display for AnnotatedMessageError<AnnotatedT> {
 
 impl<AnnotatedT> Error for AnnotatedMessageError<AnnotatedT> where AnnotatedT: fmt::Debug {}
 
+impl<AnnotatedT> ReportDiagnostic for AnnotatedMessageError<AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn diagnostic_message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>> {
+        self.annotations().map(Cow::Borrowed)
+    }
+}
+
 impl<AnnotatedT> From<String> for AnnotatedMessageError<AnnotatedT>
 where
     AnnotatedT: Default,
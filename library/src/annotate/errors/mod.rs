@@ -1,6 +1,13 @@
+mod deduplicated;
 mod dyn_annotated;
+mod limited;
 mod message;
+mod partitioned;
 mod recipient;
+mod sorted;
+mod too_many;
 
 #[allow(unused_imports)]
-pub use {dyn_annotated::*, message::*, recipient::*};
+pub use {
+    deduplicated::*, dyn_annotated::*, limited::*, message::*, partitioned::*, recipient::*, sorted::*, too_many::*,
+};
@@ -0,0 +1,69 @@
+use super::super::diagnostics::*;
+
+use kutil::std::error::*;
+
+//
+// PartitionedErrors
+//
+
+/// An [ErrorRecipient] that partitions by [DiagnosticSeverity].
+///
+/// If [fail_fast](Self::fail_fast) is true then errors are returned rather than accumulated, while
+/// warnings and infos are still accumulated.
+#[derive(Clone, Debug)]
+pub struct PartitionedErrors<ErrorT> {
+    /// Errors.
+    pub errors: Errors<ErrorT>,
+
+    /// Warnings.
+    pub warnings: Errors<ErrorT>,
+
+    /// Infos.
+    pub infos: Errors<ErrorT>,
+
+    /// Fail fast on errors.
+    pub fail_fast: bool,
+}
+
+impl<ErrorT> PartitionedErrors<ErrorT> {
+    /// Set fail fast on errors.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// True if there are no errors (there may be warnings and infos).
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// True if there are no errors, warnings, or infos.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty() && self.infos.is_empty()
+    }
+}
+
+impl<ErrorT> Default for PartitionedErrors<ErrorT> {
+    fn default() -> Self {
+        Self { errors: Default::default(), warnings: Default::default(), infos: Default::default(), fail_fast: false }
+    }
+}
+
+impl<ErrorT> ErrorRecipient<ErrorT> for PartitionedErrors<ErrorT>
+where
+    ErrorT: ReportDiagnostic,
+{
+    fn give_error(&mut self, error: ErrorT) -> Result<(), ErrorT> {
+        match error.diagnostic_severity() {
+            DiagnosticSeverity::Error => {
+                if self.fail_fast {
+                    return Err(error);
+                }
+                self.errors.give_error(error)
+            }
+
+            DiagnosticSeverity::Warning => self.warnings.give_error(error),
+            DiagnosticSeverity::Info => self.infos.give_error(error),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use super::super::annotated::*;
+
+use kutil::std::error::*;
+
+//
+// SortedErrors
+//
+
+/// An [ErrorRecipient] that accumulates errors and sorts them when finished.
+///
+/// Errors are sorted by the source and then the span in their
+/// [Annotations](super::super::annotations::Annotations). Errors without annotations come first.
+/// The sort is stable, so otherwise the order in which errors were given is kept.
+#[derive(Clone, Debug)]
+pub struct SortedErrors<ErrorT> {
+    /// Errors (unsorted).
+    pub errors: Vec<ErrorT>,
+}
+
+impl<ErrorT> SortedErrors<ErrorT> {
+    /// True if there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Finish.
+    pub fn finish(mut self) -> Errors<ErrorT>
+    where
+        ErrorT: Annotated,
+    {
+        self.errors.sort_by(|a, b| {
+            let a = a.annotations().map(|annotations| (&annotations.source, annotations.source_id, &annotations.span));
+            let b = b.annotations().map(|annotations| (&annotations.source, annotations.source_id, &annotations.span));
+            a.cmp(&b)
+        });
+
+        Errors { errors: self.errors }
+    }
+}
+
+impl<ErrorT> Default for SortedErrors<ErrorT> {
+    fn default() -> Self {
+        Self { errors: Default::default() }
+    }
+}
+
+impl<ErrorT> ErrorRecipient<ErrorT> for SortedErrors<ErrorT> {
+    fn give_error(&mut self, error: ErrorT) -> Result<(), ErrorT> {
+        self.errors.push(error);
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::*;
+
+//
+// TooManyErrorsError
+//
+
+/// Too many errors.
+///
+/// Returned by [LimitedErrorRecipient](super::limited::LimitedErrorRecipient) when its limit is
+/// exceeded.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("too many errors (more than {limit})")]
+pub struct TooManyErrorsError {
+    /// Limit.
+    pub limit: usize,
+}
+
+impl TooManyErrorsError {
+    /// Constructor.
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+}
@@ -2,12 +2,14 @@ mod annotate;
 mod annotated;
 mod annotations;
 mod depict;
+mod diagnostics;
 mod dyn_annotated;
 mod errors;
 mod label;
 mod location;
 mod macros;
 mod maybe;
+mod sources;
 mod span;
 mod r#struct;
 mod with;
@@ -20,11 +22,13 @@ pub use {
     annotated::*,
     annotations::*,
     depict::*,
+    diagnostics::*,
     dyn_annotated::*,
     errors::*,
     label::*,
     location::*,
     maybe::*,
+    sources::*,
     span::*,
     r#struct::*,
     with::*,
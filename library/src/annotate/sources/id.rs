@@ -0,0 +1,46 @@
+use {
+    kutil::cli::depict::*,
+    std::{fmt, io},
+};
+
+/// Depict source ID prefix.
+pub const DEPICT_SOURCE_ID_PREFIX: char = '#';
+
+//
+// SourceId
+//
+
+/// Compact identifier of a source in a [SourceRegistry](super::registry::SourceRegistry).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SourceId(pub u32);
+
+impl Depict for SourceId {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+        context.theme.write_delimiter(writer, DEPICT_SOURCE_ID_PREFIX)?;
+        context.theme.write_meta(writer, self.0)
+    }
+}
+
+impl fmt::Display for SourceId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}{}", DEPICT_SOURCE_ID_PREFIX, self.0)
+    }
+}
+
+// Conversions
+
+impl From<u32> for SourceId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<SourceId> for u32 {
+    fn from(id: SourceId) -> Self {
+        id.0
+    }
+}
@@ -0,0 +1,59 @@
+use std::ops::*;
+
+//
+// LineIndex
+//
+
+/// Byte offsets of the starts of lines in a source.
+///
+/// Supports "\n" and "\r\n" line endings.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LineIndex {
+    /// Byte offset of the start of each line.
+    pub starts: Vec<usize>,
+
+    /// Length of the content in bytes.
+    pub length: usize,
+}
+
+impl LineIndex {
+    /// Constructor.
+    pub fn new(content: &[u8]) -> Self {
+        let mut starts = vec![0];
+        starts.extend(content.iter().enumerate().filter(|(_, byte)| **byte == b'\n').map(|(index, _)| index + 1));
+        Self { starts, length: content.len() }
+    }
+
+    /// Number of lines.
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Row (0-based) of the line containing a byte index.
+    pub fn row(&self, index: usize) -> Option<usize> {
+        if index > self.length {
+            return None;
+        }
+
+        Some(match self.starts.binary_search(&index) {
+            Ok(row) => row,
+            Err(row) => row - 1,
+        })
+    }
+
+    /// Byte range of a line (0-based), excluding its line ending.
+    pub fn line_range(&self, row: usize, content: &[u8]) -> Option<Range<usize>> {
+        let start = *self.starts.get(row)?;
+
+        let mut end = match self.starts.get(row + 1) {
+            Some(next) => next - 1,
+            None => self.length,
+        };
+
+        if (end > start) && (content.get(end - 1) == Some(&b'\r')) {
+            end -= 1;
+        }
+
+        Some(start..end)
+    }
+}
@@ -0,0 +1,7 @@
+mod id;
+mod line_index;
+mod registry;
+mod source;
+
+#[allow(unused_imports)]
+pub use {id::*, line_index::*, registry::*, source::*};
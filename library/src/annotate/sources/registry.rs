@@ -0,0 +1,108 @@
+use super::{
+    super::{annotations::*, diagnostics::*},
+    id::*,
+    source::*,
+};
+
+use {
+    kutil::std::{collections::*, immutable::*},
+    std::sync::*,
+};
+
+//
+// SourceRegistry
+//
+
+/// Registry of sources, so that their content is available after parsing (for excerpts, mapping
+/// between byte indexes and rows, reloading, etc.).
+///
+/// A [Parser](super::super::super::parse::Parser) can register into a [SharedSourceRegistry]
+/// automatically.
+///
+/// Sources are identified by a compact [SourceId], which can be stored in
+/// [Annotations::source_id].
+#[derive(Clone, Debug, Default)]
+pub struct SourceRegistry {
+    sources: Vec<RegisteredSource>,
+    names: FastHashMap<ByteString, SourceId>,
+}
+
+impl SourceRegistry {
+    /// Register a source.
+    ///
+    /// If a source with the same name is already registered, its content is replaced and its
+    /// [SourceId] is kept.
+    pub fn register(&mut self, name: Option<ByteString>, media_type: Option<ByteString>, content: Bytes) -> SourceId {
+        if let Some(name) = &name
+            && let Some(id) = self.names.get(name)
+        {
+            let id = *id;
+            self.sources[id.0 as usize] = RegisteredSource::new(id, Some(name.clone()), media_type, content);
+            return id;
+        }
+
+        let id = SourceId(self.sources.len() as u32);
+        if let Some(name) = &name {
+            self.names.insert(name.clone(), id);
+        }
+        self.sources.push(RegisteredSource::new(id, name, media_type, content));
+        id
+    }
+
+    /// Get a source.
+    pub fn get(&self, id: SourceId) -> Option<&RegisteredSource> {
+        self.sources.get(id.0 as usize)
+    }
+
+    /// Get a source by name.
+    pub fn get_by_name(&self, name: &ByteString) -> Option<&RegisteredSource> {
+        self.names.get(name).and_then(|id| self.get(*id))
+    }
+
+    /// Get the source of [Annotations].
+    ///
+    /// Prefers [Annotations::source_id] over [Annotations::source].
+    pub fn get_for(&self, annotations: &Annotations) -> Option<&RegisteredSource> {
+        match annotations.source_id {
+            Some(id) => self.get(id),
+            None => self.get_by_name(annotations.source.as_ref()?),
+        }
+    }
+
+    /// Number of sources.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// True if there are no sources.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Iterate sources in the order of their IDs.
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredSource> {
+        self.sources.iter()
+    }
+
+    /// Into [SharedSourceRegistry].
+    pub fn into_shared(self) -> SharedSourceRegistry {
+        Arc::new(Mutex::new(self))
+    }
+}
+
+impl SourceTexts for SourceRegistry {
+    fn source_text(&self, source: &ByteString) -> Option<&str> {
+        self.get_by_name(source).and_then(|source| source.text())
+    }
+
+    fn annotations_source_text(&self, annotations: &Annotations) -> Option<&str> {
+        self.get_for(annotations).and_then(|source| source.text())
+    }
+}
+
+//
+// SharedSourceRegistry
+//
+
+/// Shared [SourceRegistry].
+pub type SharedSourceRegistry = Arc<Mutex<SourceRegistry>>;
@@ -0,0 +1,96 @@
+use super::{
+    super::{location::*, span::*},
+    id::*,
+    line_index::*,
+};
+
+use {kutil::std::immutable::*, std::str};
+
+//
+// RegisteredSource
+//
+
+/// Source in a [SourceRegistry](super::registry::SourceRegistry).
+#[derive(Clone, Debug)]
+pub struct RegisteredSource {
+    /// ID.
+    pub id: SourceId,
+
+    /// Name (as in [Annotations::source](super::super::annotations::Annotations::source)).
+    pub name: Option<ByteString>,
+
+    /// Media type.
+    pub media_type: Option<ByteString>,
+
+    /// Content.
+    pub content: Bytes,
+
+    /// Line index.
+    pub line_index: LineIndex,
+}
+
+impl RegisteredSource {
+    /// Constructor.
+    pub fn new(id: SourceId, name: Option<ByteString>, media_type: Option<ByteString>, content: Bytes) -> Self {
+        let line_index = LineIndex::new(&content);
+        Self { id, name, media_type, content, line_index }
+    }
+
+    /// Content as text, if it is valid UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        str::from_utf8(&self.content).ok()
+    }
+
+    /// Text of a line (0-based), excluding its line ending.
+    pub fn line(&self, row: usize) -> Option<&str> {
+        let range = self.line_index.line_range(row, &self.content)?;
+        str::from_utf8(&self.content[range]).ok()
+    }
+
+    /// Text of a span.
+    ///
+    /// Requires the span to have byte indexes.
+    pub fn excerpt(&self, span: &Span) -> Option<&str> {
+        let start = span.start.index?;
+        let end = match &span.end {
+            Some(end) => end.index?,
+            None => start,
+        };
+        str::from_utf8(self.content.get(start..end)?).ok()
+    }
+
+    /// [Location] of a byte index.
+    ///
+    /// The column counts characters if the line is valid UTF-8, otherwise bytes.
+    pub fn location(&self, index: usize) -> Option<Location> {
+        let row = self.line_index.row(index)?;
+        let start = self.line_index.starts[row];
+
+        let prefix = &self.content[start..index];
+        let column = match str::from_utf8(prefix) {
+            Ok(prefix) => prefix.chars().count(),
+            Err(_) => prefix.len(),
+        };
+
+        Some(Location::new(Some(index), Some(row), Some(column)))
+    }
+
+    /// Byte index of a row and column (both 0-based).
+    ///
+    /// The column counts characters if the line is valid UTF-8, otherwise bytes.
+    pub fn index(&self, row: usize, column: usize) -> Option<usize> {
+        let range = self.line_index.line_range(row, &self.content)?;
+        let line = &self.content[range.clone()];
+
+        let offset = match str::from_utf8(line) {
+            Ok(line) => match line.char_indices().nth(column) {
+                Some((offset, _)) => offset,
+                None => (column == line.chars().count()).then_some(line.len())?,
+            },
+
+            Err(_) => (column <= line.len()).then_some(column)?,
+        };
+
+        Some(range.start + offset)
+    }
+}
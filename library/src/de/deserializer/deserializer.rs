@@ -51,6 +51,16 @@ impl<'de, 'own, AnnotatedT> de::Deserializer<'de> for &'own mut Deserializer<'de
             Variant::Integer(_) => self.deserialize_i64(visitor),
             Variant::UnsignedInteger(_) => self.deserialize_u64(visitor),
             Variant::Float(_) => self.deserialize_f64(visitor),
+            Variant::Number(number) => {
+                if let Ok(integer) = number.as_str().parse() {
+                    visitor.visit_i128(integer)
+                } else if let Ok(unsigned_integer) = number.as_str().parse() {
+                    visitor.visit_u128(unsigned_integer)
+                } else {
+                    // Let the visitor decide how to interpret the exact text
+                    visitor.visit_str(number.into())
+                }
+            }
             Variant::Boolean(_) => self.deserialize_bool(visitor),
             Variant::Text(_) => self.deserialize_str(visitor),
             Variant::Blob(_) => self.deserialize_bytes(visitor),
@@ -358,6 +368,11 @@ impl<'de, 'own, AnnotatedT> de::Deserializer<'de> for &'own mut Deserializer<'de
                 None => Err(self.incompatible_value_error()),
             },
 
+            Variant::Number(number) => match number.cast() {
+                Some(float) => visitor.visit_f32(float),
+                None => Err(self.incompatible_value_error()),
+            },
+
             _ => Err(self.incompatible_type_error()),
         }
     }
@@ -379,10 +394,48 @@ impl<'de, 'own, AnnotatedT> de::Deserializer<'de> for &'own mut Deserializer<'de
                 None => Err(self.incompatible_value_error()),
             },
 
+            Variant::Number(number) => match number.to_f64() {
+                Some(float) => visitor.visit_f64(float),
+                None => Err(self.incompatible_value_error()),
+            },
+
             _ => Err(self.incompatible_type_error()),
         }
     }
 
+    fn deserialize_i128<VisitorT>(self, visitor: VisitorT) -> Result<VisitorT::Value, Self::Error>
+    where
+        VisitorT: de::Visitor<'de>,
+    {
+        match self.variant {
+            Variant::Integer(integer) => visitor.visit_i128(integer.inner as i128),
+            Variant::UnsignedInteger(unsigned_integer) => visitor.visit_i128(unsigned_integer.inner as i128),
+
+            Variant::Number(number) => match number.as_str().parse() {
+                Ok(integer) => visitor.visit_i128(integer),
+                Err(_) => Err(self.incompatible_value_error()),
+            },
+
+            _ => self.deserialize_i64(visitor),
+        }
+    }
+
+    fn deserialize_u128<VisitorT>(self, visitor: VisitorT) -> Result<VisitorT::Value, Self::Error>
+    where
+        VisitorT: de::Visitor<'de>,
+    {
+        match self.variant {
+            Variant::UnsignedInteger(unsigned_integer) => visitor.visit_u128(unsigned_integer.inner as u128),
+
+            Variant::Number(number) => match number.as_str().parse() {
+                Ok(unsigned_integer) => visitor.visit_u128(unsigned_integer),
+                Err(_) => Err(self.incompatible_value_error()),
+            },
+
+            _ => self.deserialize_u64(visitor),
+        }
+    }
+
     fn deserialize_char<VisitorT>(self, _visitor: VisitorT) -> Result<VisitorT::Value, Self::Error>
     where
         VisitorT: de::Visitor<'de>,
@@ -396,6 +449,7 @@ impl<'de, 'own, AnnotatedT> de::Deserializer<'de> for &'own mut Deserializer<'de
     {
         match self.variant {
             Variant::Text(text) => visitor.visit_str(text.into()),
+            Variant::Number(number) => visitor.visit_str(number.into()),
             _ => Err(self.incompatible_type_error()),
         }
     }
@@ -406,6 +460,7 @@ impl<'de, 'own, AnnotatedT> de::Deserializer<'de> for &'own mut Deserializer<'de
     {
         match self.variant {
             Variant::Text(text) => visitor.visit_str(text.into()),
+            Variant::Number(number) => visitor.visit_str(number.into()),
             _ => Err(self.incompatible_type_error()),
         }
     }
@@ -3,14 +3,14 @@ use super::{
     deserializer::*,
 };
 
-use {serde::de, std::collections::*};
+use serde::de;
 
 //
 // MapDeserializer
 //
 
 pub(crate) struct MapDeserializer<'de, AnnotatedT> {
-    iterator: btree_map::Iter<'de, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+    iterator: MapIter<'de, AnnotatedT>,
     current_entry: Option<(&'de Variant<AnnotatedT>, &'de Variant<AnnotatedT>)>,
 }
 
@@ -0,0 +1,171 @@
+use super::super::{annotate::*, normal::*, path::*};
+
+use {
+    kutil::cli::depict::*,
+    std::{fmt, io},
+};
+
+//
+// Change
+//
+
+/// A change between two [Variant] trees.
+///
+/// See [Variant::diff].
+#[derive(Clone, Debug)]
+pub enum Change<'own, AnnotatedT> {
+    /// Only in the new tree.
+    Added {
+        /// Path.
+        path: PathRepresentation,
+
+        /// New value.
+        new: &'own Variant<AnnotatedT>,
+    },
+
+    /// Only in the old tree.
+    Removed {
+        /// Path.
+        path: PathRepresentation,
+
+        /// Old value.
+        old: &'own Variant<AnnotatedT>,
+    },
+
+    /// In both trees but different.
+    Changed {
+        /// Path.
+        path: PathRepresentation,
+
+        /// Old value.
+        old: &'own Variant<AnnotatedT>,
+
+        /// New value.
+        new: &'own Variant<AnnotatedT>,
+    },
+}
+
+impl<'own, AnnotatedT> Change<'own, AnnotatedT> {
+    /// Path.
+    pub fn path(&self) -> &PathRepresentation {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => path,
+        }
+    }
+
+    /// Old value.
+    pub fn old_value(&self) -> Option<&'own Variant<AnnotatedT>> {
+        match self {
+            Self::Added { .. } => None,
+            Self::Removed { old, .. } | Self::Changed { old, .. } => Some(old),
+        }
+    }
+
+    /// New value.
+    pub fn new_value(&self) -> Option<&'own Variant<AnnotatedT>> {
+        match self {
+            Self::Removed { .. } => None,
+            Self::Added { new, .. } | Self::Changed { new, .. } => Some(new),
+        }
+    }
+
+    /// [Annotations] of the old value.
+    pub fn old_annotations(&self) -> Option<&'own Annotations>
+    where
+        AnnotatedT: Annotated,
+    {
+        self.old_value().and_then(|old| old.annotations())
+    }
+
+    /// [Annotations] of the new value.
+    pub fn new_annotations(&self) -> Option<&'own Annotations>
+    where
+        AnnotatedT: Annotated,
+    {
+        self.new_value().and_then(|new| new.annotations())
+    }
+}
+
+impl<'own, AnnotatedT> Depict for Change<'own, AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+
+        match self {
+            Self::Added { .. } => context.theme.write_name(writer, '+')?,
+            Self::Removed { .. } => context.theme.write_error(writer, '-')?,
+            Self::Changed { .. } => context.theme.write_symbol(writer, '~')?,
+        }
+
+        let path = self.path();
+        if !path.segments.is_empty() {
+            write!(writer, " ")?;
+            path.depict(writer, context)?;
+            context.theme.write_delimiter(writer, ':')?;
+        }
+
+        if let Some(old) = self.old_value() {
+            depict_value(old, writer, context)?;
+        }
+
+        if let Self::Changed { .. } = self {
+            write!(writer, " ")?;
+            context.theme.write_delimiter(writer, "⇒")?;
+        }
+
+        if let Some(new) = self.new_value() {
+            depict_value(new, writer, context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'own, AnnotatedT> fmt::Display for Change<'own, AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { .. } => write!(formatter, "+")?,
+            Self::Removed { .. } => write!(formatter, "-")?,
+            Self::Changed { .. } => write!(formatter, "~")?,
+        }
+
+        let path = self.path();
+        if !path.segments.is_empty() {
+            write!(formatter, " {}:", path)?;
+        }
+
+        if let Some(old) = self.old_value() {
+            write!(formatter, " {}", old)?;
+        }
+
+        if let Self::Changed { .. } = self {
+            write!(formatter, " ⇒")?;
+        }
+
+        if let Some(new) = self.new_value() {
+            write!(formatter, " {}", new)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Collections are compact so that each change is a single line
+fn depict_value<AnnotatedT, WriteT>(
+    variant: &Variant<AnnotatedT>,
+    writer: &mut WriteT,
+    context: &DepictionContext,
+) -> io::Result<()>
+where
+    AnnotatedT: Annotated,
+    WriteT: io::Write,
+{
+    let format = if variant.is_collection() { DepictionFormat::Compact } else { DepictionFormat::Optimized };
+    let context = context.child().with_separator(true).with_format(format);
+    AnnotatedDepiction::new(variant, AnnotatedDepictionMode::Inline).depict(writer, &context)
+}
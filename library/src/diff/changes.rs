@@ -0,0 +1,80 @@
+use super::{super::annotate::*, change::*};
+
+use {
+    kutil::{cli::depict::*, std::iter::*},
+    std::{fmt, io},
+};
+
+//
+// Diff
+//
+
+/// The [Change]s between two [Variant](super::super::normal::Variant) trees.
+///
+/// See [Variant::diff](super::super::normal::Variant::diff).
+#[derive(Clone, Debug)]
+pub struct Diff<'own, AnnotatedT> {
+    /// Changes in depth-first order.
+    pub changes: Vec<Change<'own, AnnotatedT>>,
+}
+
+impl<'own, AnnotatedT> Diff<'own, AnnotatedT> {
+    /// True if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<'own, AnnotatedT> Default for Diff<'own, AnnotatedT> {
+    fn default() -> Self {
+        Self { changes: Default::default() }
+    }
+}
+
+impl<'own, AnnotatedT> IntoIterator for Diff<'own, AnnotatedT> {
+    type Item = Change<'own, AnnotatedT>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'this, 'own, AnnotatedT> IntoIterator for &'this Diff<'own, AnnotatedT> {
+    type Item = &'this Change<'own, AnnotatedT>;
+    type IntoIter = std::slice::Iter<'this, Change<'own, AnnotatedT>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+impl<'own, AnnotatedT> Depict for Diff<'own, AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        for (change, first) in IterateWithFirst::new(&self.changes) {
+            context.separate_or_indent(writer, first)?;
+            change.depict(writer, &context.child().with_separator(false))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'own, AnnotatedT> fmt::Display for Diff<'own, AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (change, last) in IterateWithLast::new(&self.changes) {
+            fmt::Display::fmt(change, formatter)?;
+            if !last {
+                writeln!(formatter)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+mod change;
+mod changes;
+mod number;
+mod options;
+mod variant;
+
+#[allow(unused_imports)]
+pub use {change::*, changes::*, options::*, variant::*};
+
+#[allow(unused_imports)]
+pub(crate) use number::*;
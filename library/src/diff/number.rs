@@ -0,0 +1,62 @@
+use super::super::normal::*;
+
+use std::cmp::*;
+
+// Semantic number equality; None if either is not a number
+pub(crate) fn numbers_eq<AnnotatedT>(variant: &Variant<AnnotatedT>, other: &Variant<AnnotatedT>) -> Option<bool> {
+    Some(to_normalized_decimal_parts(variant)? == to_normalized_decimal_parts(other)?)
+}
+
+// Semantic number ordering; None if either is not a number
+pub(crate) fn numbers_cmp<AnnotatedT>(variant: &Variant<AnnotatedT>, other: &Variant<AnnotatedT>) -> Option<Ordering> {
+    if numbers_eq(variant, other)? {
+        return Some(Ordering::Equal);
+    }
+
+    match (to_i128(variant), to_i128(other)) {
+        (Some(integer), Some(other_integer)) => Some(integer.cmp(&other_integer)),
+        _ => to_f64(variant)?.partial_cmp(&to_f64(other)?),
+    }
+}
+
+fn to_i128<AnnotatedT>(variant: &Variant<AnnotatedT>) -> Option<i128> {
+    match variant {
+        Variant::Integer(integer) => Some(integer.inner.into()),
+        Variant::UnsignedInteger(unsigned_integer) => Some(unsigned_integer.inner.into()),
+        _ => None,
+    }
+}
+
+fn to_f64<AnnotatedT>(variant: &Variant<AnnotatedT>) -> Option<f64> {
+    match variant {
+        Variant::Integer(integer) => Some(integer.inner as f64),
+        Variant::UnsignedInteger(unsigned_integer) => Some(unsigned_integer.inner as f64),
+        Variant::Float(float) => Some(float.inner.into()),
+        Variant::Number(number) => number.to_f64(),
+        _ => None,
+    }
+}
+
+// Decimal parts without trailing zeros (and without sign for zero)
+fn to_normalized_decimal_parts<AnnotatedT>(variant: &Variant<AnnotatedT>) -> Option<(bool, String, i64)> {
+    let (negative, digits, exponent) = match variant {
+        Variant::Integer(integer) => decimal_parts(&integer.inner.to_string())?,
+        Variant::UnsignedInteger(unsigned_integer) => decimal_parts(&unsigned_integer.inner.to_string())?,
+        Variant::Float(float) => {
+            if !float.inner.is_finite() {
+                return None;
+            }
+            decimal_parts(&float.inner.to_string())?
+        }
+        Variant::Number(number) => number.to_decimal_parts()?,
+        _ => return None,
+    };
+
+    let trimmed = digits.trim_end_matches('0');
+    if trimmed.is_empty() {
+        Some((false, Default::default(), 0))
+    } else {
+        let exponent = exponent.checked_add((digits.len() - trimmed.len()) as i64)?;
+        Some((negative, trimmed.into(), exponent))
+    }
+}
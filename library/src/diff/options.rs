@@ -0,0 +1,70 @@
+use kutil::std::immutable::*;
+
+//
+// DiffOptions
+//
+
+/// Options for [Variant::diff](super::super::normal::Variant::diff).
+#[derive(Clone, Debug)]
+pub struct DiffOptions {
+    /// Whether to ignore [Annotations](super::super::annotate::Annotations).
+    ///
+    /// When false, values that are equal but have different annotations are reported as changed.
+    /// Lists and maps are always compared by their contents.
+    ///
+    /// Defaults to true.
+    pub ignore_annotations: bool,
+
+    /// Whether to compare numbers by their value regardless of type, e.g. the integer 1 would
+    /// equal the float 1.0.
+    ///
+    /// Defaults to false.
+    pub semantic_numbers: bool,
+
+    /// How to match list items.
+    pub list_matching: ListMatching,
+}
+
+impl DiffOptions {
+    /// Set whether to ignore annotations.
+    pub fn with_ignore_annotations(mut self, ignore_annotations: bool) -> Self {
+        self.ignore_annotations = ignore_annotations;
+        self
+    }
+
+    /// Set whether to compare numbers by value.
+    pub fn with_semantic_numbers(mut self, semantic_numbers: bool) -> Self {
+        self.semantic_numbers = semantic_numbers;
+        self
+    }
+
+    /// Set list matching.
+    pub fn with_list_matching(mut self, list_matching: ListMatching) -> Self {
+        self.list_matching = list_matching;
+        self
+    }
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self { ignore_annotations: true, semantic_numbers: false, list_matching: Default::default() }
+    }
+}
+
+//
+// ListMatching
+//
+
+/// How [Variant::diff](super::super::normal::Variant::diff) matches list items.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ListMatching {
+    /// Match items at the same index.
+    #[default]
+    ByIndex,
+
+    /// Match map items that have the same value for this key, regardless of their index.
+    ///
+    /// Reordering is thus not considered a change. If any item in either list is not a map or
+    /// does not have the key then the list is matched [ByIndex](ListMatching::ByIndex).
+    ByKey(ByteString),
+}
@@ -0,0 +1,172 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    change::*,
+    changes::*,
+    number::*,
+    options::*,
+};
+
+use kutil::std::{collections::*, immutable::*};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Compare this variant (the old tree) with another (the new tree) and return the [Change]s
+    /// needed to get from one to the other.
+    ///
+    /// Maps are compared by key and lists according to [DiffOptions::list_matching]. Anything
+    /// else that is different, including a different type, is a [Change::Changed].
+    ///
+    /// For list items the path segment is the index in the old list (or in the new list for added
+    /// items).
+    pub fn diff<'own>(&'own self, other: &'own Self, options: &DiffOptions) -> Diff<'own, AnnotatedT>
+    where
+        AnnotatedT: Annotated + Default,
+    {
+        let mut diff = Diff::default();
+        self.diff_at(other, options, &mut Default::default(), &mut diff.changes);
+        diff
+    }
+
+    fn diff_at<'own>(
+        &'own self,
+        other: &'own Self,
+        options: &DiffOptions,
+        path: &mut PathRepresentation,
+        changes: &mut Vec<Change<'own, AnnotatedT>>,
+    ) where
+        AnnotatedT: Annotated + Default,
+    {
+        match (self, other) {
+            (Self::List(list), Self::List(other_list)) => list.diff_at(other_list, options, path, changes),
+            (Self::Map(map), Self::Map(other_map)) => map.diff_at(other_map, options, path, changes),
+
+            _ => {
+                if !self.diff_eq(other, options) {
+                    changes.push(Change::Changed { path: path.clone(), old: self, new: other });
+                }
+            }
+        }
+    }
+
+    // Equality according to the options
+    fn diff_eq(&self, other: &Self, options: &DiffOptions) -> bool
+    where
+        AnnotatedT: Annotated + Default,
+    {
+        let equal = if options.semantic_numbers
+            && let Some(equal) = numbers_eq(self, other)
+        {
+            equal
+        } else {
+            self == other
+        };
+
+        equal && (options.ignore_annotations || (self.annotations() == other.annotations()))
+    }
+}
+
+impl<AnnotatedT> List<AnnotatedT> {
+    fn diff_at<'own>(
+        &'own self,
+        other: &'own Self,
+        options: &DiffOptions,
+        path: &mut PathRepresentation,
+        changes: &mut Vec<Change<'own, AnnotatedT>>,
+    ) where
+        AnnotatedT: Annotated + Default,
+    {
+        if let ListMatching::ByKey(key) = &options.list_matching
+            && let Some(keys) = self.diff_keys(key)
+            && let Some(other_keys) = other.diff_keys(key)
+        {
+            let mut matched = vec![false; other_keys.len()];
+
+            // Unmatched indexes per key (in reverse order, so that popping takes the first)
+            let mut other_indexes: FastHashMap<_, Vec<_>> = FastHashMap::default();
+            for (other_index, other_key) in other_keys.into_iter().enumerate().rev() {
+                other_indexes.entry(other_key).or_default().push(other_index);
+            }
+
+            for (index, (item, key)) in self.inner.iter().zip(keys).enumerate() {
+                path.push_list_index(index);
+
+                match other_indexes.get_mut(key).and_then(|other_indexes| other_indexes.pop()) {
+                    Some(other_index) => {
+                        matched[other_index] = true;
+                        item.diff_at(&other.inner[other_index], options, path, changes);
+                    }
+
+                    None => changes.push(Change::Removed { path: path.clone(), old: item }),
+                }
+
+                path.segments.pop();
+            }
+
+            for (index, other_item) in other.inner.iter().enumerate() {
+                if !matched[index] {
+                    path.push_list_index(index);
+                    changes.push(Change::Added { path: path.clone(), new: other_item });
+                    path.segments.pop();
+                }
+            }
+        } else {
+            for index in 0..self.inner.len().max(other.inner.len()) {
+                path.push_list_index(index);
+
+                match (self.inner.get(index), other.inner.get(index)) {
+                    (Some(item), Some(other_item)) => item.diff_at(other_item, options, path, changes),
+                    (Some(item), None) => changes.push(Change::Removed { path: path.clone(), old: item }),
+                    (None, Some(other_item)) => changes.push(Change::Added { path: path.clone(), new: other_item }),
+                    (None, None) => {}
+                }
+
+                path.segments.pop();
+            }
+        }
+    }
+
+    // The values of the key for all items (None if any item is missing it)
+    fn diff_keys(&self, key: &ByteString) -> Option<Vec<&Variant<AnnotatedT>>>
+    where
+        AnnotatedT: Default,
+    {
+        let key = Variant::from(key.clone());
+        self.inner
+            .iter()
+            .map(|item| match item {
+                Variant::Map(map) => map.inner.get(&key),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl<AnnotatedT> Map<AnnotatedT> {
+    fn diff_at<'own>(
+        &'own self,
+        other: &'own Self,
+        options: &DiffOptions,
+        path: &mut PathRepresentation,
+        changes: &mut Vec<Change<'own, AnnotatedT>>,
+    ) where
+        AnnotatedT: Annotated + Default,
+    {
+        for (key, value) in self.inner.iter() {
+            path.push_variant_key(key);
+
+            match other.inner.get(key) {
+                Some(other_value) => value.diff_at(other_value, options, path, changes),
+                None => changes.push(Change::Removed { path: path.clone(), old: value }),
+            }
+
+            path.segments.pop();
+        }
+
+        for (other_key, other_value) in other.inner.iter() {
+            if !self.inner.contains_key(other_key) {
+                path.push_variant_key(other_key);
+                changes.push(Change::Added { path: path.clone(), new: other_value });
+                path.segments.pop();
+            }
+        }
+    }
+}
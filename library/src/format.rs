@@ -38,6 +38,18 @@ impl Format {
     pub fn is_binary(&self) -> bool {
         (*self == Self::CBOR) || (*self == Self::MessagePack)
     }
+
+    /// Media type.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::CBOR => "application/cbor",
+            Self::MessagePack => "application/msgpack",
+            Self::YAML => "application/yaml",
+            Self::JSON => "application/json",
+            Self::XJSON => "application/x-xjson",
+            Self::XML => "application/xml",
+        }
+    }
 }
 
 //
@@ -43,4 +43,16 @@ impl Hints {
             escaped_map: "$$hint.map".into(),
         }
     }
+
+    /// Whether the key is one of the hints, including the escaped hints.
+    pub fn is_hint(&self, key: &str) -> bool {
+        (key == self.integer)
+            || (key == self.escaped_integer)
+            || (key == self.unsigned_integer)
+            || (key == self.escaped_unsigned_integer)
+            || (key == self.bytes)
+            || (key == self.escaped_bytes)
+            || (key == self.map)
+            || (key == self.escaped_map)
+    }
 }
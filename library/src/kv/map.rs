@@ -0,0 +1,69 @@
+use super::{super::normal::*, iterator::*};
+
+//
+// KeyValuePairIteratorForMap
+//
+
+/// A [KeyValuePairIterator] for [Map].
+///
+/// It's just a simple wrapper.
+pub struct KeyValuePairIteratorForMap<'own, AnnotatedT> {
+    /// Inner.
+    pub inner: MapIter<'own, AnnotatedT>,
+}
+
+impl<'own, AnnotatedT> KeyValuePairIteratorForMap<'own, AnnotatedT> {
+    /// Constructor.
+    pub fn new(inner: MapIter<'own, AnnotatedT>) -> Self {
+        Self { inner }
+    }
+
+    /// Constructor.
+    pub fn new_for(map: &'own Map<AnnotatedT>) -> Self {
+        Self::new(map.inner.iter())
+    }
+}
+
+impl<'own, AnnotatedT> KeyValuePairIterator<AnnotatedT> for KeyValuePairIteratorForMap<'own, AnnotatedT> {
+    fn next(
+        &mut self,
+    ) -> Result<
+        Option<(&'own Variant<AnnotatedT>, &'own Variant<AnnotatedT>)>,
+        (MalformedError<AnnotatedT>, &Variant<AnnotatedT>),
+    > {
+        Ok(self.inner.next())
+    }
+}
+
+//
+// IntoKeyValuePairIteratorForMap
+//
+
+/// An [IntoKeyValuePairIterator] for [Map].
+///
+/// It's just a simple wrapper.
+pub struct IntoKeyValuePairIteratorForMap<AnnotatedT> {
+    /// Inner.
+    pub inner: MapIntoIter<AnnotatedT>,
+}
+
+impl<AnnotatedT> IntoKeyValuePairIteratorForMap<AnnotatedT> {
+    /// Constructor.
+    pub fn new(inner: MapIntoIter<AnnotatedT>) -> Self {
+        Self { inner }
+    }
+
+    /// Constructor.
+    pub fn new_for(map: Map<AnnotatedT>) -> Self {
+        Self::new(map.inner.into_iter())
+    }
+}
+
+impl<AnnotatedT> IntoKeyValuePairIterator<AnnotatedT> for IntoKeyValuePairIteratorForMap<AnnotatedT> {
+    fn next(
+        &mut self,
+    ) -> Result<Option<(Variant<AnnotatedT>, Variant<AnnotatedT>)>, (MalformedError<AnnotatedT>, Variant<AnnotatedT>)>
+    {
+        Ok(self.inner.next())
+    }
+}
@@ -1,7 +1,8 @@
 mod btreemap;
 mod hashmap;
 mod iterator;
+mod map;
 mod variant;
 
 #[allow(unused_imports)]
-pub use {btreemap::*, hashmap::*, iterator::*, variant::*};
+pub use {btreemap::*, hashmap::*, iterator::*, map::*, variant::*};
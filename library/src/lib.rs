@@ -41,6 +41,9 @@ pub mod annotate;
 #[cfg(feature = "serde")]
 pub mod de;
 
+/// Structural diff.
+pub mod diff;
+
 /// Hints for extending representation formats (such as XJSON).
 pub mod hints;
 
@@ -56,9 +59,15 @@ pub mod normal;
 /// Parse various formats into normal types.
 pub mod parse;
 
+/// JSON Patch.
+pub mod patch;
+
 /// Path.
 pub mod path;
 
+/// Query normal types (JSONPath, with an extension for non-text map keys).
+pub mod query;
+
 /// Resolve normal types into other types.
 pub mod resolve;
 
@@ -66,5 +75,8 @@ pub mod resolve;
 #[cfg(feature = "serde")]
 pub mod ser;
 
+/// Visit and transform normal types.
+pub mod visit;
+
 #[allow(unused_imports)]
 pub use format::*;
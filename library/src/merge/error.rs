@@ -1,6 +1,6 @@
-use super::super::normal::*;
+use super::super::{annotate::*, normal::*};
 
-use std::{error::*, fmt};
+use std::{borrow::*, error::*, fmt};
 
 //
 // MergeError
@@ -27,3 +27,16 @@ impl<'own, AnnotatedT> fmt::Display for MergeError<'own, AnnotatedT> {
 }
 
 impl<'own, AnnotatedT> Error for MergeError<'own, AnnotatedT> where AnnotatedT: fmt::Debug {}
+
+impl<'own, AnnotatedT> ReportDiagnostic for MergeError<'own, AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn diagnostic_message(&self) -> String {
+        self.to_string()
+    }
+
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>> {
+        self.cause.annotations().map(Cow::Borrowed)
+    }
+}
@@ -16,7 +16,10 @@ impl<AnnotatedT> List<AnnotatedT> {
         AnnotatedT: Clone,
         ErrorRecipientT: ErrorRecipient<MergeError<'own, AnnotatedT>>,
     {
-        match merge_mode.list {
+        let list_merge_mode =
+            if merge_mode.map == MapMergeMode::MergePatch { ListMergeMode::Replace } else { merge_mode.list };
+
+        match list_merge_mode {
             ListMergeMode::Append => {
                 if other.inner.is_empty() {
                     Ok(false)
@@ -65,6 +65,25 @@ impl<AnnotatedT> Map<AnnotatedT> {
         AnnotatedT: Clone,
         ErrorRecipientT: ErrorRecipient<MergeError<'own, AnnotatedT>>,
     {
+        if merge_mode.map == MapMergeMode::MergePatch {
+            return Ok(match other_value {
+                // Null removes the key
+                Variant::Null(_) => self.inner.remove(other_key).is_some(),
+
+                _ => match self.inner.get_mut(other_key) {
+                    Some(value) => value.merge_with_errors(other_value, merge_mode, errors)?,
+
+                    None => {
+                        // Merging into nothing will remove nested nulls
+                        let mut value = Variant::Undefined;
+                        value.merge_with_errors(other_value, merge_mode, errors)?;
+                        self.inner.insert(other_key.clone(), value);
+                        true
+                    }
+                },
+            });
+        }
+
         match self.inner.get_mut(other_key) {
             Some(value) => {
                 // We already have the key, so merge the value
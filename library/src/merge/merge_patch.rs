@@ -0,0 +1,59 @@
+use super::{super::normal::*, mode::*};
+
+use std::fmt;
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Apply a JSON Merge Patch (RFC 7396). Return true if any change happened.
+    ///
+    /// Null values in the patch remove keys, and anything other than a map replaces the existing
+    /// value.
+    ///
+    /// See [MapMergeMode::MergePatch].
+    pub fn merge_patch(&mut self, patch: &Self) -> bool
+    where
+        AnnotatedT: Clone + fmt::Debug,
+    {
+        // Merge patches never cause errors, so unwrap is safe
+        self.merge_with_mode(patch, &MergeMode::merge_patch()).expect("merge_with_mode")
+    }
+
+    /// Generate a JSON Merge Patch (RFC 7396) that would turn this variant into the other
+    /// variant.
+    ///
+    /// Note that merge patches cannot set a value to null (because null removes it), nor can they
+    /// modify lists (only replace them).
+    pub fn diff_merge_patch(&self, other: &Self) -> Self
+    where
+        AnnotatedT: Clone + Default,
+    {
+        match (self, other) {
+            (Self::Map(map), Self::Map(other_map)) => {
+                let mut patch = Map { inner: Default::default(), annotated: other_map.annotated.clone() };
+
+                for key in map.inner.keys() {
+                    if !other_map.inner.contains_key(key) {
+                        patch.inner.insert(key.clone(), Null::default().into());
+                    }
+                }
+
+                for (other_key, other_value) in other_map.inner.iter() {
+                    match map.inner.get(other_key) {
+                        Some(value) => {
+                            if value != other_value {
+                                patch.inner.insert(other_key.clone(), value.diff_merge_patch(other_value));
+                            }
+                        }
+
+                        None => {
+                            patch.inner.insert(other_key.clone(), other_value.clone());
+                        }
+                    }
+                }
+
+                patch.into()
+            }
+
+            _ => other.clone(),
+        }
+    }
+}
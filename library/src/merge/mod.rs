@@ -1,8 +1,9 @@
 mod error;
 mod list;
 mod map;
+mod merge_patch;
 mod mode;
 mod variant;
 
 #[allow(unused_imports)]
-pub use {error::*, list::*, map::*, mode::*, variant::*};
+pub use {error::*, list::*, map::*, merge_patch::*, mode::*, variant::*};
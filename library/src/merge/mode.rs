@@ -12,6 +12,13 @@ pub struct MergeMode {
     pub map: MapMergeMode,
 }
 
+impl MergeMode {
+    /// JSON Merge Patch (RFC 7396) mode.
+    pub fn merge_patch() -> Self {
+        Self { list: ListMergeMode::Replace, map: MapMergeMode::MergePatch }
+    }
+}
+
 //
 // ListMergeMode
 //
@@ -46,4 +53,10 @@ pub enum MapMergeMode {
 
     /// Fail on existing keys.
     FailExisting,
+
+    /// JSON Merge Patch (RFC 7396).
+    ///
+    /// Null values remove keys, and anything other than a map replaces the existing value. Lists
+    /// are thus always replaced regardless of the [ListMergeMode].
+    MergePatch,
 }
@@ -5,7 +5,8 @@ use {kutil::std::error::*, std::fmt};
 impl<AnnotatedT> Variant<AnnotatedT> {
     /// Merge another [Variant] into this [Variant]. Return true if any change happened.
     ///
-    /// This function only affects lists and maps.
+    /// This function only affects lists and maps, unless the map merge mode is
+    /// [MapMergeMode::MergePatch].
     ///
     /// The merging behavior depends on the [MergeMode].
     pub fn merge_with_errors<'own, ErrorRecipientT>(
@@ -21,6 +22,24 @@ impl<AnnotatedT> Variant<AnnotatedT> {
         match (self, other) {
             (Self::List(list), Self::List(other_list)) => list.merge_with_errors(other_list, merge_mode, errors),
             (Self::Map(map), Self::Map(other_map)) => map.merge_with_errors(other_map, merge_mode, errors),
+
+            (variant, Self::Map(other_map)) if merge_mode.map == MapMergeMode::MergePatch => {
+                // Replace with a map and then merge into it
+                let mut map = Map { inner: Default::default(), annotated: other_map.annotated.clone() };
+                map.merge_with_errors(other_map, merge_mode, errors)?;
+                *variant = map.into();
+                Ok(true)
+            }
+
+            (variant, other) if merge_mode.map == MapMergeMode::MergePatch => {
+                if variant == other {
+                    Ok(false)
+                } else {
+                    *variant = other.clone();
+                    Ok(true)
+                }
+            }
+
             _ => Ok(false),
         }
     }
@@ -28,7 +47,8 @@ impl<AnnotatedT> Variant<AnnotatedT> {
     /// Merge another [Variant] into this [Variant] while failing on the first encountered error.
     /// Return true if any change happened.
     ///
-    /// This function only affects lists and maps.
+    /// This function only affects lists and maps, unless the map merge mode is
+    /// [MapMergeMode::MergePatch].
     ///
     /// The merging behavior depends on the [MergeMode].
     pub fn merge_with_mode<'own>(
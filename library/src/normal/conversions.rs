@@ -1,6 +1,6 @@
 use super::{
-    super::annotate::*, blob::*, boolean::*, errors::*, float::*, integer::*, list::*, map::*, null::*, text::*,
-    unsigned_integer::*, variant::*,
+    super::annotate::*, blob::*, boolean::*, errors::*, float::*, integer::*, list::*, map::*, map_inner::*, null::*,
+    number::*, text::*, unsigned_integer::*, variant::*,
 };
 
 use {
@@ -19,6 +19,7 @@ use {
   [Integer];
   [UnsignedInteger];
   [Float];
+  [Number];
   [Boolean];
   [Text];
   [Blob];
@@ -199,13 +200,43 @@ where
                 cast::<f64, _>(float.inner.into()).ok_or_else(|| CastingError::new(variant, name.into()).into())
             }
 
-            _ => {
-                Err(IncompatibleVariantTypeError::new_from(&variant, &["integer", "unsigned integer", "float"]).into())
-            }
+            Variant::Number(number) => number.cast().ok_or_else(|| CastingError::new(variant, name.into()).into()),
+
+            _ => Err(IncompatibleVariantTypeError::new_from(
+                &variant,
+                &["integer", "unsigned integer", "float", "number"],
+            )
+            .into()),
         }
     }
 }
 
+// Variant -> decimals
+
+#[cfg(feature = "rust_decimal")]
+impl<AnnotatedT> TryFrom<Variant<AnnotatedT>> for rust_decimal::Decimal
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = ConversionError<AnnotatedT>;
+
+    fn try_from(variant: Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        Self::try_from(&variant)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<AnnotatedT> TryFrom<Variant<AnnotatedT>> for bigdecimal::BigDecimal
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = ConversionError<AnnotatedT>;
+
+    fn try_from(variant: Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        Self::try_from(&variant)
+    }
+}
+
 // &Variant -> inner types (via cloning or copying)
 
 #[duplicate_item(
@@ -215,8 +246,8 @@ where
   [Boolean]    ["boolean"]  [bool]                                                [normal.inner];
   [Text]       ["text"]     [String]                                              [normal.inner.clone().into()];
   [Text]       ["text"]     [ByteString]                                          [normal.inner.clone()];
-  [List]       ["list"]     [Vec<Variant<AnnotatedT>>]                            [normal.inner.clone()];
-  [Map]        ["Map"]      [BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>]  [normal.inner.clone()];
+  [List]       ["list"]     [Vec<Variant<AnnotatedT>>]                            [normal.inner.to_vec()];
+  [Map]        ["Map"]      [BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>]  [normal.inner.clone().into()];
 )]
 #[allow(unused_variables)]
 impl<AnnotatedT> TryFrom<&Variant<AnnotatedT>> for ToT
@@ -255,7 +286,7 @@ where
     [Text]       ["text"]   [str];
     [Blob]       ["blob"]   [[u8]];
     [List]       ["list"]   [Vec<Variant<AnnotatedT>>];
-    [Map]        ["map"]    [BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>];
+    [Map]        ["map"]    [MapInner<AnnotatedT>];
   )]
 impl<'own, AnnotatedT> TryFrom<&'own Variant<AnnotatedT>> for &'own ToT
 where
@@ -265,7 +296,7 @@ where
 
     fn try_from(variant: &'own Variant<AnnotatedT>) -> Result<Self, Self::Error> {
         match variant {
-            Variant::FromNormalT(normal) => Ok(normal.into()),
+            Variant::FromNormalT(normal) => Ok(&normal.inner),
             _ => Err(IncompatibleVariantTypeError::new_from(variant, &[name]).into()),
         }
     }
@@ -310,7 +341,84 @@ where
                 cast::<f64, _>(float.inner.into()).ok_or_else(|| CastingError::new(variant.clone(), name.into()).into())
             }
 
-            _ => Err(IncompatibleVariantTypeError::new_from(variant, &["integer", "unsigned integer", "float"]).into()),
+            Variant::Number(number) => {
+                number.cast().ok_or_else(|| CastingError::new(variant.clone(), name.into()).into())
+            }
+
+            _ => Err(IncompatibleVariantTypeError::new_from(
+                variant,
+                &["integer", "unsigned integer", "float", "number"],
+            )
+            .into()),
         }
     }
 }
+
+// &Variant -> decimals
+
+#[cfg(feature = "rust_decimal")]
+impl<AnnotatedT> TryFrom<&Variant<AnnotatedT>> for rust_decimal::Decimal
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = ConversionError<AnnotatedT>;
+
+    fn try_from(variant: &Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        let decimal = match variant {
+            Variant::Integer(integer) => Some(integer.inner.into()),
+            Variant::UnsignedInteger(unsigned_integer) => Some(unsigned_integer.inner.into()),
+
+            // Display gives us the shortest representation
+            Variant::Float(float) => float.inner.to_string().parse().ok(),
+
+            Variant::Number(number) => {
+                if number.as_str().contains(['e', 'E']) {
+                    Self::from_scientific(number.as_str()).ok()
+                } else {
+                    // Fails instead of rounding
+                    Self::from_str_exact(number.as_str()).ok()
+                }
+            }
+
+            _ => {
+                return Err(IncompatibleVariantTypeError::new_from(
+                    variant,
+                    &["integer", "unsigned integer", "float", "number"],
+                )
+                .into());
+            }
+        };
+
+        decimal.ok_or_else(|| CastingError::new(variant.clone(), "decimal".into()).into())
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<AnnotatedT> TryFrom<&Variant<AnnotatedT>> for bigdecimal::BigDecimal
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = ConversionError<AnnotatedT>;
+
+    fn try_from(variant: &Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        let decimal = match variant {
+            Variant::Integer(integer) => Some(integer.inner.into()),
+            Variant::UnsignedInteger(unsigned_integer) => Some(unsigned_integer.inner.into()),
+
+            // Display gives us the shortest representation
+            Variant::Float(float) => float.inner.to_string().parse().ok(),
+
+            Variant::Number(number) => number.as_str().parse().ok(),
+
+            _ => {
+                return Err(IncompatibleVariantTypeError::new_from(
+                    variant,
+                    &["integer", "unsigned integer", "float", "number"],
+                )
+                .into());
+            }
+        };
+
+        decimal.ok_or_else(|| CastingError::new(variant.clone(), "big decimal".into()).into())
+    }
+}
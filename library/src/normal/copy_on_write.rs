@@ -0,0 +1,283 @@
+#[cfg(feature = "preserve-order")]
+use super::insertion_order_map::*;
+
+use std::{borrow::*, cmp::*, collections::*, hash::*, ops::*, slice, sync::*};
+
+//
+// CopyOnWrite
+//
+
+/// A copy-on-write wrapper around [Arc].
+///
+/// Cloning is O(1) because the inner value is shared. The first mutation of a shared value
+/// clones it (shallowly, because its own nested values are likewise shared), after which
+/// mutations are direct.
+///
+/// Only cloning requires the inner value to be [Clone]. Mutation does not, because an inner value
+/// can only be shared by cloning, at which point we keep its clone function for later.
+///
+/// Comparison and hashing delegate to the inner value.
+#[derive(Debug)]
+pub struct CopyOnWrite<InnerT> {
+    shared: Arc<Shared<InnerT>>,
+}
+
+impl<InnerT> CopyOnWrite<InnerT> {
+    /// Constructor.
+    pub fn new(inner: InnerT) -> Self {
+        Self { shared: Arc::new(Shared::new(inner, None)) }
+    }
+
+    /// True if the inner value is shared with other clones.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.shared) > 1
+    }
+
+    /// True if both share the same inner value.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.shared, &other.shared)
+    }
+
+    /// Into inner value.
+    ///
+    /// Clones it only if it is shared.
+    pub fn into_inner(self) -> InnerT {
+        match Arc::try_unwrap(self.shared) {
+            Ok(shared) => shared.inner,
+            Err(shared) => shared.clone_inner(),
+        }
+    }
+
+    /// Mutable inner value.
+    ///
+    /// Clones it first only if it is shared.
+    pub fn make_mut(&mut self) -> &mut InnerT {
+        if Arc::get_mut(&mut self.shared).is_none() {
+            self.shared = Arc::new(Shared::new(self.shared.clone_inner(), self.shared.clone.get().copied()));
+        }
+
+        &mut Arc::get_mut(&mut self.shared).expect("not shared").inner
+    }
+}
+
+impl<InnerT> Clone for CopyOnWrite<InnerT>
+where
+    InnerT: Clone,
+{
+    fn clone(&self) -> Self {
+        self.shared.clone.get_or_init(|| InnerT::clone);
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<InnerT> Default for CopyOnWrite<InnerT>
+where
+    InnerT: Default,
+{
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<InnerT> Deref for CopyOnWrite<InnerT> {
+    type Target = InnerT;
+
+    fn deref(&self) -> &Self::Target {
+        &self.shared.inner
+    }
+}
+
+impl<InnerT> DerefMut for CopyOnWrite<InnerT> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.make_mut()
+    }
+}
+
+impl<InnerT> Borrow<InnerT> for CopyOnWrite<InnerT> {
+    fn borrow(&self) -> &InnerT {
+        self
+    }
+}
+
+impl<InnerT> PartialEq for CopyOnWrite<InnerT>
+where
+    InnerT: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || (**self).eq(&**other)
+    }
+}
+
+impl<InnerT> Eq for CopyOnWrite<InnerT> where InnerT: Eq {}
+
+impl<InnerT> PartialOrd for CopyOnWrite<InnerT>
+where
+    InnerT: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<InnerT> Ord for CopyOnWrite<InnerT>
+where
+    InnerT: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<InnerT> Hash for CopyOnWrite<InnerT>
+where
+    InnerT: Hash,
+{
+    fn hash<HasherT>(&self, state: &mut HasherT)
+    where
+        HasherT: Hasher,
+    {
+        (**self).hash(state);
+    }
+}
+
+impl<InnerT> IntoIterator for CopyOnWrite<InnerT>
+where
+    InnerT: IntoIterator,
+{
+    type Item = InnerT::Item;
+    type IntoIter = InnerT::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+impl<'own, ItemT> IntoIterator for &'own CopyOnWrite<Vec<ItemT>> {
+    type Item = &'own ItemT;
+    type IntoIter = slice::Iter<'own, ItemT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shared.inner.iter()
+    }
+}
+
+impl<'own, ItemT> IntoIterator for &'own mut CopyOnWrite<Vec<ItemT>> {
+    type Item = &'own mut ItemT;
+    type IntoIter = slice::IterMut<'own, ItemT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.make_mut().iter_mut()
+    }
+}
+
+impl<'own, KeyT, ValueT> IntoIterator for &'own CopyOnWrite<BTreeMap<KeyT, ValueT>> {
+    type Item = (&'own KeyT, &'own ValueT);
+    type IntoIter = btree_map::Iter<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shared.inner.iter()
+    }
+}
+
+impl<'own, KeyT, ValueT> IntoIterator for &'own mut CopyOnWrite<BTreeMap<KeyT, ValueT>> {
+    type Item = (&'own KeyT, &'own mut ValueT);
+    type IntoIter = btree_map::IterMut<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.make_mut().iter_mut()
+    }
+}
+
+#[cfg(feature = "preserve-order")]
+impl<'own, KeyT, ValueT> IntoIterator for &'own CopyOnWrite<InsertionOrderMap<KeyT, ValueT>> {
+    type Item = (&'own KeyT, &'own ValueT);
+    type IntoIter = indexmap::map::Iter<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shared.inner.iter()
+    }
+}
+
+#[cfg(feature = "preserve-order")]
+impl<'own, KeyT, ValueT> IntoIterator for &'own mut CopyOnWrite<InsertionOrderMap<KeyT, ValueT>> {
+    type Item = (&'own KeyT, &'own mut ValueT);
+    type IntoIter = indexmap::map::IterMut<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.make_mut().iter_mut()
+    }
+}
+
+impl<ItemT, InnerT> FromIterator<ItemT> for CopyOnWrite<InnerT>
+where
+    InnerT: FromIterator<ItemT>,
+{
+    fn from_iter<IntoIteratorT>(iterator: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = ItemT>,
+    {
+        Self::new(InnerT::from_iter(iterator))
+    }
+}
+
+impl<ItemT, InnerT> Extend<ItemT> for CopyOnWrite<InnerT>
+where
+    InnerT: Extend<ItemT>,
+{
+    fn extend<IntoIteratorT>(&mut self, iterator: IntoIteratorT)
+    where
+        IntoIteratorT: IntoIterator<Item = ItemT>,
+    {
+        self.make_mut().extend(iterator);
+    }
+}
+
+// Conversions
+
+impl<InnerT> From<InnerT> for CopyOnWrite<InnerT> {
+    fn from(inner: InnerT) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<ItemT> From<CopyOnWrite<Vec<ItemT>>> for Vec<ItemT> {
+    fn from(vector: CopyOnWrite<Vec<ItemT>>) -> Self {
+        vector.into_inner()
+    }
+}
+
+impl<KeyT, ValueT> From<CopyOnWrite<BTreeMap<KeyT, ValueT>>> for BTreeMap<KeyT, ValueT> {
+    fn from(map: CopyOnWrite<BTreeMap<KeyT, ValueT>>) -> Self {
+        map.into_inner()
+    }
+}
+
+#[cfg(feature = "preserve-order")]
+impl<KeyT, ValueT> From<CopyOnWrite<InsertionOrderMap<KeyT, ValueT>>> for InsertionOrderMap<KeyT, ValueT> {
+    fn from(map: CopyOnWrite<InsertionOrderMap<KeyT, ValueT>>) -> Self {
+        map.into_inner()
+    }
+}
+
+//
+// Shared
+//
+
+#[derive(Debug)]
+struct Shared<InnerT> {
+    inner: InnerT,
+
+    // Set when first cloned
+    clone: OnceLock<fn(&InnerT) -> InnerT>,
+}
+
+impl<InnerT> Shared<InnerT> {
+    fn new(inner: InnerT, clone: Option<fn(&InnerT) -> InnerT>) -> Self {
+        Self { inner, clone: clone.map(OnceLock::from).unwrap_or_default() }
+    }
+
+    fn clone_inner(&self) -> InnerT {
+        let clone = self.clone.get().expect("shared but never cloned");
+        clone(&self.inner)
+    }
+}
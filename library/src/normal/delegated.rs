@@ -22,6 +22,7 @@ where
             Self::Integer(integer) => integer.annotations(),
             Self::UnsignedInteger(unsigned_integer) => unsigned_integer.annotations(),
             Self::Float(float) => float.annotations(),
+            Self::Number(number) => number.annotations(),
             Self::Boolean(boolean) => boolean.annotations(),
             Self::Text(text) => text.annotations(),
             Self::Blob(blob) => blob.annotations(),
@@ -37,6 +38,7 @@ where
             Self::Integer(integer) => integer.annotations_mut(),
             Self::UnsignedInteger(unsigned_integer) => unsigned_integer.annotations_mut(),
             Self::Float(float) => float.annotations_mut(),
+            Self::Number(number) => number.annotations_mut(),
             Self::Boolean(boolean) => boolean.annotations_mut(),
             Self::Text(text) => text.annotations_mut(),
             Self::Blob(blob) => blob.annotations_mut(),
@@ -60,6 +62,7 @@ impl<AnnotatedT> Depict for Variant<AnnotatedT> {
             Self::Integer(integer) => integer.depict(writer, context),
             Self::UnsignedInteger(unsigned_integer) => unsigned_integer.depict(writer, context),
             Self::Float(float) => float.depict(writer, context),
+            Self::Number(number) => number.depict(writer, context),
             Self::Boolean(boolean) => boolean.depict(writer, context),
             Self::Text(text) => text.depict(writer, context),
             Self::Blob(blob) => blob.depict(writer, context),
@@ -78,6 +81,7 @@ impl<AnnotatedT> PartialEq for Variant<AnnotatedT> {
                 unsigned_integer == other_unsigned_integer
             }
             (Self::Float(float), Self::Float(other_float)) => float == other_float,
+            (Self::Number(number), Self::Number(other_number)) => number == other_number,
             (Self::Boolean(boolean), Self::Boolean(other_boolean)) => boolean == other_boolean,
             (Self::Text(text), Self::Text(other_text)) => text == other_text,
             (Self::Blob(blob), Self::Blob(other_blob)) => blob == other_blob,
@@ -99,6 +103,7 @@ impl<AnnotatedT> PartialOrd for Variant<AnnotatedT> {
                 unsigned_integer.partial_cmp(other_unsigned_integer)
             }
             (Self::Float(float), Self::Float(other_float)) => float.partial_cmp(other_float),
+            (Self::Number(number), Self::Number(other_number)) => number.partial_cmp(other_number),
             (Self::Boolean(boolean), Self::Boolean(other_boolean)) => boolean.partial_cmp(other_boolean),
             (Self::Text(text), Self::Text(other_text)) => text.partial_cmp(other_text),
             (Self::Blob(blob), Self::Blob(other_blob)) => blob.partial_cmp(other_blob),
@@ -118,6 +123,7 @@ impl<AnnotatedT> Ord for Variant<AnnotatedT> {
                 unsigned_integer.cmp(other_unsigned_integer)
             }
             (Self::Float(float), Self::Float(other_float)) => float.cmp(other_float),
+            (Self::Number(number), Self::Number(other_number)) => number.cmp(other_number),
             (Self::Boolean(boolean), Self::Boolean(other_boolean)) => boolean.cmp(other_boolean),
             (Self::Text(text), Self::Text(other_text)) => text.cmp(other_text),
             (Self::Blob(blob), Self::Blob(other_blob)) => blob.cmp(other_blob),
@@ -141,9 +147,20 @@ impl<AnnotatedT> Ord for Variant<AnnotatedT> {
             (Self::Float(_), _) => Ordering::Less,
 
             (
-                Self::Boolean(_),
+                Self::Number(_),
                 Self::Undefined | Self::Null(_) | Self::Integer(_) | Self::UnsignedInteger(_) | Self::Float(_),
             ) => Ordering::Greater,
+            (Self::Number(_), _) => Ordering::Less,
+
+            (
+                Self::Boolean(_),
+                Self::Undefined
+                | Self::Null(_)
+                | Self::Integer(_)
+                | Self::UnsignedInteger(_)
+                | Self::Float(_)
+                | Self::Number(_),
+            ) => Ordering::Greater,
             (Self::Boolean(_), _) => Ordering::Less,
 
             (
@@ -153,6 +170,7 @@ impl<AnnotatedT> Ord for Variant<AnnotatedT> {
                 | Self::Integer(_)
                 | Self::UnsignedInteger(_)
                 | Self::Float(_)
+                | Self::Number(_)
                 | Self::Boolean(_),
             ) => Ordering::Greater,
             (Self::Text(_), _) => Ordering::Less,
@@ -164,6 +182,7 @@ impl<AnnotatedT> Ord for Variant<AnnotatedT> {
                 | Self::Integer(_)
                 | Self::UnsignedInteger(_)
                 | Self::Float(_)
+                | Self::Number(_)
                 | Self::Boolean(_)
                 | Self::Text(_),
             ) => Ordering::Greater,
@@ -176,6 +195,7 @@ impl<AnnotatedT> Ord for Variant<AnnotatedT> {
                 | Self::Integer(_)
                 | Self::UnsignedInteger(_)
                 | Self::Float(_)
+                | Self::Number(_)
                 | Self::Boolean(_)
                 | Self::Text(_)
                 | Self::Blob(_),
@@ -240,6 +260,11 @@ impl<AnnotatedT> Hash for Variant<AnnotatedT> {
                 state.write_u8(10);
                 map.hash(state);
             }
+
+            Self::Number(number) => {
+                state.write_u8(11);
+                number.hash(state);
+            }
         }
     }
 }
@@ -252,6 +277,7 @@ impl<AnnotatedT> fmt::Display for Variant<AnnotatedT> {
             Self::Integer(integer) => fmt::Display::fmt(integer, formatter),
             Self::UnsignedInteger(unsigned_integer) => fmt::Display::fmt(unsigned_integer, formatter),
             Self::Float(float) => fmt::Display::fmt(float, formatter),
+            Self::Number(number) => fmt::Display::fmt(number, formatter),
             Self::Boolean(boolean) => fmt::Display::fmt(boolean, formatter),
             Self::Text(text) => fmt::Display::fmt(text, formatter),
             Self::Blob(blob) => fmt::Display::fmt(blob, formatter),
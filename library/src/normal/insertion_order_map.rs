@@ -0,0 +1,225 @@
+use {
+    indexmap::{map, *},
+    std::{borrow::*, cmp::*, collections::*, hash::*, ops::*},
+};
+
+//
+// InsertionOrderMap
+//
+
+/// A map that iterates in insertion order.
+///
+/// This is a thin wrapper around [IndexMap]. Lookups are O(1).
+///
+/// Unlike [IndexMap], comparison and hashing ignore the order, so that it can be used as a
+/// drop-in replacement for [BTreeMap] (which is always sorted). [InsertionOrderMap::remove]
+/// preserves the order of the remaining entries.
+///
+/// Note that [Ord] and [Hash] sort the entries on every call, which is O(n log n) and allocates.
+/// Avoid using large maps as keys of other maps if that matters to you.
+#[derive(Clone, Debug)]
+pub struct InsertionOrderMap<KeyT, ValueT> {
+    /// Inner.
+    pub inner: IndexMap<KeyT, ValueT>,
+}
+
+impl<KeyT, ValueT> InsertionOrderMap<KeyT, ValueT> {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self { inner: IndexMap::new() }
+    }
+
+    /// Remove while preserving the order of the remaining entries.
+    ///
+    /// This is O(n). See [IndexMap::swap_remove] for an O(1) alternative.
+    pub fn remove<QueryT>(&mut self, key: &QueryT) -> Option<ValueT>
+    where
+        KeyT: Borrow<QueryT> + Hash + Eq,
+        QueryT: ?Sized + Hash + Eq,
+    {
+        self.inner.shift_remove(key)
+    }
+
+    /// Remove an entry while preserving the order of the remaining entries.
+    ///
+    /// This is O(n). See [IndexMap::swap_remove_entry] for an O(1) alternative.
+    pub fn remove_entry<QueryT>(&mut self, key: &QueryT) -> Option<(KeyT, ValueT)>
+    where
+        KeyT: Borrow<QueryT> + Hash + Eq,
+        QueryT: ?Sized + Hash + Eq,
+    {
+        self.inner.shift_remove_entry(key)
+    }
+
+    // Sorted entries
+    fn sorted(&self) -> Vec<(&KeyT, &ValueT)>
+    where
+        KeyT: Ord,
+    {
+        let mut entries: Vec<_> = self.inner.iter().collect();
+        entries.sort_by_key(|(key, _)| *key);
+        entries
+    }
+}
+
+impl<KeyT, ValueT> Default for InsertionOrderMap<KeyT, ValueT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<KeyT, ValueT> Deref for InsertionOrderMap<KeyT, ValueT> {
+    type Target = IndexMap<KeyT, ValueT>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<KeyT, ValueT> DerefMut for InsertionOrderMap<KeyT, ValueT> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<KeyT, ValueT> PartialEq for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+    ValueT: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Note: IndexMap's equality already ignores order
+        self.inner.eq(&other.inner)
+    }
+}
+
+impl<KeyT, ValueT> Eq for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+    ValueT: Eq,
+{
+}
+
+impl<KeyT, ValueT> PartialOrd for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Ord,
+    ValueT: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<KeyT, ValueT> Ord for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Ord,
+    ValueT: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Same as BTreeMap
+        self.sorted().cmp(&other.sorted())
+    }
+}
+
+impl<KeyT, ValueT> Hash for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Ord,
+    ValueT: Hash,
+{
+    fn hash<HasherT>(&self, state: &mut HasherT)
+    where
+        HasherT: Hasher,
+    {
+        // Same as BTreeMap
+        state.write_usize(self.inner.len());
+        for entry in self.sorted() {
+            entry.hash(state);
+        }
+    }
+}
+
+impl<KeyT, ValueT> IntoIterator for InsertionOrderMap<KeyT, ValueT> {
+    type Item = (KeyT, ValueT);
+    type IntoIter = map::IntoIter<KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'own, KeyT, ValueT> IntoIterator for &'own InsertionOrderMap<KeyT, ValueT> {
+    type Item = (&'own KeyT, &'own ValueT);
+    type IntoIter = map::Iter<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'own, KeyT, ValueT> IntoIterator for &'own mut InsertionOrderMap<KeyT, ValueT> {
+    type Item = (&'own KeyT, &'own mut ValueT);
+    type IntoIter = map::IterMut<'own, KeyT, ValueT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+impl<KeyT, ValueT> FromIterator<(KeyT, ValueT)> for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+{
+    fn from_iter<IntoIteratorT>(iterator: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = (KeyT, ValueT)>,
+    {
+        Self { inner: IndexMap::from_iter(iterator) }
+    }
+}
+
+impl<KeyT, ValueT> Extend<(KeyT, ValueT)> for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+{
+    fn extend<IntoIteratorT>(&mut self, iterator: IntoIteratorT)
+    where
+        IntoIteratorT: IntoIterator<Item = (KeyT, ValueT)>,
+    {
+        self.inner.extend(iterator);
+    }
+}
+
+// Conversions
+
+impl<const SIZE: usize, KeyT, ValueT> From<[(KeyT, ValueT); SIZE]> for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+{
+    fn from(array: [(KeyT, ValueT); SIZE]) -> Self {
+        Self { inner: IndexMap::from(array) }
+    }
+}
+
+impl<KeyT, ValueT> From<IndexMap<KeyT, ValueT>> for InsertionOrderMap<KeyT, ValueT> {
+    fn from(inner: IndexMap<KeyT, ValueT>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<KeyT, ValueT> From<BTreeMap<KeyT, ValueT>> for InsertionOrderMap<KeyT, ValueT>
+where
+    KeyT: Hash + Eq,
+{
+    fn from(map: BTreeMap<KeyT, ValueT>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<KeyT, ValueT> From<InsertionOrderMap<KeyT, ValueT>> for BTreeMap<KeyT, ValueT>
+where
+    KeyT: Ord,
+{
+    fn from(map: InsertionOrderMap<KeyT, ValueT>) -> Self {
+        map.into_iter().collect()
+    }
+}
@@ -55,7 +55,7 @@ impl<AnnotatedT> IntoVariantIterator<AnnotatedT> {
     /// Constructor.
     pub fn new(variant: Variant<AnnotatedT>) -> Self {
         match variant {
-            Variant::List(list) => Self::Iterator(list.inner.into_iter()),
+            Variant::List(list) => Self::Iterator(list.into_iter()),
             _ => Self::Variant(Some(variant)),
         }
     }
@@ -1,5 +1,5 @@
 use {
-    super::{super::annotate::*, depict::*, map::*, variant::*},
+    super::{super::annotate::*, depict::*, list_inner::*, map::*, variant::*},
     crate::impl_normal,
 };
 
@@ -19,7 +19,7 @@ impl_normal! {
     /// Normal list variant.
     ///
     /// [Annotations], if present, are *ignored* for the purposes of comparison and hashing.
-    List(Vec<Variant<AnnotatedT>>)
+    List(ListInner<AnnotatedT>)
 }
 
 impl<AnnotatedT> List<AnnotatedT> {
@@ -167,6 +167,21 @@ impl<'own, AnnotatedT> IntoIterator for &'own mut List<AnnotatedT> {
 
 // Conversions
 
+impl<AnnotatedT> From<Vec<Variant<AnnotatedT>>> for List<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    fn from(vector: Vec<Variant<AnnotatedT>>) -> Self {
+        ListInner::from(vector).into()
+    }
+}
+
+impl<AnnotatedT> From<List<AnnotatedT>> for Vec<Variant<AnnotatedT>> {
+    fn from(list: List<AnnotatedT>) -> Self {
+        list.inner.into()
+    }
+}
+
 impl<AnnotatedT> FromIterator<Variant<AnnotatedT>> for List<AnnotatedT>
 where
     AnnotatedT: Default,
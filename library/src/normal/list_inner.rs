@@ -0,0 +1,167 @@
+use super::variant::*;
+
+#[cfg(feature = "copy-on-write")]
+use super::copy_on_write::*;
+
+use std::{cmp::*, fmt, hash::*, ops::*, slice, vec};
+
+//
+// ListInner
+//
+
+/// Inner representation of [List](super::List).
+///
+/// Dereferences to a [Vec] regardless of the enabled features. Migrating from [Vec]: convert
+/// with [Into] when assigning and with [into_vec](Self::into_vec) when taking ownership.
+///
+/// With the "copy-on-write" feature cloning is O(1) because the items are shared. They are copied
+/// lazily on first mutation.
+#[derive(Clone)]
+pub struct ListInner<AnnotatedT> {
+    items: Items<AnnotatedT>,
+}
+
+#[cfg(not(feature = "copy-on-write"))]
+type Items<AnnotatedT> = Vec<Variant<AnnotatedT>>;
+
+#[cfg(feature = "copy-on-write")]
+type Items<AnnotatedT> = CopyOnWrite<Vec<Variant<AnnotatedT>>>;
+
+impl<AnnotatedT> ListInner<AnnotatedT> {
+    /// Into [Vec].
+    #[cfg(not(feature = "copy-on-write"))]
+    pub fn into_vec(self) -> Vec<Variant<AnnotatedT>> {
+        self.items
+    }
+
+    /// Into [Vec].
+    ///
+    /// With the "copy-on-write" feature the items are cloned only if they are shared.
+    #[cfg(feature = "copy-on-write")]
+    pub fn into_vec(self) -> Vec<Variant<AnnotatedT>> {
+        self.items.into_inner()
+    }
+}
+
+impl<AnnotatedT> Default for ListInner<AnnotatedT> {
+    fn default() -> Self {
+        Vec::default().into()
+    }
+}
+
+impl<AnnotatedT> Deref for ListInner<AnnotatedT> {
+    type Target = Vec<Variant<AnnotatedT>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<AnnotatedT> DerefMut for ListInner<AnnotatedT> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
+impl<AnnotatedT> fmt::Debug for ListInner<AnnotatedT>
+where
+    AnnotatedT: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
+
+impl<AnnotatedT> PartialEq for ListInner<AnnotatedT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items.eq(&other.items)
+    }
+}
+
+impl<AnnotatedT> Eq for ListInner<AnnotatedT> {}
+
+impl<AnnotatedT> PartialOrd for ListInner<AnnotatedT> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<AnnotatedT> Ord for ListInner<AnnotatedT> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.items.cmp(&other.items)
+    }
+}
+
+impl<AnnotatedT> Hash for ListInner<AnnotatedT> {
+    fn hash<HasherT>(&self, state: &mut HasherT)
+    where
+        HasherT: Hasher,
+    {
+        self.items.hash(state);
+    }
+}
+
+impl<AnnotatedT> IntoIterator for ListInner<AnnotatedT> {
+    type Item = Variant<AnnotatedT>;
+    type IntoIter = vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'own, AnnotatedT> IntoIterator for &'own ListInner<AnnotatedT> {
+    type Item = &'own Variant<AnnotatedT>;
+    type IntoIter = slice::Iter<'own, Variant<AnnotatedT>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'own, AnnotatedT> IntoIterator for &'own mut ListInner<AnnotatedT> {
+    type Item = &'own mut Variant<AnnotatedT>;
+    type IntoIter = slice::IterMut<'own, Variant<AnnotatedT>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<AnnotatedT> FromIterator<Variant<AnnotatedT>> for ListInner<AnnotatedT> {
+    fn from_iter<IntoIteratorT>(iterator: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = Variant<AnnotatedT>>,
+    {
+        Vec::from_iter(iterator).into()
+    }
+}
+
+impl<AnnotatedT> Extend<Variant<AnnotatedT>> for ListInner<AnnotatedT> {
+    fn extend<IntoIteratorT>(&mut self, iterator: IntoIteratorT)
+    where
+        IntoIteratorT: IntoIterator<Item = Variant<AnnotatedT>>,
+    {
+        (**self).extend(iterator);
+    }
+}
+
+// Conversions
+
+impl<AnnotatedT> From<Vec<Variant<AnnotatedT>>> for ListInner<AnnotatedT> {
+    #[cfg(not(feature = "copy-on-write"))]
+    fn from(vector: Vec<Variant<AnnotatedT>>) -> Self {
+        Self { items: vector }
+    }
+
+    #[cfg(feature = "copy-on-write")]
+    fn from(vector: Vec<Variant<AnnotatedT>>) -> Self {
+        Self { items: CopyOnWrite::from(vector) }
+    }
+}
+
+impl<AnnotatedT> From<ListInner<AnnotatedT>> for Vec<Variant<AnnotatedT>> {
+    fn from(inner: ListInner<AnnotatedT>) -> Self {
+        inner.into_vec()
+    }
+}
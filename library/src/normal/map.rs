@@ -4,6 +4,7 @@ use {
         depict::*,
         errors::*,
         list::*,
+        map_inner::*,
         variant::*,
     },
     crate::impl_normal,
@@ -27,7 +28,7 @@ impl_normal! {
     /// Normal map variant.
     ///
     /// [Annotations], if present, are *ignored* for the purposes of comparison and hashing.
-    Map(BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>)
+    Map(MapInner<AnnotatedT>)
 }
 
 impl<AnnotatedT> Map<AnnotatedT> {
@@ -39,6 +40,14 @@ impl<AnnotatedT> Map<AnnotatedT> {
         self.inner.get(&key.into())
     }
 
+    /// Get entry.
+    pub fn get_entry<KeyT>(&self, key: KeyT) -> Option<(&Variant<AnnotatedT>, &Variant<AnnotatedT>)>
+    where
+        KeyT: Into<Variant<AnnotatedT>>,
+    {
+        self.inner.get_key_value(&key.into())
+    }
+
     /// Insert.
     pub fn into_insert<KeyT, ValueT>(&mut self, key: KeyT, value: ValueT) -> Option<Variant<AnnotatedT>>
     where
@@ -56,6 +65,14 @@ impl<AnnotatedT> Map<AnnotatedT> {
         self.inner.remove(&key.into())
     }
 
+    /// Remove entry.
+    pub fn into_remove_entry<KeyT>(&mut self, key: KeyT) -> Option<(Variant<AnnotatedT>, Variant<AnnotatedT>)>
+    where
+        KeyT: Into<Variant<AnnotatedT>>,
+    {
+        self.inner.remove_entry(&key.into())
+    }
+
     /// True if any of the map keys is a collection.
     pub fn has_a_collection_key(&self) -> bool {
         for key in self.inner.keys() {
@@ -151,7 +168,7 @@ impl<AnnotatedT> fmt::Display for Map<AnnotatedT> {
 
 impl<AnnotatedT> IntoIterator for Map<AnnotatedT> {
     type Item = (Variant<AnnotatedT>, Variant<AnnotatedT>);
-    type IntoIter = btree_map::IntoIter<Variant<AnnotatedT>, Variant<AnnotatedT>>;
+    type IntoIter = MapIntoIter<AnnotatedT>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -160,7 +177,7 @@ impl<AnnotatedT> IntoIterator for Map<AnnotatedT> {
 
 impl<'own, AnnotatedT> IntoIterator for &'own Map<AnnotatedT> {
     type Item = (&'own Variant<AnnotatedT>, &'own Variant<AnnotatedT>);
-    type IntoIter = btree_map::Iter<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>;
+    type IntoIter = MapIter<'own, AnnotatedT>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.iter()
@@ -169,7 +186,7 @@ impl<'own, AnnotatedT> IntoIterator for &'own Map<AnnotatedT> {
 
 impl<'own, AnnotatedT> IntoIterator for &'own mut Map<AnnotatedT> {
     type Item = (&'own Variant<AnnotatedT>, &'own mut Variant<AnnotatedT>);
-    type IntoIter = btree_map::IterMut<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>;
+    type IntoIter = MapIterMut<'own, AnnotatedT>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.iter_mut()
@@ -178,12 +195,27 @@ impl<'own, AnnotatedT> IntoIterator for &'own mut Map<AnnotatedT> {
 
 // Conversions
 
+impl<AnnotatedT> From<BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>> for Map<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    fn from(map: BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>) -> Self {
+        MapInner::from(map).into()
+    }
+}
+
+impl<AnnotatedT> From<Map<AnnotatedT>> for BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>> {
+    fn from(map: Map<AnnotatedT>) -> Self {
+        map.inner.into()
+    }
+}
+
 impl<const SIZE: usize, AnnotatedT> From<[(Variant<AnnotatedT>, Variant<AnnotatedT>); SIZE]> for Map<AnnotatedT>
 where
     AnnotatedT: Default,
 {
     fn from(array: [(Variant<AnnotatedT>, Variant<AnnotatedT>); SIZE]) -> Self {
-        BTreeMap::from(array).into()
+        MapInner::from(array).into()
     }
 }
 
@@ -195,7 +227,7 @@ where
     where
         IntoIteratorT: IntoIterator<Item = (Variant<AnnotatedT>, Variant<AnnotatedT>)>,
     {
-        BTreeMap::from_iter(iterator).into()
+        MapInner::from_iter(iterator).into()
     }
 }
 
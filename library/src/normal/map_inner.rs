@@ -0,0 +1,354 @@
+use super::variant::*;
+
+#[cfg(feature = "copy-on-write")]
+use super::copy_on_write::*;
+
+#[cfg(feature = "preserve-order")]
+use {super::insertion_order_map::*, indexmap::map as storage};
+
+#[cfg(not(feature = "preserve-order"))]
+use std::collections::btree_map as storage;
+
+use std::{cmp::*, collections::*, fmt, hash::*, iter::*};
+
+//
+// MapInner
+//
+
+/// Inner representation of [Map](super::Map).
+///
+/// Its API is the same regardless of the enabled features:
+///
+/// * Keys are sorted by [Variant]'s [Ord]. With the "preserve-order" feature they are instead
+///   kept in insertion order and lookups are O(1). Comparison and hashing ignore the order, but
+///   note that this means they have to sort the entries first, which is O(n log n) and allocates.
+/// * With the "copy-on-write" feature cloning is O(1) because the entries are shared. They are
+///   copied lazily on first mutation.
+///
+/// Migrating from [BTreeMap]: the common methods have the same names and signatures, and it
+/// converts to and from a [BTreeMap] with [Into].
+#[derive(Clone)]
+pub struct MapInner<AnnotatedT> {
+    entries: Entries<AnnotatedT>,
+}
+
+#[cfg(not(feature = "preserve-order"))]
+type Storage<AnnotatedT> = BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>;
+
+#[cfg(feature = "preserve-order")]
+type Storage<AnnotatedT> = InsertionOrderMap<Variant<AnnotatedT>, Variant<AnnotatedT>>;
+
+#[cfg(not(feature = "copy-on-write"))]
+type Entries<AnnotatedT> = Storage<AnnotatedT>;
+
+#[cfg(feature = "copy-on-write")]
+type Entries<AnnotatedT> = CopyOnWrite<Storage<AnnotatedT>>;
+
+impl<AnnotatedT> MapInner<AnnotatedT> {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self::new_for(Storage::default())
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// True if the key exists.
+    pub fn contains_key(&self, key: &Variant<AnnotatedT>) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Get.
+    pub fn get(&self, key: &Variant<AnnotatedT>) -> Option<&Variant<AnnotatedT>> {
+        self.entries.get(key)
+    }
+
+    /// Get entry.
+    pub fn get_key_value(&self, key: &Variant<AnnotatedT>) -> Option<(&Variant<AnnotatedT>, &Variant<AnnotatedT>)> {
+        self.entries.get_key_value(key)
+    }
+
+    /// Get mutable.
+    pub fn get_mut(&mut self, key: &Variant<AnnotatedT>) -> Option<&mut Variant<AnnotatedT>> {
+        self.entries.get_mut(key)
+    }
+
+    /// Insert.
+    ///
+    /// Returns the previous value, if there was one.
+    pub fn insert(&mut self, key: Variant<AnnotatedT>, value: Variant<AnnotatedT>) -> Option<Variant<AnnotatedT>> {
+        self.entries.insert(key, value)
+    }
+
+    /// Remove.
+    ///
+    /// With the "preserve-order" feature the order of the remaining entries is preserved, which
+    /// is O(n).
+    pub fn remove(&mut self, key: &Variant<AnnotatedT>) -> Option<Variant<AnnotatedT>> {
+        self.entries.remove(key)
+    }
+
+    /// Remove entry.
+    ///
+    /// With the "preserve-order" feature the order of the remaining entries is preserved, which
+    /// is O(n).
+    pub fn remove_entry(&mut self, key: &Variant<AnnotatedT>) -> Option<(Variant<AnnotatedT>, Variant<AnnotatedT>)> {
+        self.entries.remove_entry(key)
+    }
+
+    /// Keep only the entries for which the predicate returns true.
+    pub fn retain<PredicateT>(&mut self, predicate: PredicateT)
+    where
+        PredicateT: FnMut(&Variant<AnnotatedT>, &mut Variant<AnnotatedT>) -> bool,
+    {
+        self.entries.retain(predicate);
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Iterate entries.
+    pub fn iter(&self) -> MapIter<'_, AnnotatedT> {
+        MapIter { inner: self.entries.iter() }
+    }
+
+    /// Iterate entries with mutable values.
+    pub fn iter_mut(&mut self) -> MapIterMut<'_, AnnotatedT> {
+        MapIterMut { inner: self.entries.iter_mut() }
+    }
+
+    /// Iterate keys.
+    pub fn keys(&self) -> MapKeys<'_, AnnotatedT> {
+        MapKeys { inner: self.entries.keys() }
+    }
+
+    /// Iterate values.
+    pub fn values(&self) -> MapValues<'_, AnnotatedT> {
+        MapValues { inner: self.entries.values() }
+    }
+
+    /// Iterate mutable values.
+    pub fn values_mut(&mut self) -> MapValuesMut<'_, AnnotatedT> {
+        MapValuesMut { inner: self.entries.values_mut() }
+    }
+}
+
+impl<AnnotatedT> Default for MapInner<AnnotatedT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<AnnotatedT> fmt::Debug for MapInner<AnnotatedT>
+where
+    AnnotatedT: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<AnnotatedT> PartialEq for MapInner<AnnotatedT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries.eq(&other.entries)
+    }
+}
+
+impl<AnnotatedT> Eq for MapInner<AnnotatedT> {}
+
+impl<AnnotatedT> PartialOrd for MapInner<AnnotatedT> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<AnnotatedT> Ord for MapInner<AnnotatedT> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entries.cmp(&other.entries)
+    }
+}
+
+impl<AnnotatedT> Hash for MapInner<AnnotatedT> {
+    fn hash<HasherT>(&self, state: &mut HasherT)
+    where
+        HasherT: Hasher,
+    {
+        self.entries.hash(state);
+    }
+}
+
+impl<AnnotatedT> IntoIterator for MapInner<AnnotatedT> {
+    type Item = (Variant<AnnotatedT>, Variant<AnnotatedT>);
+    type IntoIter = MapIntoIter<AnnotatedT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapIntoIter { inner: self.into_storage().into_iter() }
+    }
+}
+
+impl<'own, AnnotatedT> IntoIterator for &'own MapInner<AnnotatedT> {
+    type Item = (&'own Variant<AnnotatedT>, &'own Variant<AnnotatedT>);
+    type IntoIter = MapIter<'own, AnnotatedT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'own, AnnotatedT> IntoIterator for &'own mut MapInner<AnnotatedT> {
+    type Item = (&'own Variant<AnnotatedT>, &'own mut Variant<AnnotatedT>);
+    type IntoIter = MapIterMut<'own, AnnotatedT>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<AnnotatedT> FromIterator<(Variant<AnnotatedT>, Variant<AnnotatedT>)> for MapInner<AnnotatedT> {
+    fn from_iter<IntoIteratorT>(iterator: IntoIteratorT) -> Self
+    where
+        IntoIteratorT: IntoIterator<Item = (Variant<AnnotatedT>, Variant<AnnotatedT>)>,
+    {
+        Self::new_for(Storage::from_iter(iterator))
+    }
+}
+
+impl<AnnotatedT> Extend<(Variant<AnnotatedT>, Variant<AnnotatedT>)> for MapInner<AnnotatedT> {
+    fn extend<IntoIteratorT>(&mut self, iterator: IntoIteratorT)
+    where
+        IntoIteratorT: IntoIterator<Item = (Variant<AnnotatedT>, Variant<AnnotatedT>)>,
+    {
+        self.entries.extend(iterator);
+    }
+}
+
+// Conversions
+
+impl<const SIZE: usize, AnnotatedT> From<[(Variant<AnnotatedT>, Variant<AnnotatedT>); SIZE]> for MapInner<AnnotatedT> {
+    fn from(array: [(Variant<AnnotatedT>, Variant<AnnotatedT>); SIZE]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<AnnotatedT> From<BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>> for MapInner<AnnotatedT> {
+    fn from(map: BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>>) -> Self {
+        Self::new_for(Storage::from(map))
+    }
+}
+
+impl<AnnotatedT> From<MapInner<AnnotatedT>> for BTreeMap<Variant<AnnotatedT>, Variant<AnnotatedT>> {
+    fn from(inner: MapInner<AnnotatedT>) -> Self {
+        inner.into_iter().collect()
+    }
+}
+
+//
+// Iterators
+//
+
+/// Iterator for [MapInner] entries.
+pub struct MapIter<'own, AnnotatedT> {
+    inner: storage::Iter<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapIter<'own>, (&'own Variant<AnnotatedT>, &'own Variant<AnnotatedT>));
+
+/// Iterator for [MapInner] entries with mutable values.
+pub struct MapIterMut<'own, AnnotatedT> {
+    inner: storage::IterMut<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapIterMut<'own>, (&'own Variant<AnnotatedT>, &'own mut Variant<AnnotatedT>));
+
+/// Owning iterator for [MapInner] entries.
+pub struct MapIntoIter<AnnotatedT> {
+    inner: storage::IntoIter<Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapIntoIter, (Variant<AnnotatedT>, Variant<AnnotatedT>));
+
+/// Iterator for [MapInner] keys.
+pub struct MapKeys<'own, AnnotatedT> {
+    inner: storage::Keys<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapKeys<'own>, &'own Variant<AnnotatedT>);
+
+/// Iterator for [MapInner] values.
+pub struct MapValues<'own, AnnotatedT> {
+    inner: storage::Values<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapValues<'own>, &'own Variant<AnnotatedT>);
+
+/// Iterator for [MapInner] mutable values.
+pub struct MapValuesMut<'own, AnnotatedT> {
+    inner: storage::ValuesMut<'own, Variant<AnnotatedT>, Variant<AnnotatedT>>,
+}
+
+impl_map_iterator!(MapValuesMut<'own>, &'own mut Variant<AnnotatedT>);
+
+// Utils
+
+impl<AnnotatedT> MapInner<AnnotatedT> {
+    #[cfg(not(feature = "copy-on-write"))]
+    fn new_for(storage: Storage<AnnotatedT>) -> Self {
+        Self { entries: storage }
+    }
+
+    #[cfg(feature = "copy-on-write")]
+    fn new_for(storage: Storage<AnnotatedT>) -> Self {
+        Self { entries: CopyOnWrite::from(storage) }
+    }
+
+    #[cfg(not(feature = "copy-on-write"))]
+    fn into_storage(self) -> Storage<AnnotatedT> {
+        self.entries
+    }
+
+    #[cfg(feature = "copy-on-write")]
+    fn into_storage(self) -> Storage<AnnotatedT> {
+        self.entries.into_inner()
+    }
+}
+
+macro_rules! impl_map_iterator (
+    ( $type:ident $( < $lifetime:lifetime > )?, $item:ty $(,)? ) => {
+        impl<$( $lifetime, )? AnnotatedT> Iterator for $type<$( $lifetime, )? AnnotatedT> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<$( $lifetime, )? AnnotatedT> DoubleEndedIterator for $type<$( $lifetime, )? AnnotatedT> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<$( $lifetime, )? AnnotatedT> ExactSizeIterator for $type<$( $lifetime, )? AnnotatedT> {
+            fn len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<$( $lifetime, )? AnnotatedT> FusedIterator for $type<$( $lifetime, )? AnnotatedT> {}
+    };
+);
+
+use impl_map_iterator;
@@ -1,16 +1,23 @@
 mod blob;
 mod boolean;
 mod conversions;
+#[cfg(feature = "copy-on-write")]
+mod copy_on_write;
 mod delegated;
 mod depict;
 mod errors;
 mod float;
+#[cfg(feature = "preserve-order")]
+mod insertion_order_map;
 mod integer;
 mod iterator;
 mod list;
+mod list_inner;
 mod macros;
 mod map;
+mod map_inner;
 mod null;
+mod number;
 mod text;
 mod traversal;
 mod unsigned_integer;
@@ -31,10 +38,19 @@ pub use {
     integer::*,
     iterator::*,
     list::*,
+    list_inner::*,
     map::*,
+    map_inner::*,
     null::*,
+    number::*,
     text::*,
     traversal::*,
     unsigned_integer::*,
     variant::*,
 };
+
+#[cfg(feature = "copy-on-write")]
+pub use copy_on_write::*;
+
+#[cfg(feature = "preserve-order")]
+pub use insertion_order_map::*;
@@ -0,0 +1,268 @@
+use {
+    super::super::annotate::*,
+    crate::{impl_normal, impl_normal_basic},
+};
+
+use {
+    kutil::{cli::depict::*, std::immutable::*},
+    num_traits::NumCast,
+    std::{fmt, io},
+};
+
+//
+// Number
+//
+
+impl_normal! {
+    /// Normal arbitrary-precision number variant.
+    ///
+    /// The value is the exact numeric text as it appeared in the source, e.g.
+    /// "123456789012345678901234567890" or "0.1000000000000000055511". It is produced by parsers
+    /// in lossless number mode when a number cannot be represented exactly by an [Integer](super::Integer),
+    /// [UnsignedInteger](super::UnsignedInteger), or [Float](super::Float).
+    ///
+    /// Comparison and hashing are textual, e.g. "1.0" and "1.00" are *not* equal.
+    ///
+    /// [Annotations], if present, are *ignored* for the purposes of comparison and hashing.
+    Number(ByteString)
+}
+
+impl_normal_basic!(Number);
+
+impl<AnnotatedT> Number<AnnotatedT> {
+    /// As string.
+    pub fn as_str(&self) -> &str {
+        self.inner.as_ref()
+    }
+
+    /// True if there is no fraction or exponent.
+    pub fn is_integer(&self) -> bool {
+        !self.inner.contains(['.', 'e', 'E'])
+    }
+
+    /// True if negative.
+    pub fn is_negative(&self) -> bool {
+        self.inner.starts_with('-')
+    }
+
+    /// Convert to [f64]. Precision may be lost.
+    pub fn to_f64(&self) -> Option<f64> {
+        self.inner.parse().ok()
+    }
+
+    /// Cast to a primitive number. Precision may be lost for floats.
+    ///
+    /// Returns [None] if out of range.
+    pub fn cast<NumberT>(&self) -> Option<NumberT>
+    where
+        NumberT: NumCast,
+    {
+        if let Ok(integer) = self.inner.parse::<i128>() {
+            num_traits::cast(integer)
+        } else if let Ok(unsigned_integer) = self.inner.parse::<u128>() {
+            num_traits::cast(unsigned_integer)
+        } else {
+            self.to_f64().and_then(num_traits::cast)
+        }
+    }
+
+    /// Splits into sign, digits (without leading zeros), and base-10 exponent.
+    ///
+    /// The value is (-1)<sup>sign</sup> × digits × 10<sup>exponent</sup>.
+    ///
+    /// Returns [None] if the text is not a valid decimal number.
+    pub fn to_decimal_parts(&self) -> Option<(bool, String, i64)> {
+        decimal_parts(&self.inner)
+    }
+}
+
+impl<AnnotatedT> Depict for Number<AnnotatedT> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+
+        if context.get_format() == DepictionFormat::Compact {
+            write!(writer, "{}", context.theme.number(&self.inner))
+        } else {
+            write!(writer, "{} {}", context.theme.number(&self.inner), context.theme.meta("number"))
+        }
+    }
+}
+
+impl<AnnotatedT> fmt::Display for Number<AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, formatter)
+    }
+}
+
+// Conversions
+
+impl<AnnotatedT> From<i128> for Number<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    fn from(integer: i128) -> Self {
+        Self::from(ByteString::from(integer.to_string()))
+    }
+}
+
+impl<AnnotatedT> From<u128> for Number<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    fn from(unsigned_integer: u128) -> Self {
+        Self::from(ByteString::from(unsigned_integer.to_string()))
+    }
+}
+
+impl<'own, AnnotatedT> From<&'own Number<AnnotatedT>> for &'own str {
+    fn from(number: &'own Number<AnnotatedT>) -> Self {
+        &number.inner
+    }
+}
+
+// Utils
+
+/// True if the decimal number text can be parsed into an [f64] without losing precision.
+///
+/// This is guaranteed for up to 15 significant digits within the normal range.
+pub fn is_lossless_f64(text: &str) -> bool {
+    match decimal_parts(text) {
+        Some((_, digits, _)) if digits.is_empty() => true,
+
+        Some((_, digits, exponent)) => {
+            // Ignore trailing zeros
+            let significant = digits.trim_end_matches('0').len();
+            let magnitude = exponent + digits.len() as i64;
+            (significant <= 15) && (magnitude > f64::MIN_10_EXP as i64) && (magnitude < f64::MAX_10_EXP as i64)
+        }
+
+        None => false,
+    }
+}
+
+/// Splits decimal number text into sign (true if negative), digits (without leading zeros), and
+/// base-10 exponent.
+///
+/// Returns [None] if the text is not a valid decimal number.
+pub fn decimal_parts(text: &str) -> Option<(bool, String, i64)> {
+    let (negative, text) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+
+    let (whole, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+        None => (mantissa, ""),
+    };
+
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.bytes().all(|byte| byte.is_ascii_digit())
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut digits = String::with_capacity(whole.len() + fraction.len());
+    digits.push_str(whole);
+    digits.push_str(fraction);
+    let digits = digits.trim_start_matches('0').to_string();
+
+    Some((negative, digits, exponent.checked_sub(fraction.len() as i64)?))
+}
+
+/// Joins sign (true if negative), digits, and base-10 exponent into decimal number text.
+///
+/// The inverse of [decimal_parts].
+pub fn decimal_text(negative: bool, digits: &str, exponent: i64) -> String {
+    let digits = digits.trim_start_matches('0');
+    let mut text = String::with_capacity(digits.len() + 3);
+
+    if negative && !digits.is_empty() {
+        text.push('-');
+    }
+
+    if digits.is_empty() {
+        text.push('0');
+    } else if exponent > 0 {
+        text.push_str(digits);
+        text.push_str(&format!("e{}", exponent));
+    } else if exponent < 0 {
+        let fraction = exponent.unsigned_abs() as usize;
+        if fraction < digits.len() {
+            let (whole, fraction) = digits.split_at(digits.len() - fraction);
+            text.push_str(whole);
+            text.push('.');
+            text.push_str(fraction);
+        } else {
+            text.push_str("0.");
+            text.push_str(&"0".repeat(fraction - digits.len()));
+            text.push_str(digits);
+        }
+    } else {
+        text.push_str(digits);
+    }
+
+    text
+}
+
+/// Converts decimal digits into big-endian bytes (without leading zeros).
+///
+/// Returns [None] if the text contains non-digits.
+pub fn decimal_to_big_endian(digits: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::<u8>::new();
+
+    for digit in digits.bytes() {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+
+        // bytes = bytes * 10 + digit
+        let mut carry = (digit - b'0') as u16;
+        for byte in bytes.iter_mut().rev() {
+            let value = (*byte as u16) * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            bytes.insert(0, carry as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Converts big-endian bytes into decimal digits.
+pub fn big_endian_to_decimal(bytes: &[u8]) -> String {
+    let mut bytes: Vec<u8> = bytes.iter().copied().skip_while(|byte| *byte == 0).collect();
+    let mut digits = Vec::new();
+
+    while !bytes.is_empty() {
+        // bytes, remainder = bytes / 10, bytes % 10
+        let mut remainder = 0u16;
+        for byte in bytes.iter_mut() {
+            let value = (remainder << 8) | (*byte as u16);
+            *byte = (value / 10) as u8;
+            remainder = value % 10;
+        }
+
+        digits.push(b'0' + remainder as u8);
+        let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+        bytes.drain(..zeros);
+    }
+
+    if digits.is_empty() {
+        return "0".into();
+    }
+
+    digits.reverse();
+    String::from_utf8(digits).expect("digits")
+}
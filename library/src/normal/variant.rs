@@ -9,6 +9,7 @@ use super::{
     list::*,
     map::*,
     null::*,
+    number::*,
     text::*,
     unsigned_integer::*,
 };
@@ -20,7 +21,11 @@ use {kutil::std::immutable::*, std::mem::*};
 //
 
 /// Container for normal types.
+///
+/// Non-exhaustive, because normal types beyond CPS may be added (as [Number] was), so matching on
+/// it requires a wildcard arm.
 #[derive(Clone, Debug, Default)]
+#[non_exhaustive]
 pub enum Variant<AnnotatedT> {
     /// Undefined. This is different from [Null]!
     #[default]
@@ -38,6 +43,9 @@ pub enum Variant<AnnotatedT> {
     /// Float.
     Float(Float<AnnotatedT>),
 
+    /// Arbitrary-precision number.
+    Number(Number<AnnotatedT>),
+
     /// Boolean.
     Boolean(Boolean<AnnotatedT>),
 
@@ -63,6 +71,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
             Self::Integer(_) => "Integer",
             Self::UnsignedInteger(_) => "UnsignedInteger",
             Self::Float(_) => "Float",
+            Self::Number(_) => "Number",
             Self::Boolean(_) => "Boolean",
             Self::Text(_) => "Text",
             Self::Blob(_) => "Blob",
@@ -275,7 +284,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
         AnnotatedT: 'own + Clone + Default,
     {
         match self {
-            Self::Map(map) => Some(Box::new(IntoKeyValuePairIteratorForBTreeMap::new_for(map.inner))),
+            Self::Map(map) => Some(Box::new(IntoKeyValuePairIteratorForMap::new_for(map))),
             Self::List(list) => Some(Box::new(IntoKeyValuePairIteratorForVariantIterator::new_for(list))),
             _ => None,
         }
@@ -292,7 +301,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
         AnnotatedT: Default,
     {
         match self {
-            Self::Map(map) => Some(Box::new(KeyValuePairIteratorForBTreeMap::new_for(&map.inner))),
+            Self::Map(map) => Some(Box::new(KeyValuePairIteratorForMap::new_for(map))),
             Self::List(list) => Some(Box::new(KeyValuePairIteratorForVariantIterator::new_for(list))),
             _ => None,
         }
@@ -306,6 +315,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
             Self::Integer(integer) => Variant::Integer(integer.without_annotations()),
             Self::UnsignedInteger(unsigned_integer) => Variant::UnsignedInteger(unsigned_integer.without_annotations()),
             Self::Float(float) => Variant::Float(float.without_annotations()),
+            Self::Number(number) => Variant::Number(number.without_annotations()),
             Self::Boolean(boolean) => Variant::Boolean(boolean.without_annotations()),
             Self::Text(text) => Variant::Text(text.without_annotations()),
             Self::Blob(blob) => Variant::Blob(blob.without_annotations()),
@@ -326,6 +336,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
             Self::Integer(integer) => Variant::Integer(integer.into_annotated()),
             Self::UnsignedInteger(unsigned_integer) => Variant::UnsignedInteger(unsigned_integer.into_annotated()),
             Self::Float(float) => Variant::Float(float.into_annotated()),
+            Self::Number(number) => Variant::Number(number.into_annotated()),
             Self::Boolean(boolean) => Variant::Boolean(boolean.into_annotated()),
             Self::Text(text) => Variant::Text(text.into_annotated()),
             Self::Blob(blob) => Variant::Blob(blob.into_annotated()),
@@ -374,7 +385,7 @@ impl<AnnotatedT> Variant<AnnotatedT> {
 
                 for (key, value) in vector.iter_mut() {
                     let mut path = base_path.clone();
-                    path.push_map_key(key.to_string().into());
+                    path.push_variant_key(key);
 
                     key.fully_annotate(source, &path);
                     value.fully_annotate(source, &path);
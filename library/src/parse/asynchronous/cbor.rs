@@ -0,0 +1,280 @@
+use super::{
+    super::{
+        super::{annotate::*, normal::*},
+        builder::*,
+        error::*,
+        formats::*,
+        parser::*,
+    },
+    push_remaining,
+};
+
+use {
+    borc::errors::*,
+    kutil::std::immutable::*,
+    tokio::io::{AsyncRead, AsyncReadExt},
+    tracing::trace,
+};
+
+impl Parser {
+    /// Asynchronously parses CBOR into a [Variant].
+    ///
+    /// Is affected by [Parser::base64](super::super::Parser)
+    /// and [Parser::lossless_numbers](super::super::Parser). Note that Base64 input is read fully
+    /// into memory before parsing.
+    pub async fn parse_cbor_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        if self.base64 {
+            let mut buffer = Vec::default();
+            reader.read_to_end(&mut buffer).await?;
+            return self.parse_cbor(&mut buffer.as_slice());
+        }
+
+        let mut value_builder = self.variant_builder();
+        read_cbor_async(reader, &mut value_builder, self.lossless_numbers).await?;
+        Ok(value_builder.finalize())
+    }
+}
+
+// Utils
+
+// See: https://www.rfc-editor.org/rfc/rfc8949.html#name-specification-of-the-cbor-e
+async fn read_cbor_async<ReadT, AnnotatedT>(
+    reader: &mut ReadT,
+    value_builder: &mut VariantBuilder<AnnotatedT>,
+    lossless_numbers: bool,
+) -> Result<(), ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    // Remaining items for each open container (None for unknown length)
+    let mut remaining_stack: Vec<Option<u64>> = Default::default();
+
+    loop {
+        let mut byte = reader.read_u8().await?;
+        trace!("{:#04x}", byte);
+
+        if byte == CBOR_BREAK {
+            match remaining_stack.pop() {
+                Some(None) => value_builder.end_container(),
+                _ => return Err(DecodeError::Malformed.into()),
+            }
+        } else {
+            if let Some(Some(remaining)) = remaining_stack.last_mut() {
+                *remaining -= 1;
+            }
+
+            // https://www.rfc-editor.org/rfc/rfc8949.html#name-tagging-of-items
+            let mut label = None;
+            let mut last_tag = None;
+            while byte >> 5 == 6 {
+                let tag = read_cbor_argument(reader, byte & 0x1f).await?;
+                label = Some(Label::Integer(tag as i64));
+                last_tag = Some(tag);
+                byte = reader.read_u8().await?;
+            }
+
+            let info = byte & 0x1f;
+
+            match byte >> 5 {
+                // https://www.rfc-editor.org/rfc/rfc8949.html#name-bignums
+                2 if lossless_numbers && matches!(last_tag, Some(2 | 3)) => {
+                    let bytes = read_cbor_string_bytes_async(reader, 2, info).await?;
+                    value_builder.add(bignum_variant(last_tag.unwrap_or_default(), &bytes), None);
+                }
+
+                // https://www.rfc-editor.org/rfc/rfc8949.html#name-decimal-fractions-and-bigfl
+                4 if lossless_numbers && (last_tag == Some(4)) && (info == 2) => {
+                    value_builder.add(read_cbor_decimal_fraction_async(reader).await?, None);
+                }
+
+                0 => {
+                    let unsigned_integer = read_cbor_argument(reader, info).await?;
+                    value_builder.add(UnsignedInteger::from(unsigned_integer).with_label(label), None);
+                }
+
+                1 => {
+                    let integer = read_cbor_argument(reader, info).await?;
+                    if integer > i64::MAX as u64 {
+                        // Below i64::MIN
+                        if !lossless_numbers {
+                            return Err(DecodeError::Malformed.into());
+                        }
+                        value_builder.add(
+                            Number::from(ByteString::from(format!("-{}", integer as u128 + 1))).with_label(label),
+                            None,
+                        );
+                    } else {
+                        value_builder.add(Integer::from(-1 - integer as i64).with_label(label), None);
+                    }
+                }
+
+                2 => {
+                    let bytes = read_cbor_string_bytes_async(reader, 2, info).await?;
+                    value_builder.add(Blob::from(bytes).with_label(label), None);
+                }
+
+                3 => {
+                    let bytes = read_cbor_string_bytes_async(reader, 3, info).await?;
+                    value_builder.add(Text::from(String::from_utf8(bytes)?).with_label(label), None);
+                }
+
+                4 => {
+                    let length =
+                        if info == CBOR_UNKNOWN_LENGTH { None } else { Some(read_cbor_argument(reader, info).await?) };
+                    value_builder.start_list_with_label(label, None);
+                    push_remaining(&mut remaining_stack, length)?;
+                }
+
+                5 => {
+                    let length = if info == CBOR_UNKNOWN_LENGTH {
+                        None
+                    } else {
+                        // Keys and values are counted separately
+                        Some(read_cbor_argument(reader, info).await?.checked_mul(2).ok_or(DecodeError::Malformed)?)
+                    };
+                    value_builder.start_map_with_label(label, None);
+                    push_remaining(&mut remaining_stack, length)?;
+                }
+
+                _ => match info {
+                    20 => value_builder.add(Boolean::from(false).with_label(label), None),
+                    21 => value_builder.add(Boolean::from(true).with_label(label), None),
+                    22 => value_builder.add(Null::default().with_label(label), None),
+                    // Undefined is not in CPS, but it still takes up its place in the container
+                    23 => value_builder.add(Null::default().with_label(label), None),
+                    25 => {
+                        let float = half::f16::from_bits(reader.read_u16().await?);
+                        value_builder.add(Float::from(float.to_f64()).with_label(label), None);
+                    }
+                    26 => {
+                        let float = reader.read_f32().await?;
+                        value_builder.add(Float::from(float as f64).with_label(label), None);
+                    }
+                    27 => {
+                        let float = reader.read_f64().await?;
+                        value_builder.add(Float::from(float).with_label(label), None);
+                    }
+                    _ => return Err(DecodeError::Malformed.into()),
+                },
+            }
+        }
+
+        // Close containers that have all their items
+        while let Some(Some(0)) = remaining_stack.last() {
+            remaining_stack.pop();
+            value_builder.end_container();
+        }
+
+        if remaining_stack.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+async fn read_cbor_argument<ReadT>(reader: &mut ReadT, info: u8) -> Result<u64, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    Ok(match info {
+        0..24 => info as u64,
+        24 => reader.read_u8().await? as u64,
+        25 => reader.read_u16().await? as u64,
+        26 => reader.read_u32().await? as u64,
+        27 => reader.read_u64().await?,
+        _ => return Err(DecodeError::Malformed.into()),
+    })
+}
+
+// https://www.rfc-editor.org/rfc/rfc8949.html#name-decimal-fractions-and-bigfl
+async fn read_cbor_decimal_fraction_async<ReadT, AnnotatedT>(
+    reader: &mut ReadT,
+) -> Result<Variant<AnnotatedT>, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+    AnnotatedT: Default,
+{
+    let byte = reader.read_u8().await?;
+    let exponent = read_cbor_argument(reader, byte & 0x1f).await?;
+    let exponent = match byte >> 5 {
+        0 => i64::try_from(exponent).map_err(|_| DecodeError::Malformed)?,
+        1 => -1 - i64::try_from(exponent).map_err(|_| DecodeError::Malformed)?,
+        _ => return Err(DecodeError::Malformed.into()),
+    };
+
+    let byte = reader.read_u8().await?;
+    let (negative, digits) = match byte >> 5 {
+        0 => (false, read_cbor_argument(reader, byte & 0x1f).await?.to_string()),
+        1 => (true, (read_cbor_argument(reader, byte & 0x1f).await? as u128 + 1).to_string()),
+        6 => {
+            let tag = read_cbor_argument(reader, byte & 0x1f).await?;
+            let byte = reader.read_u8().await?;
+            if !matches!(tag, 2 | 3) || (byte >> 5 != 2) {
+                return Err(DecodeError::Malformed.into());
+            }
+            bignum(tag, &read_cbor_string_bytes_async(reader, 2, byte & 0x1f).await?)
+        }
+        _ => return Err(DecodeError::Malformed.into()),
+    };
+
+    let text = decimal_text(negative, &digits, exponent);
+    Ok(if is_lossless_f64(&text) {
+        Float::from(text.parse::<f64>()?).into()
+    } else {
+        Number::from(ByteString::from(text)).into()
+    })
+}
+
+// Byte strings (major 2) and text strings (major 3)
+async fn read_cbor_string_bytes_async<ReadT>(reader: &mut ReadT, major: u8, info: u8) -> Result<Vec<u8>, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    if info == CBOR_UNKNOWN_LENGTH {
+        read_cbor_unknown_length_bytes_async(reader, major).await
+    } else {
+        let length = read_cbor_argument(reader, info).await?;
+        let mut buffer = Vec::default();
+        read_cbor_bytes_async(reader, length, &mut buffer).await?;
+        Ok(buffer)
+    }
+}
+
+async fn read_cbor_bytes_async<ReadT>(reader: &mut ReadT, length: u64, buffer: &mut Vec<u8>) -> Result<(), ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    trace!("bytes length: {}", length);
+    let read = reader.take(length).read_to_end(buffer).await?;
+    if read as u64 == length { Ok(()) } else { Err(DecodeError::Malformed.into()) }
+}
+
+async fn read_cbor_unknown_length_bytes_async<ReadT>(reader: &mut ReadT, major: u8) -> Result<Vec<u8>, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    let mut buffer = Vec::default();
+
+    loop {
+        let byte = reader.read_u8().await?;
+
+        if byte == CBOR_BREAK {
+            return Ok(buffer);
+        }
+
+        // Chunks must be of the same major type and of known length
+        if (byte >> 5 != major) || (byte & 0x1f == CBOR_UNKNOWN_LENGTH) {
+            return Err(DecodeError::Malformed.into());
+        }
+
+        let length = read_cbor_argument(reader, byte & 0x1f).await?;
+        read_cbor_bytes_async(reader, length, &mut buffer).await?;
+    }
+}
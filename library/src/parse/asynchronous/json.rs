@@ -0,0 +1,163 @@
+use super::super::{
+    super::{annotate::*, hints::*, normal::*},
+    error::*,
+    parser::*,
+};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+impl Parser {
+    /// Asynchronously parses JSON into a [Variant].
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
+    pub async fn parse_json_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        self.parse_json_with_hints_async(reader, None).await
+    }
+
+    /// Asynchronously parses XJSON into a [Variant].
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
+    pub async fn parse_xjson_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        self.parse_json_with_hints_async(reader, Some(&Hints::xjson())).await
+    }
+
+    /// Asynchronously parses JSON into a [Variant].
+    ///
+    /// The input is read until the end of the root value and then parsed with
+    /// [Parser::parse_json_with_hints], so that errors are exactly those of the synchronous parser.
+    /// Note that this means that the JSON is read fully into memory before parsing.
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
+    pub async fn parse_json_with_hints_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+        hints: Option<&Hints>,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let json = read_json_async(reader).await?;
+        self.parse_json_with_hints(&mut json.as_slice(), hints)
+    }
+}
+
+// Utils
+
+const BUFFER_SIZE: usize = 8 * 1024;
+
+// Reads until the end of the root value and the whitespace after it
+//
+// We only find where the root value ends: brackets are counted (outside of strings) without being
+// matched, and nothing else is validated. Anything that is not valid JSON is left for Struson to
+// report. Reading stops at the first byte after the root value that is not whitespace, which Struson
+// will report as trailing data.
+async fn read_json_async<ReadT>(reader: &mut ReadT) -> Result<Vec<u8>, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    let mut json = Vec::default();
+    let mut boundary = JsonBoundary::default();
+
+    loop {
+        let start = json.len();
+        json.resize(start + BUFFER_SIZE, 0);
+        let size = reader.read(&mut json[start..]).await?;
+        json.truncate(start + size);
+
+        if (size == 0) || json[start..].iter().any(|byte| boundary.next(*byte)) {
+            return Ok(json);
+        }
+    }
+}
+
+//
+// JsonBoundary
+//
+
+#[derive(Default)]
+struct JsonBoundary {
+    started: bool,
+    ended: bool,
+    depth: usize,
+    bare: bool,
+    string: bool,
+    escaped: bool,
+}
+
+impl JsonBoundary {
+    // Returns true when there is no need to read further
+    fn next(&mut self, byte: u8) -> bool {
+        if self.ended {
+            return !is_whitespace(byte);
+        }
+
+        if self.string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.string = false;
+                self.ended = self.depth == 0;
+            }
+        } else if !self.started {
+            if !is_whitespace(byte) {
+                self.started = true;
+                match byte {
+                    b'"' => self.string = true,
+                    b'[' | b'{' => self.depth = 1,
+                    b']' | b'}' | b',' | b':' => return true,
+                    _ => self.bare = true,
+                }
+            }
+        } else if self.bare {
+            if is_delimiter(byte) {
+                self.ended = true;
+                return !is_whitespace(byte);
+            }
+        } else {
+            match byte {
+                b'"' => self.string = true,
+                b'[' | b'{' => self.depth += 1,
+
+                b']' | b'}' => {
+                    self.depth -= 1;
+                    self.ended = self.depth == 0;
+                }
+
+                _ => {}
+            }
+        }
+
+        false
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+fn is_delimiter(byte: u8) -> bool {
+    is_whitespace(byte) || matches!(byte, b',' | b':' | b'[' | b']' | b'{' | b'}' | b'"')
+}
@@ -0,0 +1,179 @@
+use super::{
+    super::{
+        super::{
+            annotate::*,
+            normal::{Blob, *},
+        },
+        builder::*,
+        error::*,
+        parser::*,
+    },
+    push_remaining,
+};
+
+use {
+    rmp::*,
+    tokio::io::{AsyncRead, AsyncReadExt},
+    tracing::trace,
+};
+
+impl Parser {
+    /// Asynchronously parses MessagePack into a [Variant].
+    ///
+    /// Is affected by [Parser::base64](super::super::Parser). Note that Base64 input is read fully
+    /// into memory before parsing.
+    pub async fn parse_message_pack_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        if self.base64 {
+            let mut buffer = Vec::default();
+            reader.read_to_end(&mut buffer).await?;
+            return self.parse_message_pack(&mut buffer.as_slice());
+        }
+
+        let mut value_builder = self.variant_builder();
+        read_message_pack_async(reader, &mut value_builder).await?;
+        Ok(value_builder.finalize())
+    }
+}
+
+// Utils
+
+async fn read_message_pack_async<ReadT, AnnotatedT>(
+    reader: &mut ReadT,
+    value_builder: &mut VariantBuilder<AnnotatedT>,
+) -> Result<(), ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    // Remaining items for each open container
+    let mut remaining_stack: Vec<usize> = Default::default();
+
+    loop {
+        let marker = Marker::from_u8(reader.read_u8().await?);
+        trace!("{:?}", marker);
+
+        // Reserved markers are not items, so they are not counted
+        if (marker != Marker::Reserved)
+            && let Some(remaining) = remaining_stack.last_mut()
+        {
+            *remaining -= 1;
+        }
+
+        match marker {
+            Marker::Reserved => continue,
+            Marker::Null => value_builder.add(Null::default(), None),
+            Marker::True => value_builder.add(Boolean::from(true), None),
+            Marker::False => value_builder.add(Boolean::from(false), None),
+            Marker::FixNeg(integer) => value_builder.add(Integer::from(integer as i64), None),
+            Marker::I8 => value_builder.add(Integer::from(reader.read_i8().await? as i64), None),
+            Marker::I16 => value_builder.add(Integer::from(reader.read_i16().await? as i64), None),
+            Marker::I32 => value_builder.add(Integer::from(reader.read_i32().await? as i64), None),
+            Marker::I64 => value_builder.add(Integer::from(reader.read_i64().await?), None),
+            Marker::FixPos(integer) => value_builder.add(UnsignedInteger::from(integer as u64), None),
+            Marker::U8 => value_builder.add(UnsignedInteger::from(reader.read_u8().await? as u64), None),
+            Marker::U16 => value_builder.add(UnsignedInteger::from(reader.read_u16().await? as u64), None),
+            Marker::U32 => value_builder.add(UnsignedInteger::from(reader.read_u32().await? as u64), None),
+            Marker::U64 => value_builder.add(UnsignedInteger::from(reader.read_u64().await?), None),
+            Marker::F32 => value_builder.add(Float::from(reader.read_f32().await?), None),
+            Marker::F64 => value_builder.add(Float::from(reader.read_f64().await?), None),
+
+            Marker::Bin8 | Marker::Bin16 | Marker::Bin32 => {
+                let length = read_message_pack_length_async(reader, marker).await?;
+                let bytes = read_message_pack_bytes_async(reader, length).await?;
+                value_builder.add(Blob::from(bytes), None);
+            }
+
+            Marker::FixStr(_) | Marker::Str8 | Marker::Str16 | Marker::Str32 => {
+                let length = read_message_pack_length_async(reader, marker).await?;
+                let bytes = read_message_pack_bytes_async(reader, length).await?;
+                value_builder.add(Text::from(String::from_utf8(bytes)?), None);
+            }
+
+            Marker::FixExt1
+            | Marker::FixExt2
+            | Marker::FixExt4
+            | Marker::FixExt8
+            | Marker::FixExt16
+            | Marker::Ext8
+            | Marker::Ext16
+            | Marker::Ext32 => {
+                // Note that the length comes before the label for non-fixed extensions
+                let length = read_message_pack_length_async(reader, marker).await?;
+                let label = reader.read_i8().await? as i64;
+                trace!("ext type: {}", label);
+                let bytes = read_message_pack_bytes_async(reader, length).await?;
+                value_builder.add(Blob::from(bytes).with_label(Some(Label::Integer(label))), None);
+            }
+
+            Marker::FixArray(_) | Marker::Array16 | Marker::Array32 => {
+                let length = read_message_pack_length_async(reader, marker).await?;
+                trace!("array length: {}", length);
+                value_builder.start_list(None);
+                push_remaining(&mut remaining_stack, length)?;
+            }
+
+            Marker::FixMap(_) | Marker::Map16 | Marker::Map32 => {
+                let length = read_message_pack_length_async(reader, marker).await?;
+                trace!("map length: {}", length);
+                value_builder.start_map(None);
+
+                // Keys and values are counted separately
+                let length = length.checked_mul(2).ok_or_else(|| ParseError::Malformed("map is too long".into()))?;
+                push_remaining(&mut remaining_stack, length)?;
+            }
+        }
+
+        // Close containers that have all their items
+        while let Some(0) = remaining_stack.last() {
+            remaining_stack.pop();
+            value_builder.end_container();
+        }
+
+        if remaining_stack.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+async fn read_message_pack_length_async<ReadT>(reader: &mut ReadT, marker: Marker) -> Result<usize, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    Ok(match marker {
+        Marker::FixStr(length) | Marker::FixArray(length) | Marker::FixMap(length) => length as usize,
+        Marker::FixExt1 => 1,
+        Marker::FixExt2 => 2,
+        Marker::FixExt4 => 4,
+        Marker::FixExt8 => 8,
+        Marker::FixExt16 => 16,
+        Marker::Bin8 | Marker::Str8 | Marker::Ext8 => reader.read_u8().await? as usize,
+        Marker::Bin16 | Marker::Str16 | Marker::Ext16 | Marker::Array16 | Marker::Map16 => {
+            reader.read_u16().await? as usize
+        }
+        Marker::Bin32 | Marker::Str32 | Marker::Ext32 | Marker::Array32 | Marker::Map32 => {
+            reader.read_u32().await? as usize
+        }
+        _ => return Err(ParseError::Malformed(format!("not a length marker: {:?}", marker))),
+    })
+}
+
+async fn read_message_pack_bytes_async<ReadT>(reader: &mut ReadT, length: usize) -> Result<Vec<u8>, ParseError>
+where
+    ReadT: AsyncRead + Unpin,
+{
+    trace!("bytes length: {}", length);
+    let mut buffer = Vec::default();
+    let read = reader.take(length as u64).read_to_end(&mut buffer).await?;
+    if read == length {
+        Ok(buffer)
+    } else {
+        Err(ParseError::Malformed(format!("expected {} bytes, read {}", length, read)))
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "messagepack")]
+mod message_pack;
+mod parser;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+#[cfg(any(feature = "cbor", feature = "messagepack"))]
+use super::error::*;
+
+/// Maximum container nesting depth for the streaming decoders.
+#[cfg(any(feature = "cbor", feature = "messagepack"))]
+const MAX_NESTING_DEPTH: usize = 128;
+
+/// Pushes the remaining items of a newly opened container, failing if that would exceed
+/// [MAX_NESTING_DEPTH].
+#[cfg(any(feature = "cbor", feature = "messagepack"))]
+fn push_remaining<RemainingT>(remaining_stack: &mut Vec<RemainingT>, remaining: RemainingT) -> Result<(), ParseError> {
+    if remaining_stack.len() >= MAX_NESTING_DEPTH {
+        return Err(ParseError::Malformed(format!("nesting is deeper than {}", MAX_NESTING_DEPTH)));
+    }
+    remaining_stack.push(remaining);
+    Ok(())
+}
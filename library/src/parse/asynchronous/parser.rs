@@ -0,0 +1,75 @@
+use super::super::{
+    super::{annotate::*, normal::*},
+    error::*,
+    parser::*,
+};
+
+#[cfg(any(feature = "cbor", feature = "messagepack", feature = "yaml", feature = "json", feature = "xml"))]
+use super::super::super::format::*;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+impl Parser {
+    /// Asynchronously parses into a [Variant] according to [Parser::format].
+    ///
+    /// CBOR and MessagePack are decoded while streaming. JSON and XJSON are read into memory only
+    /// up to the end of the root value, and other formats, as well as Base64-encoded input, are
+    /// read fully into memory first. So is all input when [Parser::source_registry] is set.
+    pub async fn parse_async_reader<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        if self.source_registry.is_some() {
+            return self.parse_async_reader_buffered(reader).await;
+        }
+
+        match &self.format {
+            #[cfg(feature = "cbor")]
+            Format::CBOR => self.parse_cbor_async(reader).await,
+
+            #[cfg(feature = "messagepack")]
+            Format::MessagePack => self.parse_message_pack_async(reader).await,
+
+            #[cfg(feature = "yaml")]
+            Format::YAML => self.parse_yaml_async(reader).await,
+
+            #[cfg(feature = "json")]
+            Format::JSON => self.parse_json_async(reader).await,
+
+            #[cfg(feature = "json")]
+            Format::XJSON => self.parse_xjson_async(reader).await,
+
+            #[cfg(feature = "xml")]
+            Format::XML => Err(ParseError::UnsupportedFormat(self.format)),
+
+            #[cfg(not(all(
+                feature = "cbor",
+                feature = "messagepack",
+                feature = "yaml",
+                feature = "json",
+                feature = "xml",
+            )))]
+            _ => Err(ParseError::UnsupportedFormat(self.format)),
+        }
+    }
+
+    /// Reads the entire input into memory and then parses it with [Parser::parse_reader].
+    ///
+    /// Used for formats (and encodings) that do not support asynchronous streaming.
+    pub async fn parse_async_reader_buffered<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let mut buffer = Vec::default();
+        reader.read_to_end(&mut buffer).await?;
+        self.parse_reader(&mut buffer.as_slice())
+    }
+}
@@ -0,0 +1,27 @@
+use super::super::{
+    super::{annotate::*, normal::*},
+    error::*,
+    parser::*,
+};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+impl Parser {
+    /// Asynchronously parses YAML into a [Variant].
+    ///
+    /// Note that the input is read fully into memory before parsing.
+    ///
+    /// See [Parser::parse_yaml].
+    pub async fn parse_yaml_async<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: AsyncRead + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let mut buffer = Vec::default();
+        reader.read_to_end(&mut buffer).await?;
+        self.parse_yaml(&mut buffer.as_slice())
+    }
+}
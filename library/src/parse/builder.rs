@@ -1,6 +1,7 @@
 use super::{
-    super::{annotate::*, hints::*, normal::*},
+    super::{annotate::*, hints::*, normal::*, path::*},
     error::*,
+    event::*,
 };
 
 use kutil::std::{collections::*, immutable::*};
@@ -12,11 +13,23 @@ use kutil::std::{collections::*, immutable::*};
 /// Builds a [Variant] sequentially.
 ///
 /// This is a utility for format parsers.
+///
+/// The source, the source ID, and the [PathRepresentation] are added to the [Annotations] of each
+/// variant. Paths are added in a final pass unless [record_paths](Self::record_paths) is true.
 #[derive(Debug)]
 pub struct VariantBuilder<AnnotatedT> {
     /// Source.
     pub source: Option<ByteString>,
 
+    /// Source ID.
+    pub source_id: Option<SourceId>,
+
+    /// Whether to record the [PathRepresentation] of each variant in its [Annotations] as it is
+    /// built, instead of in a final pass in [finalize](Self::finalize). Defaults to false.
+    ///
+    /// Ignored if annotations are not supported.
+    pub record_paths: bool,
+
     /// Stack.
     pub stack: Vec<StackEntry<AnnotatedT>>,
 
@@ -30,7 +43,27 @@ pub struct VariantBuilder<AnnotatedT> {
 impl<AnnotatedT> VariantBuilder<AnnotatedT> {
     /// Constructor.
     pub fn new(source: Option<ByteString>) -> Self {
-        Self { source, stack: Default::default(), key_stack: Default::default(), references: FastHashMap::default() }
+        Self {
+            source,
+            source_id: None,
+            record_paths: false,
+            stack: Default::default(),
+            key_stack: Default::default(),
+            references: FastHashMap::default(),
+        }
+    }
+
+    /// Set source ID.
+    pub fn with_source_id(mut self, source_id: Option<SourceId>) -> Self {
+        self.source_id = source_id;
+        self
+    }
+
+    /// Set whether to record the [PathRepresentation] of each variant in its [Annotations] as it
+    /// is built.
+    pub fn with_record_paths(mut self, record_paths: bool) -> Self {
+        self.record_paths = record_paths;
+        self
     }
 
     /// Returns the final built [Variant].
@@ -51,7 +84,10 @@ impl<AnnotatedT> VariantBuilder<AnnotatedT> {
 
         match self.stack.len() {
             0 => Variant::Undefined,
-            1 => self.stack.remove(0).variant.fully_annotated(&self.source),
+            1 => {
+                let variant = self.stack.remove(0).variant;
+                if self.recording_paths() { variant } else { variant.fully_annotated(&self.source) }
+            }
             length => panic!("VariantBuilder in indeterminate state, stack length: {}", length),
         }
     }
@@ -70,7 +106,9 @@ impl<AnnotatedT> VariantBuilder<AnnotatedT> {
         AnnotatedT: Annotated + Clone,
         VariantT: Into<Variant<AnnotatedT>>,
     {
-        let variant = variant.into();
+        let mut variant = variant.into();
+        let path = if self.recording_paths() { self.next_path(Some(&variant)) } else { None };
+        self.annotate(&mut variant, path);
 
         if let Some(reference) = reference {
             // Note that we are allowing overriding of references
@@ -113,6 +151,9 @@ impl<AnnotatedT> VariantBuilder<AnnotatedT> {
     }
 
     /// Add a referenced [Variant].
+    ///
+    /// When recording paths, only the referenced variant itself is given the new path. Its
+    /// descendents keep their original paths.
     pub fn add_referenced(&mut self, reference: usize) -> Result<(), ParseError>
     where
         AnnotatedT: Annotated + Clone,
@@ -235,6 +276,12 @@ impl<AnnotatedT> VariantBuilder<AnnotatedT> {
             && let Some(hinted_value) = entry.variant.to_hinted_variant(hints)?
         {
             entry.variant = hinted_value;
+
+            if self.recording_paths() {
+                // The hinted variant may have been restructured, so we must annotate it again
+                let path = entry.path.clone().filter(|path| !path.segments.is_empty());
+                entry.variant = entry.variant.with_path(path).fully_annotated(&self.source);
+            }
         }
 
         self.add(entry.variant, entry.reference);
@@ -242,12 +289,116 @@ impl<AnnotatedT> VariantBuilder<AnnotatedT> {
         Ok(())
     }
 
-    fn push(&mut self, variant: Variant<AnnotatedT>, reference: Option<usize>)
+    /// Adds an [Event].
+    ///
+    /// Hints, if provided, are processed when containers end. See [Variant::to_hinted_variant].
+    pub fn add_event(&mut self, event: Event<AnnotatedT>, hints: Option<&Hints>) -> Result<(), ParseError>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        match event {
+            Event::StartMap { span, label } => {
+                self.push(Map::default().with_span(span).with_label(label).into(), None);
+
+                // Every map entry on the stack has a matching key_stack entry
+                self.key_stack.push(None);
+            }
+
+            Event::StartList { span, label } => {
+                self.push(List::default().with_span(span).with_label(label).into(), None);
+            }
+
+            Event::Key(variant) | Event::Scalar(variant) => {
+                self.add(variant, None);
+            }
+
+            Event::End => {
+                self.end_container_with_hints(hints)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds all [Event]s, stopping at the first error.
+    ///
+    /// Hints, if provided, are processed when containers end. See [Variant::to_hinted_variant].
+    pub fn add_events<EventsT>(&mut self, events: EventsT, hints: Option<&Hints>) -> Result<(), ParseError>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+        EventsT: IntoIterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+    {
+        for event in events {
+            self.add_event(event?, hints)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, mut variant: Variant<AnnotatedT>, reference: Option<usize>)
     where
         AnnotatedT: Annotated,
     {
         tracing::trace!("push on stack: {}", variant);
-        self.stack.push(StackEntry::new(variant, reference));
+        let path = if self.recording_paths() { self.next_path(None) } else { None };
+        self.annotate(&mut variant, path.clone());
+        let mut entry = StackEntry::new(variant, reference);
+        entry.path = path;
+        self.stack.push(entry);
+    }
+
+    // Paths are useless if they can't be stored in the annotations
+    fn recording_paths(&self) -> bool
+    where
+        AnnotatedT: Annotated,
+    {
+        self.record_paths && AnnotatedT::can_have_annotations()
+    }
+
+    // Path of the next variant to be added
+    fn next_path(&self, variant: Option<&Variant<AnnotatedT>>) -> Option<PathRepresentation> {
+        let Some(entry) = self.stack.last() else {
+            return Some(Default::default());
+        };
+
+        let mut path = entry.path.clone()?;
+        match &entry.variant {
+            Variant::List(list) => path.push_list_index(list.inner.len()),
+
+            Variant::Map(_) => match self.key_stack.last() {
+                Some(Some(key)) => path.push_variant_key(key),
+
+                // The variant is the key (and a key container is unknown until it ends)
+                _ => path.push_variant_key(variant?),
+            },
+
+            _ => return None,
+        }
+
+        Some(path)
+    }
+
+    // Adds source and path (the root has no path)
+    fn annotate(&self, variant: &mut Variant<AnnotatedT>, path: Option<PathRepresentation>)
+    where
+        AnnotatedT: Annotated,
+    {
+        if AnnotatedT::can_have_annotations()
+            && let Some(annotations) = variant.annotations_mut()
+        {
+            if self.source.is_some() {
+                annotations.source = self.source.clone();
+            }
+
+            if self.source_id.is_some() {
+                annotations.source_id = self.source_id;
+            }
+
+            if let Some(path) = path
+                && !path.segments.is_empty()
+            {
+                annotations.path = Some(path);
+            }
+        }
     }
 }
 
@@ -263,11 +414,14 @@ pub struct StackEntry<AnnotatedT> {
 
     /// Optional reference.
     pub reference: Option<usize>,
+
+    /// Path (if recorded).
+    pub path: Option<PathRepresentation>,
 }
 
 impl<AnnotatedT> StackEntry<AnnotatedT> {
     /// Constructor.
     pub fn new(variant: Variant<AnnotatedT>, reference: Option<usize>) -> Self {
-        Self { variant, reference }
+        Self { variant, reference, path: None }
     }
 }
@@ -1,8 +1,8 @@
-use super::super::*;
+use super::super::{annotate::*, *};
 
 use {
     kutil::cli::depict::*,
-    std::{io, num::*, string},
+    std::{borrow::*, io, num::*, string},
     thiserror::*,
 };
 
@@ -21,6 +21,10 @@ pub enum ParseError {
     #[error("hint: {0}")]
     Hint(string::String),
 
+    /// Malformed.
+    #[error("malformed: {0}")]
+    Malformed(string::String),
+
     /// Reference not found.
     #[error("reference not found: {0}")]
     ReferenceNotFound(usize),
@@ -51,6 +55,11 @@ pub enum ParseError {
     #[error("Struson JSON: {0}")]
     Struson(#[from] struson::reader::ReaderError),
 
+    /// SIMD JSON.
+    #[cfg(feature = "simd-json")]
+    #[error("SIMD JSON: {0}")]
+    SimdJson(#[from] simd_json::Error),
+
     /// Borc (CBOR).
     #[cfg(feature = "cbor")]
     #[error("Borc CBOR: {0}")]
@@ -65,3 +74,24 @@ pub enum ParseError {
     #[error("Base64: {0}")]
     Base64(#[from] base64_simd::Error),
 }
+
+impl ReportDiagnostic for ParseError {
+    fn diagnostic_message(&self) -> String {
+        self.to_string()
+    }
+
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>> {
+        match self {
+            #[cfg(feature = "yaml")]
+            Self::Saphyr(scan_error) => {
+                // Saphyr's lines are 1-based
+                let marker = scan_error.marker();
+                let row = marker.line().saturating_sub(1);
+                let location = Location::new(Some(marker.index()), Some(row), Some(marker.col()));
+                Some(Cow::Owned(Annotations::default().with_span(Span::new(location, None))))
+            }
+
+            _ => None,
+        }
+    }
+}
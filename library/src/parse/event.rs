@@ -0,0 +1,149 @@
+use super::{
+    super::{annotate::*, normal::*},
+    error::*,
+};
+
+//
+// Event
+//
+
+/// Format-agnostic parser event.
+///
+/// A full stream of events describes exactly one [Variant]. Containers are started by
+/// [Event::StartMap] or [Event::StartList] and closed by a matching [Event::End]. Map entries
+/// are represented as alternating keys and values.
+///
+/// Scalar map keys are represented by [Event::Key]. Complex keys (lists and maps, which are
+/// supported by some formats) are represented by a container in the key position.
+///
+/// Scalars carry their span and label (if any) in their annotations.
+#[derive(Clone, Debug)]
+pub enum Event<AnnotatedT> {
+    /// Start of a [Map].
+    StartMap {
+        /// Optional span.
+        span: Option<Span>,
+
+        /// Optional label.
+        label: Option<Label>,
+    },
+
+    /// Start of a [List].
+    StartList {
+        /// Optional span.
+        span: Option<Span>,
+
+        /// Optional label.
+        label: Option<Label>,
+    },
+
+    /// Scalar map key.
+    Key(Variant<AnnotatedT>),
+
+    /// Scalar.
+    Scalar(Variant<AnnotatedT>),
+
+    /// End of the current container.
+    End,
+}
+
+impl<AnnotatedT> Event<AnnotatedT> {
+    /// Whether this is [Event::StartMap] or [Event::StartList].
+    pub fn is_start(&self) -> bool {
+        matches!(self, Self::StartMap { .. } | Self::StartList { .. })
+    }
+}
+
+//
+// EventNesting
+//
+
+/// Tracks container nesting for [Event] producers.
+///
+/// Its main purpose is to know whether the next scalar is a map key.
+#[derive(Clone, Debug, Default)]
+pub struct EventNesting {
+    /// Stack. True for maps expecting a key next, false for maps expecting a value next, and
+    /// [None] for lists.
+    pub stack: Vec<Option<bool>>,
+}
+
+impl EventNesting {
+    /// Current depth.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Whether we are not in a container.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Whether we are in a map.
+    pub fn in_map(&self) -> bool {
+        matches!(self.stack.last(), Some(Some(_)))
+    }
+
+    /// Whether we are in a list.
+    pub fn in_list(&self) -> bool {
+        matches!(self.stack.last(), Some(None))
+    }
+
+    /// Whether the next item is a map key.
+    pub fn expects_key(&self) -> bool {
+        matches!(self.stack.last(), Some(Some(true)))
+    }
+
+    /// Creates an [Event::Key] or an [Event::Scalar] according to the current position.
+    pub fn scalar<AnnotatedT>(&mut self, variant: Variant<AnnotatedT>) -> Event<AnnotatedT> {
+        if self.next_item() { Event::Key(variant) } else { Event::Scalar(variant) }
+    }
+
+    /// Creates an [Event::StartMap] and enters the map.
+    pub fn start_map<AnnotatedT>(&mut self, span: Option<Span>, label: Option<Label>) -> Event<AnnotatedT> {
+        self.next_item();
+        self.stack.push(Some(true));
+        Event::StartMap { span, label }
+    }
+
+    /// Creates an [Event::StartList] and enters the list.
+    pub fn start_list<AnnotatedT>(&mut self, span: Option<Span>, label: Option<Label>) -> Event<AnnotatedT> {
+        self.next_item();
+        self.stack.push(None);
+        Event::StartList { span, label }
+    }
+
+    /// Creates an [Event::End] and exits the current container.
+    pub fn end<AnnotatedT>(&mut self) -> Event<AnnotatedT> {
+        self.stack.pop();
+        Event::End
+    }
+
+    /// Normalizes an event according to the current position and updates the nesting.
+    ///
+    /// Useful for replaying recorded events.
+    pub fn normalize<AnnotatedT>(&mut self, event: Event<AnnotatedT>) -> Event<AnnotatedT> {
+        match event {
+            Event::StartMap { span, label } => self.start_map(span, label),
+            Event::StartList { span, label } => self.start_list(span, label),
+            Event::Key(variant) | Event::Scalar(variant) => self.scalar(variant),
+            Event::End => self.end(),
+        }
+    }
+
+    // Returns true if the item is a key
+    fn next_item(&mut self) -> bool {
+        match self.stack.last_mut() {
+            Some(Some(expects_key)) => {
+                let is_key = *expects_key;
+                *expects_key = !is_key;
+                is_key
+            }
+
+            _ => false,
+        }
+    }
+}
+
+/// Iterator of [Event] results.
+pub type Events<'own, AnnotatedT> = Box<dyn Iterator<Item = Result<Event<AnnotatedT>, ParseError>> + 'own>;
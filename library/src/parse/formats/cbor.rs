@@ -1,189 +1,365 @@
 use super::super::{
     super::{annotate::*, normal::*},
-    builder::*,
-    *,
+    Event as ParserEvent, *,
 };
 
 use {
-    borc::{basic::streaming::*, errors::*},
-    std::io,
+    borc::errors::*,
+    kutil::std::immutable::*,
+    std::{
+        io::{self, Read},
+        marker::*,
+    },
     tracing::trace,
 };
 
+pub(crate) const CBOR_BREAK: u8 = 0xff;
+pub(crate) const CBOR_UNKNOWN_LENGTH: u8 = 31;
+
 impl Parser {
     /// Parses CBOR into a [Variant].
     ///
-    /// Is affected by [Parser::base64](super::super::Parser).
+    /// Is affected by [Parser::base64](super::super::Parser)
+    /// and [Parser::lossless_numbers](super::super::Parser).
     pub fn parse_cbor<ReadT, AnnotatedT>(&self, reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
     where
         ReadT: io::Read,
         AnnotatedT: Annotated + Clone + Default,
     {
-        let mut value_builder = VariantBuilder::new(self.source.clone());
+        let mut value_builder = self.variant_builder();
         if self.base64 {
-            let reader = Self::base64_reader(reader);
-            let mut decoder = Decoder::new(reader);
-            read_next_cbor(&mut decoder, &mut value_builder, None)?;
+            value_builder.add_events(self.cbor_events(Self::base64_reader(reader)), None)?;
         } else {
-            let mut decoder = Decoder::new(reader);
-            read_next_cbor(&mut decoder, &mut value_builder, None)?;
+            value_builder.add_events(self.cbor_events(reader), None)?;
         }
         Ok(value_builder.finalize())
     }
+
+    /// Parses CBOR into [ParserEvent]s.
+    ///
+    /// Note that this does *not* handle [Parser::base64](super::super::Parser).
+    ///
+    /// Is affected by [Parser::lossless_numbers](super::super::Parser).
+    pub fn cbor_events<ReadT, AnnotatedT>(&self, reader: ReadT) -> CborEvents<ReadT, AnnotatedT>
+    where
+        ReadT: io::Read,
+    {
+        CborEvents::new(reader, self.lossless_numbers)
+    }
 }
 
-// Utils
+//
+// CborEvents
+//
 
-fn read_next_cbor<ReadT, AnnotatedT>(
-    decoder: &mut Decoder<ReadT>,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    label: Option<Label>,
-) -> Result<bool, ParseError>
+/// CBOR [ParserEvent] iterator.
+pub struct CborEvents<ReadT, AnnotatedT>
 where
     ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
 {
-    let event = decoder.next_event()?;
-    trace!("{:?}", event);
+    reader: ReadT,
+    lossless_numbers: bool,
+    nesting: EventNesting,
 
-    match event {
-        Event::Break => {
-            return Ok(false);
-        }
+    // Remaining items for each open container (None for unknown length)
+    remaining: Vec<Option<u64>>,
 
-        Event::Tag(tag) => {
-            // https://www.rfc-editor.org/rfc/rfc8949.html#name-tagging-of-items
-            return read_next_cbor(decoder, value_builder, Some(Label::Integer(tag as i64)));
-        }
+    started: bool,
+    finished: bool,
+    annotated: PhantomData<AnnotatedT>,
+}
 
-        Event::Null => {
-            value_builder.add(Null::default().with_label(label), None);
+impl<ReadT, AnnotatedT> CborEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+{
+    /// Constructor.
+    pub fn new(reader: ReadT, lossless_numbers: bool) -> Self {
+        Self {
+            reader,
+            lossless_numbers,
+            nesting: Default::default(),
+            remaining: Default::default(),
+            started: false,
+            finished: false,
+            annotated: PhantomData,
         }
+    }
 
-        Event::Unsigned(unsigned_integer) => {
-            value_builder.add(UnsignedInteger::from(unsigned_integer).with_label(label), None);
+    // We decode the bytes ourselves rather than with borc's streaming decoder, because the latter
+    // loses track of indefinite-length maps when an item is split across reads
+    //
+    // See: https://www.rfc-editor.org/rfc/rfc8949.html#name-specification-of-the-cbor-e
+    fn next_event(&mut self) -> Result<Option<ParserEvent<AnnotatedT>>, ParseError>
+    where
+        AnnotatedT: Annotated + Default,
+    {
+        // Close containers that have all their items
+        if let Some(Some(0)) = self.remaining.last() {
+            self.remaining.pop();
+            return Ok(Some(self.nesting.end()));
         }
 
-        Event::Signed(integer) => {
-            let integer = Event::interpret_signed_checked(integer).ok_or_else(|| DecodeError::Malformed)?;
-            value_builder.add(Integer::from(integer).with_label(label), None);
+        if self.started && self.remaining.is_empty() {
+            return Ok(None);
         }
 
-        Event::Float(float) => {
-            value_builder.add(Float::from(float).with_label(label), None);
-        }
+        self.started = true;
 
-        Event::Bool(boolean) => {
-            value_builder.add(Boolean::from(boolean).with_label(label), None);
-        }
+        let reader = &mut self.reader;
+        let mut byte = read_cbor_u8(reader)?;
+        trace!("{:#04x}", byte);
 
-        Event::TextString(string) => {
-            value_builder.add(Text::from(string).with_label(label), None);
+        if byte == CBOR_BREAK {
+            return match self.remaining.pop() {
+                Some(None) => Ok(Some(self.nesting.end())),
+                _ => Err(DecodeError::Malformed.into()),
+            };
         }
 
-        Event::UnknownLengthTextString => {
-            let string = read_cbor_unknown_length_text_string(decoder)?;
-            value_builder.add(Text::from(string).with_label(label), None);
+        if let Some(Some(remaining)) = self.remaining.last_mut() {
+            *remaining -= 1;
         }
 
-        Event::ByteString(bytes) => {
-            value_builder.add(Blob::from(bytes).with_label(label), None);
+        // https://www.rfc-editor.org/rfc/rfc8949.html#name-tagging-of-items
+        let mut label = None;
+        let mut last_tag = None;
+        while byte >> 5 == 6 {
+            let tag = read_cbor_argument(reader, byte & 0x1f)?;
+            label = Some(Label::Integer(tag as i64));
+            last_tag = Some(tag);
+            byte = read_cbor_u8(reader)?;
         }
 
-        Event::UnknownLengthByteString => {
-            let bytes = read_cbor_unknown_length_bytes(decoder)?;
-            value_builder.add(Blob::from(bytes).with_label(label), None);
-        }
+        let info = byte & 0x1f;
 
-        Event::Array(length) => {
-            value_builder.start_list_with_label(label, None);
-            for _ in 0..length {
-                read_next_cbor(decoder, value_builder, None)?;
+        let variant: Variant<AnnotatedT> = match byte >> 5 {
+            // https://www.rfc-editor.org/rfc/rfc8949.html#name-bignums
+            2 if self.lossless_numbers && matches!(last_tag, Some(2 | 3)) => {
+                bignum_variant(last_tag.unwrap_or_default(), &read_cbor_string_bytes(reader, 2, info)?)
             }
-            value_builder.end_container();
-        }
 
-        Event::UnknownLengthArray => {
-            value_builder.start_list_with_label(label, None);
-            loop {
-                match decoder.next_event()? {
-                    Event::Break => {
-                        break;
-                    }
+            // https://www.rfc-editor.org/rfc/rfc8949.html#name-decimal-fractions-and-bigfl
+            4 if self.lossless_numbers && (last_tag == Some(4)) && (info == 2) => read_cbor_decimal_fraction(reader)?,
+
+            0 => UnsignedInteger::from(read_cbor_argument(reader, info)?).with_label(label).into(),
 
-                    _ => {
-                        read_next_cbor(decoder, value_builder, None)?;
+            1 => {
+                let integer = read_cbor_argument(reader, info)?;
+                if integer > i64::MAX as u64 {
+                    // Below i64::MIN
+                    if !self.lossless_numbers {
+                        return Err(DecodeError::Malformed.into());
                     }
+                    Number::from(ByteString::from(format!("-{}", integer as u128 + 1))).with_label(label).into()
+                } else {
+                    Integer::from(-1 - integer as i64).with_label(label).into()
                 }
             }
-            value_builder.end_container();
-        }
 
-        Event::Map(length) => {
-            value_builder.start_map_with_label(label, None);
-            for _ in 0..length {
-                read_next_cbor(decoder, value_builder, None)?;
-                read_next_cbor(decoder, value_builder, None)?;
+            2 => Blob::from(read_cbor_string_bytes(reader, 2, info)?).with_label(label).into(),
+
+            3 => Text::from(String::from_utf8(read_cbor_string_bytes(reader, 3, info)?)?).with_label(label).into(),
+
+            4 => {
+                let length = if info == CBOR_UNKNOWN_LENGTH { None } else { Some(read_cbor_argument(reader, info)?) };
+                self.remaining.push(length);
+                return Ok(Some(self.nesting.start_list(None, label)));
             }
-            value_builder.end_container();
-        }
 
-        Event::UnknownLengthMap => {
-            value_builder.start_map_with_label(label, None);
-            loop {
-                match decoder.next_event()? {
-                    Event::Break => {
-                        break;
-                    }
+            5 => {
+                let length = if info == CBOR_UNKNOWN_LENGTH {
+                    None
+                } else {
+                    // Keys and values are counted separately
+                    Some(read_cbor_argument(reader, info)?.checked_mul(2).ok_or(DecodeError::Malformed)?)
+                };
+                self.remaining.push(length);
+                return Ok(Some(self.nesting.start_map(None, label)));
+            }
 
-                    _ => {
-                        read_next_cbor(decoder, value_builder, None)?;
-                    }
+            _ => match info {
+                20 => Boolean::from(false).with_label(label).into(),
+                21 => Boolean::from(true).with_label(label).into(),
+                22 => Null::default().with_label(label).into(),
+                // Undefined is not in CPS, but it still takes up its place in the container
+                23 => Null::default().with_label(label).into(),
+                25 => {
+                    let float = half::f16::from_be_bytes(read_cbor_bytes_array(reader)?);
+                    Float::from(float.to_f64()).with_label(label).into()
                 }
-            }
-            value_builder.end_container();
+                26 => Float::from(f32::from_be_bytes(read_cbor_bytes_array(reader)?) as f64).with_label(label).into(),
+                27 => Float::from(f64::from_be_bytes(read_cbor_bytes_array(reader)?)).with_label(label).into(),
+                _ => return Err(DecodeError::Malformed.into()),
+            },
+        };
+
+        Ok(Some(self.nesting.scalar(variant)))
+    }
+}
+
+impl<ReadT, AnnotatedT> Iterator for CborEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+    AnnotatedT: Annotated + Default,
+{
+    type Item = Result<ParserEvent<AnnotatedT>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
         }
 
-        Event::Undefined => {}
+        let event = self.next_event().transpose();
+        if matches!(event, None | Some(Err(_))) {
+            self.finished = true;
+        }
+        event
     }
+}
+
+// Utils
+
+// https://www.rfc-editor.org/rfc/rfc8949.html#name-bignums
+pub(crate) fn bignum_variant<AnnotatedT>(tag: u64, bytes: &[u8]) -> Variant<AnnotatedT>
+where
+    AnnotatedT: Default,
+{
+    let (negative, digits) = bignum(tag, bytes);
+    let text = decimal_text(negative, &digits, 0);
+    if let Ok(unsigned_integer) = text.parse::<u64>() {
+        UnsignedInteger::from(unsigned_integer).into()
+    } else if let Ok(integer) = text.parse::<i64>() {
+        Integer::from(integer).into()
+    } else {
+        Number::from(ByteString::from(text)).into()
+    }
+}
+
+// Returns sign (true if negative) and digits
+pub(crate) fn bignum(tag: u64, bytes: &[u8]) -> (bool, String) {
+    if tag == 3 {
+        // Negative bignums are encoded as -1 - n
+        let mut bytes = bytes.to_vec();
+        let mut carry = true;
+        for byte in bytes.iter_mut().rev() {
+            (*byte, carry) = byte.overflowing_add(1);
+            if !carry {
+                break;
+            }
+        }
+        if carry {
+            bytes.insert(0, 1);
+        }
+        (true, big_endian_to_decimal(&bytes))
+    } else {
+        (false, big_endian_to_decimal(bytes))
+    }
+}
 
-    Ok(true)
+fn read_cbor_u8<ReadT>(reader: &mut ReadT) -> Result<u8, ParseError>
+where
+    ReadT: io::Read,
+{
+    Ok(read_cbor_bytes_array::<_, 1>(reader)?[0])
 }
 
-fn read_cbor_unknown_length_text_string<ReadT>(decoder: &mut Decoder<ReadT>) -> Result<String, DecodeError>
+fn read_cbor_bytes_array<ReadT, const SIZE: usize>(reader: &mut ReadT) -> Result<[u8; SIZE], ParseError>
 where
     ReadT: io::Read,
 {
-    let mut buffer = String::default();
+    let mut bytes = [0; SIZE];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
 
-    loop {
-        match decoder.next_event()? {
-            Event::TextString(string) => {
-                buffer.push_str(&string);
-            }
+fn read_cbor_argument<ReadT>(reader: &mut ReadT, info: u8) -> Result<u64, ParseError>
+where
+    ReadT: io::Read,
+{
+    Ok(match info {
+        0..24 => info as u64,
+        24 => read_cbor_u8(reader)? as u64,
+        25 => u16::from_be_bytes(read_cbor_bytes_array(reader)?) as u64,
+        26 => u32::from_be_bytes(read_cbor_bytes_array(reader)?) as u64,
+        27 => u64::from_be_bytes(read_cbor_bytes_array(reader)?),
+        _ => return Err(DecodeError::Malformed.into()),
+    })
+}
 
-            Event::Break => return Ok(buffer),
+// https://www.rfc-editor.org/rfc/rfc8949.html#name-decimal-fractions-and-bigfl
+fn read_cbor_decimal_fraction<ReadT, AnnotatedT>(reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
+where
+    ReadT: io::Read,
+    AnnotatedT: Default,
+{
+    let byte = read_cbor_u8(reader)?;
+    let exponent = read_cbor_argument(reader, byte & 0x1f)?;
+    let exponent = match byte >> 5 {
+        0 => i64::try_from(exponent).map_err(|_| DecodeError::Malformed)?,
+        1 => -1 - i64::try_from(exponent).map_err(|_| DecodeError::Malformed)?,
+        _ => return Err(DecodeError::Malformed.into()),
+    };
 
-            _ => return Err(DecodeError::Malformed),
+    let byte = read_cbor_u8(reader)?;
+    let (negative, digits) = match byte >> 5 {
+        0 => (false, read_cbor_argument(reader, byte & 0x1f)?.to_string()),
+        1 => (true, (read_cbor_argument(reader, byte & 0x1f)? as u128 + 1).to_string()),
+        6 => {
+            let tag = read_cbor_argument(reader, byte & 0x1f)?;
+            let byte = read_cbor_u8(reader)?;
+            if !matches!(tag, 2 | 3) || (byte >> 5 != 2) {
+                return Err(DecodeError::Malformed.into());
+            }
+            bignum(tag, &read_cbor_string_bytes(reader, 2, byte & 0x1f)?)
         }
-    }
+        _ => return Err(DecodeError::Malformed.into()),
+    };
+
+    let text = decimal_text(negative, &digits, exponent);
+    Ok(if is_lossless_f64(&text) {
+        Float::from(text.parse::<f64>()?).into()
+    } else {
+        Number::from(ByteString::from(text)).into()
+    })
 }
 
-fn read_cbor_unknown_length_bytes<ReadT>(decoder: &mut Decoder<ReadT>) -> Result<Vec<u8>, DecodeError>
+// Byte strings (major 2) and text strings (major 3)
+fn read_cbor_string_bytes<ReadT>(reader: &mut ReadT, major: u8, info: u8) -> Result<Vec<u8>, ParseError>
 where
     ReadT: io::Read,
 {
     let mut buffer = Vec::default();
 
+    if info != CBOR_UNKNOWN_LENGTH {
+        let length = read_cbor_argument(reader, info)?;
+        read_cbor_bytes(reader, length, &mut buffer)?;
+        return Ok(buffer);
+    }
+
     loop {
-        match decoder.next_event()? {
-            Event::ByteString(bytes) => {
-                buffer.extend_from_slice(&bytes);
-            }
+        let byte = read_cbor_u8(reader)?;
 
-            Event::Break => return Ok(buffer),
+        if byte == CBOR_BREAK {
+            return Ok(buffer);
+        }
 
-            _ => return Err(DecodeError::Malformed),
+        // Chunks must be of the same major type and of known length
+        if (byte >> 5 != major) || (byte & 0x1f == CBOR_UNKNOWN_LENGTH) {
+            return Err(DecodeError::Malformed.into());
         }
+
+        let length = read_cbor_argument(reader, byte & 0x1f)?;
+        read_cbor_bytes(reader, length, &mut buffer)?;
     }
 }
+
+// The length is untrusted, so we don't allocate for it in advance
+fn read_cbor_bytes<ReadT>(reader: &mut ReadT, length: u64, buffer: &mut Vec<u8>) -> Result<(), ParseError>
+where
+    ReadT: io::Read,
+{
+    trace!("bytes length: {}", length);
+    let read = reader.take(length).read_to_end(buffer)?;
+    if read as u64 == length { Ok(()) } else { Err(DecodeError::Malformed.into()) }
+}
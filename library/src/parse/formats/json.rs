@@ -4,17 +4,21 @@ use super::super::{
         hints::*,
         normal::{Variant, *},
     },
-    builder::*,
     *,
 };
 
-use {kutil::std::immutable::*, std::io, struson::reader::*};
+use {
+    kutil::std::immutable::*,
+    std::{io, marker::*},
+    struson::reader::*,
+};
 
 impl Parser {
     /// Parses JSON into a [Variant].
     ///
-    /// Is affected by [Parser::try_integers](super::super::Parser)
-    /// and [Parser::try_unsigned_integers](super::super::Parser).
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
     pub fn parse_json<ReadT, AnnotatedT>(&self, reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
     where
         ReadT: io::Read,
@@ -25,8 +29,9 @@ impl Parser {
 
     /// Parses XJSON into a [Variant].
     ///
-    /// Is affected by [Parser::try_integers](super::super::Parser)
-    /// and [Parser::try_unsigned_integers](super::super::Parser).
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
     pub fn parse_xjson<ReadT, AnnotatedT>(&self, reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
     where
         ReadT: io::Read,
@@ -37,8 +42,13 @@ impl Parser {
 
     /// Parses JSON into a [Variant].
     ///
-    /// Is affected by [Parser::try_integers](super::super::Parser)
-    /// and [Parser::try_unsigned_integers](super::super::Parser).
+    /// With the `simd-json` feature, will use SIMD acceleration when annotations are not
+    /// supported by `AnnotatedT` (because we cannot get spans from it) and
+    /// [Parser::lossless_numbers](super::super::Parser) is false.
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
     pub fn parse_json_with_hints<ReadT, AnnotatedT>(
         &self,
         reader: &mut ReadT,
@@ -48,98 +58,199 @@ impl Parser {
         ReadT: io::Read,
         AnnotatedT: Annotated + Clone + Default,
     {
-        let mut reader = JsonStreamReader::new(reader);
-        let mut value_builder = VariantBuilder::new(self.source.clone());
-        read_next_json(&mut reader, &mut value_builder, hints, self.try_integers, self.try_unsigned_integers)?;
+        #[cfg(feature = "simd-json")]
+        if !AnnotatedT::can_have_annotations() && !self.lossless_numbers {
+            return self.parse_simd_json_with_hints(reader, hints);
+        }
+
+        let mut value_builder = self.variant_builder();
+        value_builder.add_events(self.json_events(reader), hints)?;
         Ok(value_builder.finalize())
     }
+
+    /// Parses JSON into [Event]s.
+    ///
+    /// Note that XJSON hints are not processed.
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser),
+    /// [Parser::try_unsigned_integers](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
+    pub fn json_events<ReadT, AnnotatedT>(&self, reader: ReadT) -> JsonEvents<ReadT, AnnotatedT>
+    where
+        ReadT: io::Read,
+        AnnotatedT: Annotated,
+    {
+        JsonEvents::new(reader, self.try_integers, self.try_unsigned_integers, self.lossless_numbers)
+    }
 }
 
-// Utils
+//
+// JsonEvents
+//
 
-fn read_next_json<JsonReaderT, AnnotatedT>(
-    reader: &mut JsonReaderT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    hints: Option<&Hints>,
-    try_integers: bool,
-    try_unsigned_integers: bool,
-) -> Result<(), ParseError>
+/// JSON [Event] iterator.
+pub struct JsonEvents<ReadT, AnnotatedT>
 where
-    JsonReaderT: JsonReader,
-    AnnotatedT: Annotated + Clone + Default,
+    ReadT: io::Read,
 {
-    let get_span = if AnnotatedT::can_have_annotations() {
-        |reader: &mut JsonReaderT| -> Option<Span> { get_json_span(reader) }
-    } else {
-        |_reader: &mut JsonReaderT| -> Option<Span> { None }
-    };
+    // Taken when checking for trailing data
+    reader: Option<JsonStreamReader<ReadT>>,
+    decoder: JsonScalarDecoder,
+    nesting: EventNesting,
+    started: bool,
+    finished: bool,
+    span: fn(&mut JsonStreamReader<ReadT>) -> Option<Span>,
+    annotated: PhantomData<AnnotatedT>,
+}
 
-    let value = reader.peek()?;
-    tracing::trace!("{}", value);
-    match value {
-        ValueType::Null => {
-            let span = get_span(reader);
-            reader.next_null()?;
-            value_builder.add(Null::default().with_span(span), None);
+impl<ReadT, AnnotatedT> JsonEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+    AnnotatedT: Annotated,
+{
+    /// Constructor.
+    pub fn new(reader: ReadT, try_integers: bool, try_unsigned_integers: bool, lossless_numbers: bool) -> Self {
+        let decoder = JsonScalarDecoder::new(try_integers, try_unsigned_integers, lossless_numbers);
+        Self {
+            reader: Some(JsonStreamReader::new_custom(reader, decoder.reader_settings())),
+            decoder,
+            nesting: Default::default(),
+            started: false,
+            finished: false,
+            span: if AnnotatedT::can_have_annotations() { |reader| get_json_span(reader) } else { |_| None },
+            annotated: PhantomData,
         }
+    }
 
-        ValueType::Number => {
-            if try_integers || try_unsigned_integers {
-                let span = get_span(reader);
-                let number = reader.next_number_as_str()?;
-                if let Some(number) = if try_unsigned_integers { number.parse::<u64>().ok() } else { None } {
-                    value_builder.add(UnsignedInteger::from(number).with_span(span), None);
-                } else if let Some(number) = if try_integers { number.parse::<i64>().ok() } else { None } {
-                    value_builder.add(Integer::from(number).with_span(span), None);
-                } else {
-                    value_builder.add(Float::from(number.parse::<f64>()?).with_span(span), None);
-                }
-            } else {
-                let span = get_span(reader);
-                let number: f64 = reader.next_number()??;
-                value_builder.add(Float::from(number).with_span(span), None);
+    fn next_event(&mut self) -> Result<Option<Event<AnnotatedT>>, ParseError>
+    where
+        AnnotatedT: Default,
+    {
+        if self.nesting.is_empty() && self.started {
+            // Only whitespace may follow the root value
+            if let Some(reader) = self.reader.take() {
+                reader.consume_trailing_whitespace()?;
             }
+            return Ok(None);
         }
 
-        ValueType::Boolean => {
-            let span = get_span(reader);
-            value_builder.add(Boolean::from(reader.next_bool()?).with_span(span), None);
-        }
-
-        ValueType::String => {
-            let span = get_span(reader);
-            value_builder.add(Text::from(ByteString::from(reader.next_str()?)).with_span(span), None);
-        }
+        let Some(reader) = &mut self.reader else {
+            return Ok(None);
+        };
 
-        ValueType::Array => {
-            let span = get_span(reader);
-            reader.begin_array()?;
-            value_builder.start_list_with_span(span, None);
-            while reader.has_next()? {
-                read_next_json(reader, value_builder, hints, try_integers, try_unsigned_integers)?;
+        if self.nesting.expects_key() {
+            if !reader.has_next()? {
+                reader.end_object()?;
+                return Ok(Some(self.nesting.end()));
             }
-            value_builder.end_container();
+
+            let span = (self.span)(reader);
+            let key = Text::from(ByteString::from(reader.next_name()?)).with_span(span);
+            return Ok(Some(self.nesting.scalar(key.into())));
+        } else if self.nesting.in_list() && !reader.has_next()? {
             reader.end_array()?;
+            return Ok(Some(self.nesting.end()));
         }
 
-        ValueType::Object => {
-            let span = get_span(reader);
-            reader.begin_object()?;
-            value_builder.start_map_with_span(span, None);
-            while reader.has_next()? {
-                // Key
-                let span = get_span(reader);
-                value_builder.add(Text::from(ByteString::from(reader.next_name()?)).with_span(span), None);
-
-                // Value
-                read_next_json(reader, value_builder, hints, try_integers, try_unsigned_integers)?;
+        self.started = true;
+
+        let value = reader.peek()?;
+        tracing::trace!("{}", value);
+        let span = (self.span)(reader);
+        Ok(Some(match value {
+            ValueType::Array => {
+                reader.begin_array()?;
+                self.nesting.start_list(span, None)
             }
-            value_builder.end_container_with_hints(hints)?;
-            reader.end_object()?;
+
+            ValueType::Object => {
+                reader.begin_object()?;
+                self.nesting.start_map(span, None)
+            }
+
+            _ => self.nesting.scalar(self.decoder.decode(reader, value, span)?),
+        }))
+    }
+}
+
+impl<ReadT, AnnotatedT> Iterator for JsonEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+    AnnotatedT: Annotated + Default,
+{
+    type Item = Result<Event<AnnotatedT>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let event = self.next_event().transpose();
+        if matches!(event, None | Some(Err(_))) {
+            self.finished = true;
         }
+        event
+    }
+}
+
+//
+// JsonScalarDecoder
+//
+
+// Decodes JSON scalars (everything but arrays and objects) from a Struson reader
+#[derive(Clone, Copy, Debug)]
+struct JsonScalarDecoder {
+    try_integers: bool,
+    try_unsigned_integers: bool,
+    lossless_numbers: bool,
+}
+
+impl JsonScalarDecoder {
+    fn new(try_integers: bool, try_unsigned_integers: bool, lossless_numbers: bool) -> Self {
+        Self { try_integers, try_unsigned_integers, lossless_numbers }
     }
 
-    Ok(())
+    fn reader_settings(&self) -> ReaderSettings {
+        // Numbers with huge exponents or many digits are rejected by default
+        ReaderSettings { restrict_number_values: !self.lossless_numbers, ..Default::default() }
+    }
+
+    fn decode<JsonReaderT, AnnotatedT>(
+        &self,
+        reader: &mut JsonReaderT,
+        value_type: ValueType,
+        span: Option<Span>,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        JsonReaderT: JsonReader,
+        AnnotatedT: Annotated + Default,
+    {
+        Ok(match value_type {
+            ValueType::Null => {
+                reader.next_null()?;
+                Null::default().with_span(span).into()
+            }
+
+            ValueType::Number => {
+                let number = reader.next_number_as_str()?;
+                if let Some(number) = if self.try_unsigned_integers { number.parse::<u64>().ok() } else { None } {
+                    UnsignedInteger::from(number).with_span(span).into()
+                } else if let Some(number) = if self.try_integers { number.parse::<i64>().ok() } else { None } {
+                    Integer::from(number).with_span(span).into()
+                } else if self.lossless_numbers && !is_lossless_f64(number) {
+                    Number::from(ByteString::from(number)).with_span(span).into()
+                } else {
+                    Float::from(number.parse::<f64>()?).with_span(span).into()
+                }
+            }
+
+            ValueType::Boolean => Boolean::from(reader.next_bool()?).with_span(span).into(),
+
+            ValueType::String => Text::from(ByteString::from(reader.next_str()?)).with_span(span).into(),
+
+            ValueType::Array | ValueType::Object => unreachable!("not a scalar: {}", value_type),
+        })
+    }
 }
 
 // Note that Struson only provides the start of the span
@@ -3,13 +3,12 @@ use super::super::{
         annotate::*,
         normal::{Blob, *},
     },
-    builder::*,
     *,
 };
 
 use {
     rmp::{decode::*, *},
-    std::io,
+    std::{io, marker::*},
     tracing::trace,
 };
 
@@ -22,232 +21,259 @@ impl Parser {
         ReadT: io::Read,
         AnnotatedT: Annotated + Clone + Default,
     {
-        let mut value_builder = VariantBuilder::new(self.source.clone());
+        let mut value_builder = self.variant_builder();
         if self.base64 {
-            let mut reader = Self::base64_reader(reader);
-            read_next_message_pack(&mut reader, &mut value_builder)?;
+            value_builder.add_events(self.message_pack_events(Self::base64_reader(reader)), None)?;
         } else {
-            read_next_message_pack(reader, &mut value_builder)?;
+            value_builder.add_events(self.message_pack_events(reader), None)?;
         }
         Ok(value_builder.finalize())
     }
+
+    /// Parses MessagePack into [Event]s.
+    ///
+    /// Note that this does *not* handle [Parser::base64](super::super::Parser).
+    pub fn message_pack_events<ReadT, AnnotatedT>(&self, reader: ReadT) -> MessagePackEvents<ReadT, AnnotatedT>
+    where
+        ReadT: io::Read,
+    {
+        MessagePackEvents::new(reader)
+    }
 }
 
-// Utils
+//
+// MessagePackEvents
+//
 
-fn read_next_message_pack<ReadT, AnnotatedT>(
-    reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-) -> Result<(), ParseError>
+/// MessagePack [Event] iterator.
+pub struct MessagePackEvents<ReadT, AnnotatedT>
 where
     ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
 {
-    let marker = read_marker(reader)?;
-    trace!("{:?}", marker);
-    match marker {
-        Marker::Reserved => {}
-        Marker::Null => value_builder.add(Null::default(), None),
-        Marker::True => value_builder.add(Boolean::from(true), None),
-        Marker::False => value_builder.add(Boolean::from(false), None),
-        Marker::FixNeg(integer) => value_builder.add(Integer::from(integer as i64), None),
-        Marker::I8 => value_builder.add(Integer::from(read_i8(reader)? as i64), None),
-        Marker::I16 => value_builder.add(Integer::from(read_i16(reader)? as i64), None),
-        Marker::I32 => value_builder.add(Integer::from(read_i32(reader)? as i64), None),
-        Marker::I64 => value_builder.add(Integer::from(read_i64(reader)?), None),
-        Marker::FixPos(integer) => value_builder.add(UnsignedInteger::from(integer as u64), None),
-        Marker::U8 => value_builder.add(UnsignedInteger::from(read_u8(reader)? as u64), None),
-        Marker::U16 => value_builder.add(UnsignedInteger::from(read_u16(reader)? as u64), None),
-        Marker::U32 => value_builder.add(UnsignedInteger::from(read_u32(reader)? as u64), None),
-        Marker::U64 => value_builder.add(UnsignedInteger::from(read_u64(reader)?), None),
-        Marker::F32 => value_builder.add(Float::from(read_f32(reader)?), None),
-        Marker::F64 => value_builder.add(Float::from(read_f64(reader)?), None),
-
-        Marker::Bin8 => {
-            let length = read_u8(reader)? as usize;
-            read_message_pack_bytes(reader, value_builder, length)?;
-        }
+    reader: ReadT,
+    nesting: EventNesting,
 
-        Marker::Bin16 => {
-            let length = read_u16(reader)? as usize;
-            read_message_pack_bytes(reader, value_builder, length)?;
-        }
-
-        Marker::Bin32 => {
-            let length = read_u32(reader)? as usize;
-            read_message_pack_bytes(reader, value_builder, length)?;
-        }
-
-        Marker::FixStr(length) => read_message_pack_string(reader, value_builder, length as usize)?,
-
-        Marker::Str8 => {
-            let length = read_u8(reader)? as usize;
-            read_message_pack_string(reader, value_builder, length)?;
-        }
-
-        Marker::Str16 => {
-            let length = read_u16(reader)? as usize;
-            read_message_pack_string(reader, value_builder, length)?;
-        }
+    // Remaining items for each open container
+    remaining: Vec<usize>,
 
-        Marker::Str32 => {
-            let length = read_u32(reader)? as usize;
-            read_message_pack_string(reader, value_builder, length)?;
-        }
+    started: bool,
+    finished: bool,
+    annotated: PhantomData<AnnotatedT>,
+}
 
-        Marker::FixExt1 => {
-            let label = read_i8(reader)? as i64;
-            read_message_pack_ext(reader, value_builder, 1, label)?;
+impl<ReadT, AnnotatedT> MessagePackEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+{
+    /// Constructor.
+    pub fn new(reader: ReadT) -> Self {
+        Self {
+            reader,
+            nesting: Default::default(),
+            remaining: Default::default(),
+            started: false,
+            finished: false,
+            annotated: PhantomData,
         }
+    }
 
-        Marker::FixExt2 => {
-            let label = read_i8(reader)? as i64;
-            read_message_pack_ext(reader, value_builder, 2, label)?;
+    fn next_event(&mut self) -> Result<Option<Event<AnnotatedT>>, ParseError>
+    where
+        AnnotatedT: Annotated + Default,
+    {
+        // Close containers that have all their items
+        if let Some(0) = self.remaining.last() {
+            self.remaining.pop();
+            return Ok(Some(self.nesting.end()));
         }
 
-        Marker::FixExt4 => {
-            let label = read_i8(reader)? as i64;
-            read_message_pack_ext(reader, value_builder, 4, label)?;
+        if self.started && self.remaining.is_empty() {
+            return Ok(None);
         }
 
-        Marker::FixExt8 => {
-            let label = read_i8(reader)? as i64;
-            read_message_pack_ext(reader, value_builder, 8, label)?;
-        }
+        self.started = true;
 
-        Marker::FixExt16 => {
-            let label = read_i8(reader)? as i64;
-            read_message_pack_ext(reader, value_builder, 16, label)?;
-        }
+        let reader = &mut self.reader;
 
-        Marker::Ext8 => {
-            let label = read_i8(reader)? as i64;
-            let length = read_u8(reader)? as usize;
-            read_message_pack_ext(reader, value_builder, length, label)?;
-        }
+        // Reserved markers are not items, so they are skipped without being counted
+        let marker = loop {
+            let marker = read_marker(reader)?;
+            trace!("{:?}", marker);
+            if marker != Marker::Reserved {
+                break marker;
+            }
+        };
 
-        Marker::Ext16 => {
-            let label = read_i8(reader)? as i64;
-            let length = read_u16(reader)? as usize;
-            read_message_pack_ext(reader, value_builder, length, label)?;
+        if let Some(remaining) = self.remaining.last_mut() {
+            *remaining -= 1;
         }
 
-        Marker::Ext32 => {
-            let label = read_i8(reader)? as i64;
-            let length = read_u32(reader)? as usize;
-            read_message_pack_ext(reader, value_builder, length, label)?;
-        }
+        let variant: Variant<AnnotatedT> = match marker {
+            Marker::Reserved => unreachable!(),
+            Marker::Null => Null::default().into(),
+            Marker::True => Boolean::from(true).into(),
+            Marker::False => Boolean::from(false).into(),
+            Marker::FixNeg(integer) => Integer::from(integer as i64).into(),
+            Marker::I8 => Integer::from(reader.read_data_i8()? as i64).into(),
+            Marker::I16 => Integer::from(reader.read_data_i16()? as i64).into(),
+            Marker::I32 => Integer::from(reader.read_data_i32()? as i64).into(),
+            Marker::I64 => Integer::from(reader.read_data_i64()?).into(),
+            Marker::FixPos(integer) => UnsignedInteger::from(integer as u64).into(),
+            Marker::U8 => UnsignedInteger::from(reader.read_data_u8()? as u64).into(),
+            Marker::U16 => UnsignedInteger::from(reader.read_data_u16()? as u64).into(),
+            Marker::U32 => UnsignedInteger::from(reader.read_data_u32()? as u64).into(),
+            Marker::U64 => UnsignedInteger::from(reader.read_data_u64()?).into(),
+            Marker::F32 => Float::from(reader.read_data_f32()?).into(),
+            Marker::F64 => Float::from(reader.read_data_f64()?).into(),
+
+            Marker::Bin8 => {
+                let length = reader.read_data_u8()? as usize;
+                Blob::from(read_message_pack_bytes(reader, length)?).into()
+            }
+
+            Marker::Bin16 => {
+                let length = reader.read_data_u16()? as usize;
+                Blob::from(read_message_pack_bytes(reader, length)?).into()
+            }
+
+            Marker::Bin32 => {
+                let length = reader.read_data_u32()? as usize;
+                Blob::from(read_message_pack_bytes(reader, length)?).into()
+            }
+
+            Marker::FixStr(length) => Text::from(read_message_pack_string(reader, length as usize)?).into(),
+
+            Marker::Str8 => {
+                let length = reader.read_data_u8()? as usize;
+                Text::from(read_message_pack_string(reader, length)?).into()
+            }
+
+            Marker::Str16 => {
+                let length = reader.read_data_u16()? as usize;
+                Text::from(read_message_pack_string(reader, length)?).into()
+            }
+
+            Marker::Str32 => {
+                let length = reader.read_data_u32()? as usize;
+                Text::from(read_message_pack_string(reader, length)?).into()
+            }
+
+            Marker::FixExt1 => read_message_pack_ext(reader, 1)?,
+            Marker::FixExt2 => read_message_pack_ext(reader, 2)?,
+            Marker::FixExt4 => read_message_pack_ext(reader, 4)?,
+            Marker::FixExt8 => read_message_pack_ext(reader, 8)?,
+            Marker::FixExt16 => read_message_pack_ext(reader, 16)?,
+
+            // Note that for non-fixed extensions the length comes before the label
+            Marker::Ext8 => {
+                let length = reader.read_data_u8()? as usize;
+                read_message_pack_ext(reader, length)?
+            }
+
+            Marker::Ext16 => {
+                let length = reader.read_data_u16()? as usize;
+                read_message_pack_ext(reader, length)?
+            }
+
+            Marker::Ext32 => {
+                let length = reader.read_data_u32()? as usize;
+                read_message_pack_ext(reader, length)?
+            }
+
+            Marker::FixArray(length) => return Ok(Some(self.start_list(length as usize))),
+
+            Marker::Array16 => {
+                let length = reader.read_data_u16()? as usize;
+                return Ok(Some(self.start_list(length)));
+            }
+
+            Marker::Array32 => {
+                let length = reader.read_data_u32()? as usize;
+                return Ok(Some(self.start_list(length)));
+            }
+
+            Marker::FixMap(length) => return Ok(Some(self.start_map(length as usize))),
+
+            Marker::Map16 => {
+                let length = reader.read_data_u16()? as usize;
+                return Ok(Some(self.start_map(length)));
+            }
+
+            Marker::Map32 => {
+                let length = reader.read_data_u32()? as usize;
+                return Ok(Some(self.start_map(length)));
+            }
+        };
+
+        Ok(Some(self.nesting.scalar(variant)))
+    }
 
-        Marker::FixArray(length) => read_message_pack_array(reader, value_builder, length as usize)?,
+    fn start_list(&mut self, length: usize) -> Event<AnnotatedT> {
+        trace!("array length: {}", length);
+        self.remaining.push(length);
+        self.nesting.start_list(None, None)
+    }
 
-        Marker::Array16 => {
-            let length = read_u16(reader)? as usize;
-            read_message_pack_array(reader, value_builder, length)?;
-        }
+    fn start_map(&mut self, length: usize) -> Event<AnnotatedT> {
+        trace!("map length: {}", length);
 
-        Marker::Array32 => {
-            let length = read_u32(reader)? as usize;
-            read_message_pack_array(reader, value_builder, length)?;
-        }
+        // Keys and values are counted separately
+        self.remaining.push(length * 2);
+        self.nesting.start_map(None, None)
+    }
+}
 
-        Marker::FixMap(length) => read_message_pack_map(reader, value_builder, length as usize)?,
+impl<ReadT, AnnotatedT> Iterator for MessagePackEvents<ReadT, AnnotatedT>
+where
+    ReadT: io::Read,
+    AnnotatedT: Annotated + Default,
+{
+    type Item = Result<Event<AnnotatedT>, ParseError>;
 
-        Marker::Map16 => {
-            let length = read_u16(reader)? as usize;
-            read_message_pack_map(reader, value_builder, length)?;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
         }
 
-        Marker::Map32 => {
-            let length = read_u32(reader)? as usize;
-            read_message_pack_map(reader, value_builder, length)?;
+        let event = self.next_event().transpose();
+        if matches!(event, None | Some(Err(_))) {
+            self.finished = true;
         }
+        event
     }
-
-    Ok(())
 }
 
-fn read_message_pack_string<ReadT, AnnotatedT>(
-    reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    length: usize,
-) -> Result<(), ParseError>
+// Utils
+
+fn read_message_pack_string<ReadT>(reader: &mut ReadT, length: usize) -> Result<String, ParseError>
 where
     ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
 {
     trace!("string length: {}", length);
-    let mut buffer = vec![0; length];
-    reader.read_exact_buf(&mut buffer)?;
-    let string = String::from_utf8(buffer)?;
-    Ok(value_builder.add(Text::from(string), None))
+    Ok(String::from_utf8(read_message_pack_bytes(reader, length)?)?)
 }
 
-fn read_message_pack_bytes<ReadT, AnnotatedT>(
-    reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    length: usize,
-) -> Result<(), ParseError>
+fn read_message_pack_bytes<ReadT>(reader: &mut ReadT, length: usize) -> Result<Vec<u8>, ParseError>
 where
     ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
 {
     trace!("bytes length: {}", length);
     let mut buffer = vec![0; length];
     reader.read_exact_buf(&mut buffer)?;
-    Ok(value_builder.add(Blob::from(buffer), None))
+    Ok(buffer)
 }
 
 fn read_message_pack_ext<ReadT, AnnotatedT>(
     reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
     length: usize,
-    label: i64,
-) -> Result<(), ParseError>
+) -> Result<Variant<AnnotatedT>, ParseError>
 where
     ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
+    AnnotatedT: Annotated + Default,
 {
+    let label = reader.read_data_i8()? as i64;
     trace!("ext type: {}", label);
-    let mut buffer = vec![0; length];
-    reader.read_exact_buf(&mut buffer)?;
-    Ok(value_builder.add(Blob::from(buffer).with_label(Some(Label::Integer(label))), None))
-}
-
-fn read_message_pack_array<ReadT, AnnotatedT>(
-    reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    length: usize,
-) -> Result<(), ParseError>
-where
-    ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
-{
-    trace!("array length: {}", length);
-    value_builder.start_list(None);
-    for _ in 0..length {
-        read_next_message_pack(reader, value_builder)?;
-    }
-    value_builder.end_container();
-    Ok(())
-}
-
-fn read_message_pack_map<ReadT, AnnotatedT>(
-    reader: &mut ReadT,
-    value_builder: &mut VariantBuilder<AnnotatedT>,
-    length: usize,
-) -> Result<(), ParseError>
-where
-    ReadT: io::Read,
-    AnnotatedT: Annotated + Clone + Default,
-{
-    trace!("map length: {}", length);
-    value_builder.start_map(None);
-    for _ in 0..length {
-        read_next_message_pack(reader, value_builder)?;
-        read_next_message_pack(reader, value_builder)?;
-    }
-    value_builder.end_container();
-    Ok(())
+    Ok(Blob::from(read_message_pack_bytes(reader, length)?).with_label(Some(Label::Integer(label))).into())
 }
 
 impl From<MarkerReadError> for ParseError {
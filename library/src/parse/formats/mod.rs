@@ -4,7 +4,25 @@ mod cbor;
 mod json;
 #[cfg(feature = "messagepack")]
 mod message_pack;
+#[cfg(feature = "simd-json")]
+mod simd_json;
 #[cfg(feature = "xml")]
 mod xml;
 #[cfg(feature = "yaml")]
 mod yaml;
+
+#[cfg(feature = "cbor")]
+#[allow(unused_imports)]
+pub use cbor::*;
+
+#[cfg(feature = "json")]
+#[allow(unused_imports)]
+pub use json::*;
+
+#[cfg(feature = "messagepack")]
+#[allow(unused_imports)]
+pub use message_pack::*;
+
+#[cfg(feature = "yaml")]
+#[allow(unused_imports)]
+pub use yaml::*;
@@ -0,0 +1,156 @@
+use super::super::{
+    super::{
+        annotate::*,
+        hints::*,
+        normal::{Variant, *},
+    },
+    builder::*,
+    *,
+};
+
+use {
+    kutil::std::immutable::*,
+    simd_json::{Node, StaticNode},
+    std::io,
+    struson::reader::*,
+};
+
+impl Parser {
+    /// Parses JSON into a [Variant] using SIMD acceleration.
+    ///
+    /// The input is read fully into memory before parsing. Spans are not supported. The nesting
+    /// depth is limited to the same maximum as without SIMD acceleration.
+    ///
+    /// Is affected by [Parser::try_integers](super::super::Parser)
+    /// and [Parser::try_unsigned_integers](super::super::Parser).
+    pub fn parse_simd_json_with_hints<ReadT, AnnotatedT>(
+        &self,
+        reader: &mut ReadT,
+        hints: Option<&Hints>,
+    ) -> Result<Variant<AnnotatedT>, ParseError>
+    where
+        ReadT: io::Read,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let mut buffer = Vec::default();
+        reader.read_to_end(&mut buffer)?;
+
+        let tape = simd_json::to_tape(&mut buffer)?;
+        let mut nodes = tape.0.into_iter();
+
+        let mut value_builder = self.variant_builder();
+        read_simd_json(&mut nodes, &mut value_builder, hints, self.try_integers, self.try_unsigned_integers)?;
+        Ok(value_builder.finalize())
+    }
+}
+
+// Utils
+
+// Iterative (rather than recursive) so that deeply nested input cannot overflow the stack
+fn read_simd_json<'input, NodesT, AnnotatedT>(
+    nodes: &mut NodesT,
+    value_builder: &mut VariantBuilder<AnnotatedT>,
+    hints: Option<&Hints>,
+    try_integers: bool,
+    try_unsigned_integers: bool,
+) -> Result<(), ParseError>
+where
+    NodesT: Iterator<Item = Node<'input>>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    // Same limit as Struson's
+    let max_nesting_depth = ReaderSettings::default().max_nesting_depth;
+
+    // Remaining nodes (for objects it's keys and values) and whether it's an object
+    let mut container_stack: Vec<(usize, bool)> = Default::default();
+
+    loop {
+        let node = nodes.next().ok_or_else(|| ParseError::Malformed("unexpected end of JSON tape".into()))?;
+        tracing::trace!("{:?}", node);
+        match node {
+            Node::Static(StaticNode::Null) => {
+                value_builder.add(Null::default(), None);
+            }
+
+            Node::Static(StaticNode::Bool(boolean)) => {
+                value_builder.add(Boolean::from(boolean), None);
+            }
+
+            // Note that we follow the same semantics as the Struson implementation, which parses the
+            // number's text first as u64 (if allowed), then as i64 (if allowed), and finally as f64
+            Node::Static(StaticNode::I64(integer)) => {
+                if try_unsigned_integers && integer >= 0 {
+                    value_builder.add(UnsignedInteger::from(integer as u64), None);
+                } else if try_integers {
+                    value_builder.add(Integer::from(integer), None);
+                } else {
+                    value_builder.add(Float::from(integer as f64), None);
+                }
+            }
+
+            Node::Static(StaticNode::U64(unsigned_integer)) => {
+                if try_unsigned_integers {
+                    value_builder.add(UnsignedInteger::from(unsigned_integer), None);
+                } else if try_integers && let Ok(integer) = i64::try_from(unsigned_integer) {
+                    value_builder.add(Integer::from(integer), None);
+                } else {
+                    value_builder.add(Float::from(unsigned_integer as f64), None);
+                }
+            }
+
+            Node::Static(StaticNode::F64(float)) => {
+                value_builder.add(Float::from(float), None);
+            }
+
+            Node::String(string) => {
+                value_builder.add(Text::from(ByteString::from(string)), None);
+            }
+
+            Node::Array { len, .. } | Node::Object { len, .. } => {
+                if let Some(max_nesting_depth) = max_nesting_depth
+                    && (container_stack.len() >= max_nesting_depth as usize)
+                {
+                    let location = JsonReaderPosition { path: None, line_pos: None, data_pos: None };
+                    return Err(ReaderError::MaxNestingDepthExceeded { max_nesting_depth, location }.into());
+                }
+
+                let is_object = matches!(node, Node::Object { .. });
+                if is_object {
+                    value_builder.start_map(None);
+                    container_stack.push((len * 2, true));
+                } else {
+                    value_builder.start_list(None);
+                    container_stack.push((len, false));
+                }
+
+                // Empty containers are closed below
+                if len != 0 {
+                    continue;
+                }
+            }
+        }
+
+        // Close containers and move to the next node
+        loop {
+            match container_stack.last_mut() {
+                Some((0, is_object)) => {
+                    if *is_object {
+                        value_builder.end_container_with_hints(hints)?;
+                    } else {
+                        value_builder.end_container();
+                    }
+                    container_stack.pop();
+                }
+
+                Some((remaining, _)) => {
+                    *remaining -= 1;
+                    if *remaining != 0 {
+                        break;
+                    }
+                }
+
+                None => return Ok(()),
+            }
+        }
+    }
+}
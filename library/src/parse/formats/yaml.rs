@@ -3,15 +3,20 @@ use super::super::{
         annotate::{Span, *},
         normal::{Variant, *},
     },
-    Parser,
-    builder::*,
-    *,
+    Parser, *,
 };
 
 use {
-    kutil::{io::reader::*, std::immutable::*},
-    saphyr_parser::{Event, Parser as SaphyrParser, Span as SaphyrSpan, *},
-    std::{borrow::*, io},
+    kutil::std::{collections::*, immutable::*},
+    saphyr_parser::{
+        BufferedInput, Event as SaphyrEvent, Parser as SaphyrParser, ScalarStyle, ScanError, Span as SaphyrSpan,
+    },
+    std::{
+        borrow::*,
+        collections::*,
+        io::{self, Read},
+        str,
+    },
 };
 
 impl Parser {
@@ -19,82 +24,214 @@ impl Parser {
     ///
     /// Is affected by [Parser::try_unsigned_integers](super::super::Parser),
     /// [Parser::allow_legacy_words](super::super::Parser),
-    /// and [Parser::allow_legacy_types](super::super::Parser).
+    /// [Parser::allow_legacy_types](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
     pub fn parse_yaml<ReadT, AnnotatedT>(&self, reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
     where
         ReadT: io::Read,
         AnnotatedT: Annotated + Clone + Default,
+    {
+        let mut value_builder = self.variant_builder();
+        value_builder.add_events(self.yaml_events(reader), None)?;
+        Ok(value_builder.finalize())
+    }
+
+    /// Parses YAML into [Event]s.
+    ///
+    /// Only the first document is parsed. Aliases are replayed as the events of their anchored
+    /// nodes.
+    ///
+    /// Is affected by [Parser::try_unsigned_integers](super::super::Parser),
+    /// [Parser::allow_legacy_words](super::super::Parser),
+    /// [Parser::allow_legacy_types](super::super::Parser),
+    /// and [Parser::lossless_numbers](super::super::Parser).
+    pub fn yaml_events<'input, ReadT, AnnotatedT>(&self, reader: ReadT) -> YamlEvents<'input, ReadT, AnnotatedT>
+    where
+        ReadT: 'input + io::Read,
+        AnnotatedT: Annotated,
     {
         // https://github.com/saphyr-rs/saphyr/issues/17
         // https://github.com/saphyr-rs/saphyr/issues/16
 
-        let mut receiver = YamlReceiver::new(
-            self.source.clone(),
+        YamlEvents::new(
+            reader,
             self.try_unsigned_integers,
             self.allow_legacy_words,
             self.allow_legacy_types,
-        );
-        SaphyrParser::new_from_iter(io::BufReader::new(reader).chars()).load(&mut receiver, false)?;
-        receiver.value()
+            self.lossless_numbers,
+        )
     }
 }
 
 const YAML_TAG_PREFIX: &'static str = "tag:yaml.org,2002:";
 
 //
-// YamlReceiver
+// YamlEvents
 //
 
-/// Saphyr receiver for normal types.
-struct YamlReceiver<AnnotatedT> {
+/// YAML [Event] iterator.
+pub struct YamlEvents<'input, ReadT, AnnotatedT>
+where
+    ReadT: 'input + io::Read,
+{
+    parser: SaphyrParser<'input, BufferedInput<ReadChars<ReadT>>>,
+
     try_unsigned_integers: bool,
     allow_legacy_words: bool,
     allow_legacy_types: bool,
+    lossless_numbers: bool,
 
-    value_builder: VariantBuilder<AnnotatedT>,
+    nesting: EventNesting,
     last_span: Option<SaphyrSpan>,
-    error: Option<ParseError>,
+    finished: bool,
+
+    // Recorded events per anchor ID
+    anchors: FastHashMap<usize, Vec<Event<AnnotatedT>>>,
+
+    // Anchor ID, nesting depth, and recorded events
+    recordings: Vec<(usize, usize, Vec<Event<AnnotatedT>>)>,
+
+    // Events of an alias
+    replay: VecDeque<Event<AnnotatedT>>,
 
     span: fn(&SaphyrSpan) -> Option<Span>,
     collection_span: fn(&Self, &SaphyrSpan) -> Option<Span>,
 }
 
-impl<AnnotatedT> YamlReceiver<AnnotatedT>
+impl<'input, ReadT, AnnotatedT> YamlEvents<'input, ReadT, AnnotatedT>
 where
+    ReadT: 'input + io::Read,
     AnnotatedT: Annotated,
 {
     /// Constructor.
-    fn new(
-        source: Option<ByteString>,
-        allow_unsigned_integers: bool,
+    pub fn new(
+        reader: ReadT,
+        try_unsigned_integers: bool,
         allow_legacy_words: bool,
         allow_legacy_types: bool,
+        lossless_numbers: bool,
     ) -> Self {
         Self {
-            try_unsigned_integers: allow_unsigned_integers,
+            parser: SaphyrParser::new_from_iter(ReadChars::new(reader)),
+            try_unsigned_integers,
             allow_legacy_words,
             allow_legacy_types,
-            value_builder: VariantBuilder::new(source.clone()),
+            lossless_numbers,
+            nesting: Default::default(),
             last_span: None,
-            error: None,
+            finished: false,
+            anchors: Default::default(),
+            recordings: Default::default(),
+            replay: Default::default(),
             span: if AnnotatedT::can_have_annotations() { |span| Some(span.into()) } else { |_| None },
             collection_span: if AnnotatedT::can_have_annotations() {
-                |yaml_receiver, span| Some(yaml_receiver.last_span.as_ref().unwrap_or_else(|| span).into())
+                |yaml_events, span| Some(yaml_events.last_span.as_ref().unwrap_or(span).into())
             } else {
                 |_, _| None
             },
         }
     }
 
-    /// Returns the final built value.
-    fn value(&mut self) -> Result<Variant<AnnotatedT>, ParseError>
+    fn next_event(&mut self) -> Result<Option<Event<AnnotatedT>>, ParseError>
     where
-        AnnotatedT: Default,
+        AnnotatedT: Clone + Default,
     {
-        match self.error.take() {
-            None => Ok(self.value_builder.finalize()),
-            Some(error) => Err(error),
+        loop {
+            if let Some(event) = self.replay.pop_front() {
+                return Ok(Some(self.emit(event, None)));
+            }
+
+            let Some(next) = self.parser.next() else {
+                return Ok(None);
+            };
+
+            let (event, span) = next?;
+            tracing::trace!("{:?} {:?}", event, span);
+
+            let event = match event {
+                SaphyrEvent::SequenceStart(anchor_id, _tag) => Some(self.emit(
+                    Event::StartList { span: (self.collection_span)(self, &span), label: None },
+                    anchor(anchor_id),
+                )),
+
+                SaphyrEvent::MappingStart(anchor_id, _tag) => Some(self.emit(
+                    Event::StartMap { span: (self.collection_span)(self, &span), label: None },
+                    anchor(anchor_id),
+                )),
+
+                SaphyrEvent::SequenceEnd | SaphyrEvent::MappingEnd => Some(self.emit(Event::End, None)),
+
+                SaphyrEvent::Scalar(value, style, anchor_id, tag) => {
+                    let variant = if style != ScalarStyle::Plain {
+                        // All non-plain scalars are strings
+                        Text::from(value).with_span((self.span)(&span)).into()
+                    } else if let Some(tag) = tag {
+                        // Tagged plain scalar
+                        self.parse_yaml_tagged_scalar(value, &tag.handle, &tag.suffix, &span)?
+                    } else {
+                        // Plain and untagged scalar, so determine type heuristically
+                        self.parse_yaml_bare_scalar(value, &span)?
+                    };
+
+                    Some(self.emit(Event::Scalar(variant), anchor(anchor_id)))
+                }
+
+                SaphyrEvent::Alias(anchor_id) => match self.anchors.get(&anchor_id) {
+                    Some(events) => {
+                        self.replay.extend(events.iter().cloned());
+                        None
+                    }
+
+                    None => return Err(ParseError::ReferenceNotFound(anchor_id)),
+                },
+
+                // We support only the first document
+                SaphyrEvent::DocumentEnd | SaphyrEvent::StreamEnd => return Ok(None),
+
+                _ => None,
+            };
+
+            if AnnotatedT::can_have_annotations() {
+                self.last_span = Some(span);
+            }
+
+            if event.is_some() {
+                return Ok(event);
+            }
+        }
+    }
+
+    // Normalizes the event and handles anchor recording
+    fn emit(&mut self, event: Event<AnnotatedT>, anchor_id: Option<usize>) -> Event<AnnotatedT>
+    where
+        AnnotatedT: Clone,
+    {
+        let is_start = event.is_start();
+        let event = self.nesting.normalize(event);
+
+        if let Some(anchor_id) = anchor_id {
+            if is_start {
+                // Record until the container ends
+                self.recordings.push((anchor_id, self.nesting.depth(), Default::default()));
+            } else {
+                // Note that we are allowing overriding of anchors
+                self.anchors.insert(anchor_id, vec![event.clone()]);
+            }
+        }
+
+        for (_, _, events) in &mut self.recordings {
+            events.push(event.clone());
         }
+
+        while let Some((_, depth, _)) = self.recordings.last()
+            && self.nesting.depth() < *depth
+        {
+            if let Some((anchor_id, _, events)) = self.recordings.pop() {
+                self.anchors.insert(anchor_id, events);
+            }
+        }
+
+        event
     }
 
     fn parse_yaml_tagged_scalar(
@@ -128,6 +265,10 @@ where
 
                 // JSON schema, https://yaml.org/spec/1.2.2/#10213-integer
                 "int" => {
+                    if let Some(number) = self.try_parse_yaml_lossless_number(&value, true) {
+                        return Ok(number.with_span((self.span)(span)).into());
+                    }
+
                     return Ok(Integer::from(Self::parse_yaml_integer(&value, &span)?)
                         .with_span((self.span)(span))
                         .into());
@@ -135,6 +276,10 @@ where
 
                 // JSON schema, https://yaml.org/spec/1.2.2/#10214-floating-point
                 "float" => {
+                    if let Some(number) = self.try_parse_yaml_lossless_number(&value, false) {
+                        return Ok(number.with_span((self.span)(span)).into());
+                    }
+
                     return Ok(Float::from(Self::parse_yaml_float(&value, &span)?).with_span((self.span)(span)).into());
                 }
 
@@ -174,6 +319,8 @@ where
             Ok(UnsignedInteger::from(unsigned_integer).with_span((self.span)(span)).into())
         } else if let Ok(integer) = Self::parse_yaml_integer(&value, &span) {
             Ok(Integer::from(integer).with_span((self.span)(span)).into())
+        } else if let Some(number) = self.try_parse_yaml_lossless_number(&value, false) {
+            Ok(number.with_span((self.span)(span)).into())
         } else if let Ok(float) = Self::parse_yaml_float(&value, span) {
             Ok(Float::from(float).with_span((self.span)(span)).into())
         } else {
@@ -247,6 +394,22 @@ where
         value.parse().ok()
     }
 
+    fn try_parse_yaml_lossless_number(&self, value: &str, integer: bool) -> Option<Number<AnnotatedT>>
+    where
+        AnnotatedT: Default,
+    {
+        if self.lossless_numbers
+            && let Some((_, _, exponent)) = decimal_parts(value)
+            && !(integer && (exponent < 0))
+            && value.parse::<i64>().is_err()
+            && !is_lossless_f64(value)
+        {
+            Some(Number::from(ByteString::from(value)))
+        } else {
+            None
+        }
+    }
+
     fn parse_yaml_float(value: &str, span: &SaphyrSpan) -> Result<f64, ParseError> {
         // Core schema, https://yaml.org/spec/1.2.2/#1032-tag-resolution
         // Section 10.2.1.4 in https://yaml.org/spec/1.2.2/#1021-tags
@@ -259,72 +422,67 @@ where
     }
 }
 
-impl<'own, 'input, AnnotatedT> SpannedEventReceiver<'input> for YamlReceiver<AnnotatedT>
+impl<'input, ReadT, AnnotatedT> Iterator for YamlEvents<'input, ReadT, AnnotatedT>
 where
+    ReadT: 'input + io::Read,
     AnnotatedT: Annotated + Clone + Default,
 {
-    fn on_event(&mut self, event: Event, span: SaphyrSpan) {
-        tracing::trace!("{:?} {:?}", event, span);
+    type Item = Result<Event<AnnotatedT>, ParseError>;
 
-        match event {
-            Event::SequenceStart(anchor_id, _tag) => {
-                self.value_builder.start_list_with_span((self.collection_span)(self, &span), anchor(anchor_id));
-            }
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
 
-            Event::SequenceEnd => {
-                self.value_builder.end_container();
-            }
+        let event = self.next_event().transpose();
+        if matches!(event, None | Some(Err(_))) {
+            self.finished = true;
+        }
+        event
+    }
+}
 
-            Event::MappingStart(anchor_id, _tag) => {
-                self.value_builder.start_map_with_span((self.collection_span)(self, &span), anchor(anchor_id));
-            }
+//
+// ReadChars
+//
 
-            Event::MappingEnd => {
-                self.value_builder.end_container();
-            }
+// Owning char iterator for readers; ends iteration if there is an error
+struct ReadChars<ReadT> {
+    reader: io::BufReader<ReadT>,
+}
 
-            Event::Scalar(value, style, anchor_id, tag) => {
-                if style != ScalarStyle::Plain {
-                    // All non-plain scalars are strings
-                    self.value_builder.add(Text::from(value).with_span((self.span)(&span)), anchor(anchor_id));
-                } else {
-                    // Tagged plain scalar?
-                    if let Some(tag) = tag {
-                        match self.parse_yaml_tagged_scalar(value, &tag.handle, &tag.suffix, &span) {
-                            Ok(value) => self.value_builder.add(value, anchor(anchor_id)),
-                            Err(error) => {
-                                // See: https://github.com/saphyr-rs/saphyr/issues/20
-                                self.error = Some(error.into());
-                                self.value_builder.add(Variant::Undefined, anchor(anchor_id));
-                            }
-                        }
-                    } else {
-                        // Plain and untagged scalar, so determine type heuristically
-                        match self.parse_yaml_bare_scalar(value, &span) {
-                            Ok(value) => self.value_builder.add(value, anchor(anchor_id)),
-                            Err(error) => {
-                                // See: https://github.com/saphyr-rs/saphyr/issues/20
-                                self.error = Some(error.into());
-                                self.value_builder.add(Variant::Undefined, anchor(anchor_id));
-                            }
-                        }
-                    }
-                }
-            }
+impl<ReadT> ReadChars<ReadT>
+where
+    ReadT: io::Read,
+{
+    fn new(reader: ReadT) -> Self {
+        Self { reader: io::BufReader::new(reader) }
+    }
+}
 
-            Event::Alias(anchor_id) => {
-                if let Err(error) = self.value_builder.add_referenced(anchor_id) {
-                    self.error = Some(error);
-                    self.value_builder.add(Variant::Undefined, None);
-                }
-            }
+impl<ReadT> Iterator for ReadChars<ReadT>
+where
+    ReadT: io::Read,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buffer = [0; 4];
+        self.reader.read_exact(&mut buffer[..1]).ok()?;
+
+        let length = match buffer[0] {
+            0x00..0x80 => 1,
+            0xc0..0xe0 => 2,
+            0xe0..0xf0 => 3,
+            0xf0..0xf8 => 4,
+            _ => return None,
+        };
 
-            _ => {}
+        if length > 1 {
+            self.reader.read_exact(&mut buffer[1..length]).ok()?;
         }
 
-        if AnnotatedT::can_have_annotations() {
-            self.last_span = Some(span);
-        }
+        str::from_utf8(&buffer[..length]).ok()?.chars().next()
     }
 }
 
@@ -1,4 +1,7 @@
+#[cfg(feature = "async")]
+mod asynchronous;
 mod error;
+mod event;
 mod formats;
 mod parser;
 
@@ -6,4 +9,4 @@ mod parser;
 pub mod builder;
 
 #[allow(unused_imports)]
-pub use {error::*, formats::*, parser::*};
+pub use {error::*, event::*, formats::*, parser::*};
@@ -1,15 +1,21 @@
 use super::{
     super::{annotate::*, format::*, normal::*},
+    builder::*,
     error::*,
+    event::*,
 };
 
-use {kutil::std::immutable::*, std::io};
+use {
+    kutil::std::immutable::*,
+    std::{io, sync::*},
+};
 
 //
 // Parser
 //
 
 /// Parses various formats into [Variant].
+#[derive(Clone)]
 pub struct Parser {
     /// Format.
     pub format: Format,
@@ -17,6 +23,16 @@ pub struct Parser {
     /// Source.
     pub source: Option<ByteString>,
 
+    /// Source ID. Set automatically when registering into [Parser::source_registry].
+    pub source_id: Option<SourceId>,
+
+    /// Source registry.
+    ///
+    /// When set, [Parser::parse_reader] (and the methods that call it) reads the entire input
+    /// into memory and registers it before parsing. The [SourceId] is added to the
+    /// [Annotations] of each variant.
+    pub source_registry: Option<SharedSourceRegistry>,
+
     /// Try to parse numbers as integers (for JSON only). Defaults to false.
     pub try_integers: bool,
 
@@ -31,6 +47,15 @@ pub struct Parser {
 
     /// Decode Base64 (for CBOR and MessagePack only). Defaults to false.
     pub base64: bool,
+
+    /// Keep numbers that cannot be represented exactly as [Number] (for YAML, JSON, and CBOR).
+    /// Defaults to false.
+    pub lossless_numbers: bool,
+
+    /// Record the [PathRepresentation](super::super::path::PathRepresentation) of each variant in
+    /// its [Annotations] while parsing, instead of in a final pass over the parsed variant.
+    /// Defaults to false.
+    pub record_paths: bool,
 }
 
 impl Parser {
@@ -39,11 +64,15 @@ impl Parser {
         Self {
             format,
             source: None,
+            source_id: None,
+            source_registry: None,
             try_integers: false,
             try_unsigned_integers: false,
             allow_legacy_words: false,
             allow_legacy_types: false,
             base64: false,
+            lossless_numbers: false,
+            record_paths: false,
         }
     }
 
@@ -59,6 +88,18 @@ impl Parser {
         self
     }
 
+    /// Set source ID.
+    pub fn with_source_id(mut self, source_id: SourceId) -> Self {
+        self.source_id = Some(source_id);
+        self
+    }
+
+    /// Set source registry.
+    pub fn with_source_registry(mut self, source_registry: SharedSourceRegistry) -> Self {
+        self.source_registry = Some(source_registry);
+        self
+    }
+
     /// Set whether to try to parse numbers as integers (for JSON only).
     ///
     /// False sets [Parser::try_unsigned_integers] to false.
@@ -99,12 +140,43 @@ impl Parser {
         self
     }
 
+    /// Set whether to keep numbers that cannot be represented exactly as [Number] (for YAML,
+    /// JSON, and CBOR).
+    pub fn with_lossless_numbers(mut self, lossless_numbers: bool) -> Self {
+        self.lossless_numbers = lossless_numbers;
+        self
+    }
+
+    /// Set whether to record the [PathRepresentation](super::super::path::PathRepresentation) of
+    /// each variant in its [Annotations] while parsing, instead of in a final pass over the parsed
+    /// variant.
+    pub fn with_record_paths(mut self, record_paths: bool) -> Self {
+        self.record_paths = record_paths;
+        self
+    }
+
     /// Parses into a [Variant] according to [Parser::format].
     pub fn parse_reader<ReadT, AnnotatedT>(&self, reader: &mut ReadT) -> Result<Variant<AnnotatedT>, ParseError>
     where
         ReadT: io::Read,
         AnnotatedT: Annotated + Clone + Default,
     {
+        if let Some(source_registry) = &self.source_registry {
+            let mut content = Vec::default();
+            reader.read_to_end(&mut content)?;
+            let content = Bytes::from(content);
+
+            let source_id = source_registry.lock().unwrap_or_else(PoisonError::into_inner).register(
+                self.source.clone(),
+                Some(self.format.media_type().into()),
+                content.clone(),
+            );
+
+            let mut parser = self.clone();
+            parser.source_registry = None;
+            return parser.with_source_id(source_id).parse_reader(&mut content.as_ref());
+        }
+
         match &self.format {
             #[cfg(feature = "cbor")]
             Format::CBOR => self.parse_cbor(reader),
@@ -135,6 +207,53 @@ impl Parser {
         }
     }
 
+    /// Parses into [Event]s according to [Parser::format].
+    ///
+    /// Note that XJSON hints are not processed. See
+    /// [VariantBuilder::add_events](super::builder::VariantBuilder::add_events).
+    pub fn events<'own, ReadT, AnnotatedT>(
+        &self,
+        reader: &'own mut ReadT,
+    ) -> Result<Events<'own, AnnotatedT>, ParseError>
+    where
+        ReadT: io::Read,
+        AnnotatedT: 'own + Annotated + Clone + Default,
+    {
+        match &self.format {
+            #[cfg(feature = "cbor")]
+            Format::CBOR => Ok(if self.base64 {
+                Box::new(self.cbor_events(Self::base64_reader(reader)))
+            } else {
+                Box::new(self.cbor_events(reader))
+            }),
+
+            #[cfg(feature = "messagepack")]
+            Format::MessagePack => Ok(if self.base64 {
+                Box::new(self.message_pack_events(Self::base64_reader(reader)))
+            } else {
+                Box::new(self.message_pack_events(reader))
+            }),
+
+            #[cfg(feature = "yaml")]
+            Format::YAML => Ok(Box::new(self.yaml_events(reader))),
+
+            #[cfg(feature = "json")]
+            Format::JSON | Format::XJSON => Ok(Box::new(self.json_events(reader))),
+
+            #[cfg(feature = "xml")]
+            Format::XML => Err(ParseError::UnsupportedFormat(self.format)),
+
+            #[cfg(not(all(
+                feature = "cbor",
+                feature = "messagepack",
+                feature = "yaml",
+                feature = "json",
+                feature = "xml",
+            )))]
+            _ => Err(ParseError::UnsupportedFormat(self.format.clone())),
+        }
+    }
+
     /// Parses into a [Variant] according to [Parser::format].
     pub fn parse_string<AnnotatedT>(&self, string: &str) -> Result<Variant<AnnotatedT>, ParseError>
     where
@@ -143,6 +262,12 @@ impl Parser {
         self.parse_reader(&mut string.as_bytes())
     }
 
+    // Builder for format parsers
+    #[allow(dead_code)]
+    pub(crate) fn variant_builder<AnnotatedT>(&self) -> VariantBuilder<AnnotatedT> {
+        VariantBuilder::new(self.source.clone()).with_source_id(self.source_id).with_record_paths(self.record_paths)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn base64_reader<ReadT>(
         reader: &mut ReadT,
@@ -0,0 +1,82 @@
+use super::{
+    super::{annotate::*, diff::*, normal::*},
+    errors::*,
+    json_patch::*,
+    operation::*,
+};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Apply a [JsonPatch].
+    ///
+    /// The operations are applied in order. The patch is atomic: if any operation fails then this
+    /// variant is left unchanged. (It is applied to a clone, which is O(1) with the
+    /// "copy-on-write" feature.)
+    ///
+    /// See [JsonPointer](super::super::path::JsonPointer) for how map keys are addressed. New map keys are always [Text].
+    ///
+    /// The "test" operation compares numbers by value regardless of type, as required by the RFC.
+    ///
+    /// Errors have the [Annotations] of the failed operation.
+    pub fn apply_patch(&mut self, patch: &JsonPatch<AnnotatedT>) -> Result<(), PatchError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let mut patched = self.clone();
+
+        for operation in &patch.operations {
+            patched.apply_patch_operation(&operation.kind).map_err(|reason| {
+                PatchOperationError::new(operation.kind.name().into(), operation.kind.path().to_string(), reason)
+                    .with_annotations_from(operation)
+            })?;
+        }
+
+        *self = patched;
+        Ok(())
+    }
+
+    fn apply_patch_operation(&mut self, kind: &JsonPatchOperationKind<AnnotatedT>) -> Result<(), String>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        match kind {
+            JsonPatchOperationKind::Add { path, value } => {
+                self.pointer_insert(path, value.clone()).map_err(|error| error.to_string())?;
+            }
+
+            JsonPatchOperationKind::Remove { path } => {
+                self.pointer_remove(path).map_err(|error| error.to_string())?;
+            }
+
+            JsonPatchOperationKind::Replace { path, value } => {
+                *self.pointer_get_mut(path).ok_or("not found")? = value.clone();
+            }
+
+            JsonPatchOperationKind::Move { from, path } => {
+                if from == path {
+                    return Ok(());
+                }
+
+                if path.starts_with(from) {
+                    return Err(format!("cannot move {:?} into its own child", from.to_string()));
+                }
+
+                let value = self.pointer_remove(from).map_err(|error| format!("from: {}", error))?;
+                self.pointer_insert(path, value).map_err(|error| error.to_string())?;
+            }
+
+            JsonPatchOperationKind::Copy { from, path } => {
+                let value = self.pointer_get(from).ok_or("from: not found")?.clone();
+                self.pointer_insert(path, value).map_err(|error| error.to_string())?;
+            }
+
+            JsonPatchOperationKind::Test { path, value } => {
+                let current = self.pointer_get(path).ok_or("not found")?;
+                if !current.diff(value, &DiffOptions::default().with_semantic_numbers(true)).is_empty() {
+                    return Err(format!("is {}, expected {}", current, value));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
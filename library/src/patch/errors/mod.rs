@@ -0,0 +1,5 @@
+mod operation;
+mod patch;
+
+#[allow(unused_imports)]
+pub use {operation::*, patch::*};
@@ -0,0 +1,69 @@
+use super::super::super::annotate::*;
+
+use {
+    kutil::cli::depict::*,
+    std::{fmt, io},
+    thiserror::*,
+};
+
+//
+// PatchOperationError
+//
+
+/// Failed [JsonPatchOperation](super::super::JsonPatchOperation).
+#[derive(Debug, Error)]
+pub struct PatchOperationError<AnnotatedT> {
+    /// Operation name.
+    pub operation: String,
+
+    /// JSON Pointer.
+    pub pointer: String,
+
+    /// Reason.
+    pub reason: String,
+
+    /// Annotated.
+    pub annotated: AnnotatedT,
+}
+
+impl<AnnotatedT> PatchOperationError<AnnotatedT> {
+    /// Constructor.
+    pub fn new(operation: String, pointer: String, reason: String) -> Self
+    where
+        AnnotatedT: Default,
+    {
+        Self { operation, pointer, reason, annotated: Default::default() }
+    }
+
+    /// Into different [Annotated] implementation.
+    pub fn into_annotated<NewAnnotationsT>(self) -> PatchOperationError<NewAnnotationsT>
+    where
+        AnnotatedT: Annotated,
+        NewAnnotationsT: Annotated + Default,
+    {
+        PatchOperationError::new(self.operation, self.pointer, self.reason).with_annotations_from(&self.annotated)
+    }
+}
+
+impl_annotated!(PatchOperationError);
+
+impl<AnnotatedT> Depict for PatchOperationError<AnnotatedT> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        write!(
+            writer,
+            "{} {}: {}",
+            context.theme.name(&self.operation),
+            context.theme.string(format!("{:?}", self.pointer)),
+            context.theme.error(&self.reason)
+        )
+    }
+}
+
+impl<AnnotatedT> fmt::Display for PatchOperationError<AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} {:?}: {}", self.operation, self.pointer, self.reason)
+    }
+}
@@ -0,0 +1,74 @@
+use super::{
+    super::super::{annotate::*, normal::*},
+    operation::*,
+};
+
+use {kutil::cli::depict::*, thiserror::*};
+
+//
+// PatchError
+//
+
+/// [JsonPatch](super::super::JsonPatch) error.
+#[derive(Debug, Depict, Error)]
+#[depict(variant = false)]
+pub enum PatchError<AnnotatedT> {
+    /// Incompatible value type.
+    #[error("incompatible value type: {0}")]
+    #[depict(as(depict))]
+    IncompatibleVariantType(#[from] IncompatibleVariantTypeError<AnnotatedT>),
+
+    /// Malformed.
+    #[error("malformed: {0}")]
+    #[depict(as(depict))]
+    Malformed(#[from] MalformedError<AnnotatedT>),
+
+    /// Operation.
+    #[error("operation: {0}")]
+    #[depict(as(depict))]
+    Operation(#[from] PatchOperationError<AnnotatedT>),
+}
+
+impl<AnnotatedT> PatchError<AnnotatedT> {
+    /// Into different [Annotated] implementation.
+    pub fn into_annotated<NewAnnotationsT>(self) -> PatchError<NewAnnotationsT>
+    where
+        AnnotatedT: Annotated,
+        NewAnnotationsT: Annotated + Default,
+    {
+        match self {
+            Self::IncompatibleVariantType(incompatible_variant_type) => {
+                incompatible_variant_type.into_annotated().into()
+            }
+            Self::Malformed(malformed) => malformed.into_annotated().into(),
+            Self::Operation(operation) => operation.into_annotated().into(),
+        }
+    }
+}
+
+// Delegated
+
+impl<AnnotatedT> Annotated for PatchError<AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn can_have_annotations() -> bool {
+        AnnotatedT::can_have_annotations()
+    }
+
+    fn annotations(&self) -> Option<&Annotations> {
+        match self {
+            Self::IncompatibleVariantType(incompatible_variant_type) => incompatible_variant_type.annotations(),
+            Self::Malformed(malformed) => malformed.annotations(),
+            Self::Operation(operation) => operation.annotations(),
+        }
+    }
+
+    fn annotations_mut(&mut self) -> Option<&mut Annotations> {
+        match self {
+            Self::IncompatibleVariantType(incompatible_variant_type) => incompatible_variant_type.annotations_mut(),
+            Self::Malformed(malformed) => malformed.annotations_mut(),
+            Self::Operation(operation) => operation.annotations_mut(),
+        }
+    }
+}
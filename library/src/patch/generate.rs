@@ -0,0 +1,53 @@
+use super::{
+    super::{annotate::*, diff::*, normal::*, path::*},
+    json_patch::*,
+    operation::*,
+};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Generate a [JsonPatch] that would turn this variant into the other variant.
+    ///
+    /// Based on [Variant::diff] with list items matched by index. Map keys are addressed by their
+    /// string representation.
+    ///
+    /// Operations have the [Annotations] of the new value (or of the old value for removals).
+    pub fn diff_patch(&self, other: &Self) -> JsonPatch<AnnotatedT>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let diff = self.diff(other, &Default::default());
+        let mut patch = JsonPatch::default();
+        let mut removals = Vec::default();
+
+        for change in diff {
+            let path = JsonPointer::from(change.path());
+
+            let operation = match change {
+                Change::Added { new, .. } => {
+                    JsonPatchOperation::new(JsonPatchOperationKind::Add { path, value: new.clone() })
+                        .with_annotations_from(new)
+                }
+
+                Change::Changed { new, .. } => {
+                    JsonPatchOperation::new(JsonPatchOperationKind::Replace { path, value: new.clone() })
+                        .with_annotations_from(new)
+                }
+
+                Change::Removed { old, .. } => {
+                    removals.push(
+                        JsonPatchOperation::new(JsonPatchOperationKind::Remove { path }).with_annotations_from(old),
+                    );
+                    continue;
+                }
+            };
+
+            // Consecutive removals are of trailing list items (or of map keys), so we reverse them
+            // in order to keep the indexes valid
+            patch.operations.extend(removals.drain(..).rev());
+            patch.operations.push(operation);
+        }
+
+        patch.operations.extend(removals.drain(..).rev());
+        patch
+    }
+}
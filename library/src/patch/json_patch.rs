@@ -0,0 +1,57 @@
+use super::{
+    super::{annotate::*, normal::*},
+    errors::*,
+    operation::*,
+};
+
+//
+// JsonPatch
+//
+
+/// JSON Patch (RFC 6902).
+///
+/// Though named for JSON, it can be parsed from and applied to [Variant]s of any format.
+///
+/// See [Variant::apply_patch] and [Variant::diff_patch].
+#[derive(Clone, Debug)]
+pub struct JsonPatch<AnnotatedT> {
+    /// Operations.
+    pub operations: Vec<JsonPatchOperation<AnnotatedT>>,
+}
+
+impl<AnnotatedT> Default for JsonPatch<AnnotatedT> {
+    fn default() -> Self {
+        Self { operations: Default::default() }
+    }
+}
+
+// Conversions
+
+impl<AnnotatedT> TryFrom<&Variant<AnnotatedT>> for JsonPatch<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = PatchError<AnnotatedT>;
+
+    /// Parses a list of operation maps.
+    ///
+    /// Errors have the [Annotations] of the offending [Variant].
+    fn try_from(variant: &Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        match variant {
+            Variant::List(list) => {
+                Ok(Self { operations: list.inner.iter().map(JsonPatchOperation::try_from).collect::<Result<_, _>>()? })
+            }
+
+            _ => Err(IncompatibleVariantTypeError::new_from(variant, &["List"]).into()),
+        }
+    }
+}
+
+impl<AnnotatedT> From<JsonPatch<AnnotatedT>> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn from(patch: JsonPatch<AnnotatedT>) -> Self {
+        patch.operations.into_iter().map(Variant::from).collect::<List<_>>().into()
+    }
+}
@@ -0,0 +1,8 @@
+mod apply;
+mod errors;
+mod generate;
+mod json_patch;
+mod operation;
+
+#[allow(unused_imports)]
+pub use {apply::*, errors::*, generate::*, json_patch::*, operation::*};
@@ -0,0 +1,205 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    errors::*,
+};
+
+//
+// JsonPatchOperation
+//
+
+/// JSON Patch (RFC 6902) operation.
+#[derive(Clone, Debug)]
+pub struct JsonPatchOperation<AnnotatedT> {
+    /// Kind.
+    pub kind: JsonPatchOperationKind<AnnotatedT>,
+
+    /// Annotated.
+    pub annotated: AnnotatedT,
+}
+
+impl<AnnotatedT> JsonPatchOperation<AnnotatedT> {
+    /// Constructor.
+    pub fn new(kind: JsonPatchOperationKind<AnnotatedT>) -> Self
+    where
+        AnnotatedT: Default,
+    {
+        Self { kind, annotated: Default::default() }
+    }
+}
+
+impl_annotated!(JsonPatchOperation);
+
+//
+// JsonPatchOperationKind
+//
+
+/// [JsonPatchOperation] kind.
+#[derive(Clone, Debug)]
+pub enum JsonPatchOperationKind<AnnotatedT> {
+    /// Add a value. Existing map keys are replaced and list items are shifted.
+    Add {
+        /// Path.
+        path: JsonPointer,
+
+        /// Value.
+        value: Variant<AnnotatedT>,
+    },
+
+    /// Remove a value.
+    Remove {
+        /// Path.
+        path: JsonPointer,
+    },
+
+    /// Replace an existing value.
+    Replace {
+        /// Path.
+        path: JsonPointer,
+
+        /// Value.
+        value: Variant<AnnotatedT>,
+    },
+
+    /// Move a value.
+    Move {
+        /// From path.
+        from: JsonPointer,
+
+        /// Path.
+        path: JsonPointer,
+    },
+
+    /// Copy a value.
+    Copy {
+        /// From path.
+        from: JsonPointer,
+
+        /// Path.
+        path: JsonPointer,
+    },
+
+    /// Test that a value equals this value.
+    Test {
+        /// Path.
+        path: JsonPointer,
+
+        /// Value.
+        value: Variant<AnnotatedT>,
+    },
+}
+
+impl<AnnotatedT> JsonPatchOperationKind<AnnotatedT> {
+    /// Operation name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add { .. } => "add",
+            Self::Remove { .. } => "remove",
+            Self::Replace { .. } => "replace",
+            Self::Move { .. } => "move",
+            Self::Copy { .. } => "copy",
+            Self::Test { .. } => "test",
+        }
+    }
+
+    /// Path.
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::Add { path, .. }
+            | Self::Remove { path }
+            | Self::Replace { path, .. }
+            | Self::Move { path, .. }
+            | Self::Copy { path, .. }
+            | Self::Test { path, .. } => path,
+        }
+    }
+}
+
+// Conversions
+
+impl<AnnotatedT> TryFrom<&Variant<AnnotatedT>> for JsonPatchOperation<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    type Error = PatchError<AnnotatedT>;
+
+    fn try_from(variant: &Variant<AnnotatedT>) -> Result<Self, Self::Error> {
+        let Variant::Map(map) = variant else {
+            return Err(IncompatibleVariantTypeError::new_from(variant, &["Map"]).into());
+        };
+
+        let member = |name: &str| map.into_get(String::from(name));
+
+        let pointer = |name: &str| -> Result<JsonPointer, PatchError<AnnotatedT>> {
+            match member(name) {
+                Some(Variant::Text(text)) => text.as_str().parse().map_err(|error: JsonPointerError| {
+                    MalformedError::new("JSON Pointer".into(), error.to_string()).with_annotations_from(text).into()
+                }),
+
+                Some(other) => Err(IncompatibleVariantTypeError::new_from(other, &["Text"]).into()),
+
+                None => Err(malformed(format!("missing {:?}", name), variant)),
+            }
+        };
+
+        let value = |name: &str| -> Result<Variant<AnnotatedT>, PatchError<AnnotatedT>> {
+            member(name).cloned().ok_or_else(|| malformed(format!("missing {:?}", name), variant))
+        };
+
+        let kind = match member("op") {
+            Some(Variant::Text(op)) => match op.as_str() {
+                "add" => JsonPatchOperationKind::Add { path: pointer("path")?, value: value("value")? },
+                "remove" => JsonPatchOperationKind::Remove { path: pointer("path")? },
+                "replace" => JsonPatchOperationKind::Replace { path: pointer("path")?, value: value("value")? },
+                "move" => JsonPatchOperationKind::Move { from: pointer("from")?, path: pointer("path")? },
+                "copy" => JsonPatchOperationKind::Copy { from: pointer("from")?, path: pointer("path")? },
+                "test" => JsonPatchOperationKind::Test { path: pointer("path")?, value: value("value")? },
+                _ => return Err(malformed(format!("unsupported \"op\": {:?}", op.as_str()), op)),
+            },
+
+            Some(other) => return Err(IncompatibleVariantTypeError::new_from(other, &["Text"]).into()),
+
+            None => return Err(malformed("missing \"op\"".into(), variant)),
+        };
+
+        Ok(Self::new(kind).with_annotations_from(variant))
+    }
+}
+
+impl<AnnotatedT> From<JsonPatchOperation<AnnotatedT>> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn from(operation: JsonPatchOperation<AnnotatedT>) -> Self {
+        let name = operation.kind.name();
+        let mut map = Map::default();
+        map.into_insert("op", name);
+
+        match operation.kind {
+            JsonPatchOperationKind::Add { path, value }
+            | JsonPatchOperationKind::Replace { path, value }
+            | JsonPatchOperationKind::Test { path, value } => {
+                map.into_insert("path", path.to_string());
+                map.into_insert("value", value);
+            }
+
+            JsonPatchOperationKind::Remove { path } => {
+                map.into_insert("path", path.to_string());
+            }
+
+            JsonPatchOperationKind::Move { from, path } | JsonPatchOperationKind::Copy { from, path } => {
+                map.into_insert("from", from.to_string());
+                map.into_insert("path", path.to_string());
+            }
+        }
+
+        Variant::from(map).with_annotations_from(&operation.annotated)
+    }
+}
+
+fn malformed<AnnotatedT, SourceT>(reason: String, source: &SourceT) -> PatchError<AnnotatedT>
+where
+    AnnotatedT: Annotated + Default,
+    SourceT: Annotated,
+{
+    MalformedError::new("JSON Patch operation".into(), reason).with_annotations_from(source).into()
+}
@@ -0,0 +1,103 @@
+use super::representation::*;
+
+use thiserror::*;
+
+//
+// JsonPointerError
+//
+
+/// [JsonPointer](super::json_pointer::JsonPointer) error.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum JsonPointerError {
+    /// Malformed.
+    #[error("malformed JSON Pointer: {0:?}")]
+    Malformed(String),
+
+    /// Not found.
+    #[error("not found")]
+    NotFound,
+
+    /// Parent not found.
+    #[error("parent not found")]
+    ParentNotFound,
+
+    /// Parent is not a list or map.
+    #[error("parent is {0}, expected List or Map")]
+    NotACollection(String),
+
+    /// Invalid list index.
+    #[error("invalid list index: {0:?}")]
+    InvalidListIndex(String),
+
+    /// List index out of bounds.
+    #[error("list index out of bounds: {0}")]
+    ListIndexOutOfBounds(usize),
+
+    /// Cannot remove the root.
+    #[error("cannot remove the root")]
+    RemoveRoot,
+}
+
+//
+// PathError
+//
+
+/// Path mutation error.
+///
+/// See [Variant::set_path](super::super::normal::Variant::set_path).
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum PathError {
+    /// Missing and not allowed to be created.
+    #[error("{0}: missing")]
+    Missing(PathRepresentation),
+
+    /// Intermediate node is not a list or map.
+    #[error("{path}: is {type_name}, expected List or Map")]
+    NotACollection {
+        /// Path.
+        path: PathRepresentation,
+
+        /// Type name.
+        type_name: String,
+    },
+
+    /// Key is not a list index.
+    #[error("{path}: {key} is not a list index")]
+    NotAListIndex {
+        /// Path of the list.
+        path: PathRepresentation,
+
+        /// Key.
+        key: String,
+    },
+
+    /// List index out of bounds.
+    #[error("{0}: list index out of bounds")]
+    ListIndexOutOfBounds(PathRepresentation),
+
+    /// Cannot remove the root.
+    #[error("cannot remove the root")]
+    RemoveRoot,
+}
+
+//
+// MalformedPathError
+//
+
+/// Malformed [PathRepresentation].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("malformed path: {message} at position {position}")]
+pub struct MalformedPathError {
+    /// Message.
+    pub message: String,
+
+    /// Position (in bytes).
+    pub position: usize,
+}
+
+impl MalformedPathError {
+    /// Constructor.
+    pub fn new(message: String, position: usize) -> Self {
+        Self { message, position }
+    }
+}
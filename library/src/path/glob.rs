@@ -0,0 +1,85 @@
+use super::{
+    super::{annotate::*, normal::*, visit::*},
+    pattern::*,
+    representation::*,
+};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Find all descendents (or self) with paths matching a [PathPattern], in depth-first
+    /// pre-order.
+    ///
+    /// Subtrees that cannot contain matches are skipped.
+    pub fn glob(&self, pattern: &PathPattern) -> Vec<(PathRepresentation, &Self)> {
+        let mut matches = Vec::default();
+        self.glob_at(pattern, &mut Default::default(), &mut matches);
+        matches
+    }
+
+    /// Call a function on all descendents (or self) with paths matching a [PathPattern], in
+    /// depth-first pre-order.
+    ///
+    /// The function may modify or replace the variant, in which case the children of the *new*
+    /// variant will be matched. Returns the number of matches.
+    ///
+    /// Errors returned by the function abort. If they don't already have [Annotations] they will
+    /// be given the [Annotations] of the matched variant and its [PathRepresentation]. See
+    /// [Variant::visit_mut].
+    pub fn apply_glob<ErrorT, FunctionT>(
+        &mut self,
+        pattern: &PathPattern,
+        mut function: FunctionT,
+    ) -> Result<usize, ErrorT>
+    where
+        AnnotatedT: Annotated + Clone,
+        ErrorT: Annotated,
+        FunctionT: FnMut(&mut Self, &PathRepresentation) -> Result<(), ErrorT>,
+    {
+        let mut count = 0;
+
+        self.visit_mut(&mut |variant: &mut Self, path: &PathRepresentation| {
+            if pattern.matches(path) {
+                function(variant, path)?;
+                count += 1;
+            }
+
+            Ok(if pattern.matches_prefix(path) { Visit::Continue } else { Visit::SkipChildren })
+        })?;
+
+        Ok(count)
+    }
+
+    fn glob_at<'own>(
+        &'own self,
+        pattern: &PathPattern,
+        path: &mut PathRepresentation,
+        matches: &mut Vec<(PathRepresentation, &'own Self)>,
+    ) {
+        if pattern.matches(path) {
+            matches.push((path.clone(), self));
+        }
+
+        match self {
+            Self::List(list) => {
+                for (index, item) in list.inner.iter().enumerate() {
+                    path.push_list_index(index);
+                    if pattern.matches_prefix(path) {
+                        item.glob_at(pattern, path, matches);
+                    }
+                    path.segments.pop();
+                }
+            }
+
+            Self::Map(map) => {
+                for (key, value) in map.inner.iter() {
+                    path.push_variant_key(key);
+                    if pattern.matches_prefix(path) {
+                        value.glob_at(pattern, path, matches);
+                    }
+                    path.segments.pop();
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
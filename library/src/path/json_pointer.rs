@@ -0,0 +1,279 @@
+use super::{super::normal::*, errors::*, key_representation::*, representation::*, segment::*};
+
+use {
+    kutil::{cli::depict::*, std::immutable::*},
+    std::{fmt, io, str::*},
+};
+
+//
+// JsonPointer
+//
+
+/// JSON Pointer (RFC 6901).
+///
+/// A pointer is a sequence of reference tokens, e.g. "/a/0/b~1c" is "a", "0", and "b/c". Whether a
+/// token is a list index or a map key depends on the [Variant] it is applied to.
+///
+/// Map keys are addressed by their string representation (their [Display](fmt::Display)), which is
+/// also what [PathRepresentation] uses. A token first matches a [Text] key with exactly that text.
+/// Otherwise it matches a key of another type with that representation, e.g. "true" would match
+/// the [Boolean] key true and "1i64" would match the [Integer] key 1. A plain number without a type
+/// suffix, as RFC 6901 would have it, matches a numeric key of any type with that value, e.g. "1"
+/// would match the [Integer] key 1 (trying [Number], [UnsignedInteger], [Integer], and then
+/// [Float]).
+///
+/// Note that this is ambiguous: a key of another type cannot be addressed if there is also a
+/// [Text] key with the same representation (e.g. the [Text] key "1i64" hides the [Integer] key 1).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct JsonPointer {
+    /// Reference tokens (unescaped).
+    pub tokens: Vec<ByteString>,
+}
+
+impl JsonPointer {
+    /// True if this is the root pointer ("").
+    pub fn is_root(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Push a reference token.
+    pub fn push(&mut self, token: ByteString) {
+        self.tokens.push(token);
+    }
+
+    /// The parent pointer and the last reference token.
+    ///
+    /// Returns [None] for the root pointer.
+    pub fn split_last(&self) -> Option<(&ByteString, &[ByteString])> {
+        self.tokens.split_last()
+    }
+
+    /// True if this pointer starts with the other pointer (or is equal to it).
+    pub fn starts_with(&self, other: &Self) -> bool {
+        self.tokens.starts_with(&other.tokens)
+    }
+
+    /// To [PathRepresentation] without a [Variant] to guide it.
+    ///
+    /// Tokens that are valid list indexes are assumed to be list indexes and other tokens are
+    /// read as unquoted map keys (see [MapKeyRepresentation::from_unquoted]), so for the exact path
+    /// use [to_path_representation_for](Self::to_path_representation_for) instead.
+    pub fn to_path_representation(&self) -> PathRepresentation {
+        let mut path = PathRepresentation::default();
+        for token in &self.tokens {
+            match parse_list_index(token) {
+                Some(index) => path.push_list_index(index),
+                None => path.segments.push(PathSegment::MapKey(MapKeyRepresentation::from_unquoted(token.clone()))),
+            }
+        }
+        path
+    }
+
+    /// To [PathRepresentation] for a [Variant].
+    ///
+    /// Returns [None] if the pointer does not exist in the variant.
+    pub fn to_path_representation_for<AnnotatedT>(&self, variant: &Variant<AnnotatedT>) -> Option<PathRepresentation>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let mut path = PathRepresentation::default();
+        let mut variant = variant;
+        for token in &self.tokens {
+            variant = match variant {
+                Variant::Map(map) => {
+                    let key = map.json_pointer_key(token)?;
+                    path.push_variant_key(&key);
+                    map.inner.get(&key)?
+                }
+
+                Variant::List(list) => {
+                    let index = parse_list_index(token)?;
+                    path.push_list_index(index);
+                    list.inner.get(index)?
+                }
+
+                _ => return None,
+            };
+        }
+        Some(path)
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = JsonPointerError;
+
+    fn from_str(representation: &str) -> Result<Self, Self::Err> {
+        if representation.is_empty() {
+            return Ok(Default::default());
+        }
+
+        let malformed = || JsonPointerError::Malformed(representation.into());
+
+        Ok(Self {
+            tokens: representation
+                .strip_prefix('/')
+                .ok_or_else(malformed)?
+                .split('/')
+                .map(|token| unescape_token(token).map(ByteString::from).ok_or_else(malformed))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Depict for JsonPointer {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+        context.theme.write_string(writer, self)
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.tokens {
+            write!(formatter, "/{}", token.replace('~', "~0").replace('/', "~1"))?;
+        }
+        Ok(())
+    }
+}
+
+// Conversions
+
+impl From<&PathRepresentation> for JsonPointer {
+    fn from(path: &PathRepresentation) -> Self {
+        Self {
+            tokens: path
+                .segments
+                .iter()
+                .map(|segment| match segment {
+                    PathSegment::ListIndex(index) => index.to_string().into(),
+                    PathSegment::MapKey(MapKeyRepresentation::Text(key) | MapKeyRepresentation::Other(key)) => {
+                        key.clone()
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<PathRepresentation> for JsonPointer {
+    fn from(path: PathRepresentation) -> Self {
+        (&path).into()
+    }
+}
+
+impl PathRepresentation {
+    /// Parse a JSON Pointer.
+    ///
+    /// See [JsonPointer::to_path_representation].
+    pub fn from_json_pointer(pointer: &str) -> Result<Self, JsonPointerError> {
+        Ok(pointer.parse::<JsonPointer>()?.to_path_representation())
+    }
+
+    /// To JSON Pointer.
+    pub fn to_json_pointer(&self) -> JsonPointer {
+        self.into()
+    }
+}
+
+impl<AnnotatedT> Map<AnnotatedT> {
+    /// The key addressed by a [JsonPointer] reference token, if it exists.
+    ///
+    /// See [JsonPointer] for the matching rules.
+    pub fn json_pointer_key(&self, token: &str) -> Option<Variant<AnnotatedT>>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let key = Variant::from(String::from(token));
+        if self.inner.contains_key(&key) {
+            return Some(key);
+        }
+
+        // Scalar keys can be parsed from their representation
+        let keys = parse_scalar_keys(token);
+        if !keys.is_empty() {
+            return keys.into_iter().find(|key| self.inner.contains_key(key));
+        }
+
+        // Only keys with a representation that cannot be parsed back have to be searched for
+        if token.starts_with(['[', '{']) || token.ends_with(" bytes") {
+            return self
+                .inner
+                .keys()
+                .find(|key| {
+                    matches!(key, Variant::List(_) | Variant::Map(_) | Variant::Blob(_)) && (key.to_string() == token)
+                })
+                .cloned();
+        }
+
+        None
+    }
+}
+
+/// Parse a [JsonPointer] list index reference token.
+///
+/// Leading zeros are not allowed. Note that "-" (past the end) is *not* handled here.
+pub fn parse_list_index(token: &str) -> Option<usize> {
+    if (token == "0") || (!token.starts_with('0') && token.bytes().all(|byte| byte.is_ascii_digit())) {
+        token.parse().ok()
+    } else {
+        None
+    }
+}
+
+// The inverse of Display for scalar keys (other than Text)
+//
+// A bare number (without a type suffix) could be any of the number types, so all of them are
+// returned, the verbatim Number first.
+fn parse_scalar_keys<AnnotatedT>(token: &str) -> Vec<Variant<AnnotatedT>>
+where
+    AnnotatedT: Default,
+{
+    match token {
+        "true" => return vec![true.into()],
+        "false" => return vec![false.into()],
+        "Null" => return vec![Null::default().into()],
+        _ => {}
+    }
+
+    if let Some(integer) = token.strip_suffix("i64") {
+        integer.parse::<i64>().ok().map(|integer| integer.into()).into_iter().collect()
+    } else if let Some(unsigned_integer) = token.strip_suffix("u64") {
+        unsigned_integer.parse::<u64>().ok().map(|unsigned_integer| unsigned_integer.into()).into_iter().collect()
+    } else if let Some(float) = token.strip_suffix("f64") {
+        float.parse::<f64>().ok().map(|float| float.into()).into_iter().collect()
+    } else if token.starts_with(|c: char| c.is_ascii_digit() || (c == '-')) {
+        let mut keys = vec![Number::from(ByteString::from(token)).into()];
+        if let Ok(unsigned_integer) = token.parse::<u64>() {
+            keys.push(unsigned_integer.into());
+        }
+        if let Ok(integer) = token.parse::<i64>() {
+            keys.push(integer.into());
+        }
+        if let Ok(float) = token.parse::<f64>() {
+            keys.push(float.into());
+        }
+        keys
+    } else {
+        Default::default()
+    }
+}
+
+fn unescape_token(token: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next()? {
+                '0' => unescaped.push('~'),
+                '1' => unescaped.push('/'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
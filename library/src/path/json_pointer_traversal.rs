@@ -0,0 +1,128 @@
+use super::{super::normal::*, errors::*, json_pointer::*};
+
+use {kutil::std::immutable::*, std::mem};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Get a nested variant by [JsonPointer].
+    pub fn pointer_get(&self, pointer: &JsonPointer) -> Option<&Self>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        self.pointer_get_tokens(&pointer.tokens)
+    }
+
+    /// Get a mutable nested variant by [JsonPointer].
+    pub fn pointer_get_mut(&mut self, pointer: &JsonPointer) -> Option<&mut Self>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        self.pointer_get_tokens_mut(&pointer.tokens)
+    }
+
+    /// Insert a variant at a [JsonPointer]. Return the replaced variant, if there was one.
+    ///
+    /// The parent must exist. In a map the key is inserted or replaced (a new key is always
+    /// [Text]). In a list the item is inserted before the index, which may be the length of the
+    /// list or "-" (past the end) to append. The root pointer replaces this variant.
+    ///
+    /// These are the semantics of JSON Patch's "add" operation.
+    pub fn pointer_insert(&mut self, pointer: &JsonPointer, variant: Self) -> Result<Option<Self>, JsonPointerError>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let Some((token, parent_tokens)) = pointer.split_last() else {
+            return Ok(Some(mem::replace(self, variant)));
+        };
+
+        match self.pointer_get_tokens_mut(parent_tokens).ok_or(JsonPointerError::ParentNotFound)? {
+            Self::Map(map) => {
+                let key = map.json_pointer_key(token).unwrap_or_else(|| token.clone().into());
+                Ok(map.inner.insert(key, variant))
+            }
+
+            Self::List(list) => {
+                if token == "-" {
+                    list.inner.push(variant);
+                    return Ok(None);
+                }
+
+                let index =
+                    parse_list_index(token).ok_or_else(|| JsonPointerError::InvalidListIndex(token.to_string()))?;
+                if index > list.inner.len() {
+                    return Err(JsonPointerError::ListIndexOutOfBounds(index));
+                }
+
+                list.inner.insert(index, variant);
+                Ok(None)
+            }
+
+            parent => Err(JsonPointerError::NotACollection(parent.type_name().into())),
+        }
+    }
+
+    /// Remove the variant at a [JsonPointer].
+    ///
+    /// List items after it are shifted. The root cannot be removed.
+    pub fn pointer_remove(&mut self, pointer: &JsonPointer) -> Result<Self, JsonPointerError>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let Some((token, parent_tokens)) = pointer.split_last() else {
+            return Err(JsonPointerError::RemoveRoot);
+        };
+
+        match self.pointer_get_tokens_mut(parent_tokens).ok_or(JsonPointerError::ParentNotFound)? {
+            Self::Map(map) => {
+                let key = map.json_pointer_key(token).ok_or(JsonPointerError::NotFound)?;
+                map.inner.remove(&key).ok_or(JsonPointerError::NotFound)
+            }
+
+            Self::List(list) => {
+                let index =
+                    parse_list_index(token).ok_or_else(|| JsonPointerError::InvalidListIndex(token.to_string()))?;
+                if index >= list.inner.len() {
+                    return Err(JsonPointerError::ListIndexOutOfBounds(index));
+                }
+
+                Ok(list.inner.remove(index))
+            }
+
+            parent => Err(JsonPointerError::NotACollection(parent.type_name().into())),
+        }
+    }
+
+    fn pointer_get_tokens(&self, tokens: &[ByteString]) -> Option<&Self>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let mut variant = self;
+        for token in tokens {
+            variant = match variant {
+                Self::Map(map) => map.inner.get(&map.json_pointer_key(token)?)?,
+                Self::List(list) => list.inner.get(parse_list_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(variant)
+    }
+
+    fn pointer_get_tokens_mut(&mut self, tokens: &[ByteString]) -> Option<&mut Self>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let mut variant = self;
+        for token in tokens {
+            variant = match variant {
+                Self::Map(map) => {
+                    let key = map.json_pointer_key(token)?;
+                    map.inner.get_mut(&key)?
+                }
+
+                Self::List(list) => list.inner.get_mut(parse_list_index(token)?)?,
+
+                _ => return None,
+            };
+        }
+        Some(variant)
+    }
+}
@@ -0,0 +1,82 @@
+use super::{super::normal::*, json_pointer::*, key_representation::*, segment::*};
+
+//
+// PathKey
+//
+
+/// Key for path-based mutation.
+///
+/// Implemented for [PathSegment] (as found in
+/// [PathRepresentation](super::representation::PathRepresentation)) and for [Variant] keys (as used
+/// by [Variant::traverse]).
+///
+/// See [Variant::set_path].
+pub trait PathKey<AnnotatedT> {
+    /// As an index into an existing [List].
+    fn to_list_index(&self) -> Option<usize>;
+
+    /// As a key into an existing [Map].
+    ///
+    /// Should return the existing key if there is a matching one.
+    fn to_map_key(&self, map: &Map<AnnotatedT>) -> Variant<AnnotatedT>;
+
+    /// Whether a missing container for this key should be a [List] (otherwise it is a [Map]).
+    fn is_list_index(&self) -> bool;
+}
+
+impl<AnnotatedT> PathKey<AnnotatedT> for &PathSegment<MapKeyRepresentation>
+where
+    AnnotatedT: Clone + Default,
+{
+    fn to_list_index(&self) -> Option<usize> {
+        match self {
+            PathSegment::ListIndex(index) => Some(*index),
+            PathSegment::MapKey(MapKeyRepresentation::Text(key)) => parse_list_index(key),
+            PathSegment::MapKey(MapKeyRepresentation::Other(_)) => None,
+        }
+    }
+
+    fn to_map_key(&self, map: &Map<AnnotatedT>) -> Variant<AnnotatedT> {
+        match self {
+            PathSegment::ListIndex(index) => map
+                .inner
+                .keys()
+                .find(|key| match key {
+                    Variant::Integer(integer) => usize::try_from(integer.inner).ok() == Some(*index),
+                    Variant::UnsignedInteger(unsigned_integer) => {
+                        usize::try_from(unsigned_integer.inner).ok() == Some(*index)
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .unwrap_or_else(|| (*index as i64).into()),
+
+            PathSegment::MapKey(key) => key.to_map_key(map),
+        }
+    }
+
+    fn is_list_index(&self) -> bool {
+        matches!(self, PathSegment::ListIndex(_))
+    }
+}
+
+impl<AnnotatedT> PathKey<AnnotatedT> for &Variant<AnnotatedT>
+where
+    AnnotatedT: Clone,
+{
+    fn to_list_index(&self) -> Option<usize> {
+        match self {
+            Variant::Integer(integer) => usize::try_from(integer.inner).ok(),
+            Variant::UnsignedInteger(unsigned_integer) => usize::try_from(unsigned_integer.inner).ok(),
+            _ => None,
+        }
+    }
+
+    fn to_map_key(&self, _map: &Map<AnnotatedT>) -> Variant<AnnotatedT> {
+        (*self).clone()
+    }
+
+    fn is_list_index(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,158 @@
+use super::super::{annotate::*, normal::*};
+
+use {
+    kutil::std::immutable::*,
+    std::fmt::{self, Write},
+};
+
+//
+// MapKeyRepresentation
+//
+
+/// Map key in a [PathRepresentation](super::representation::PathRepresentation).
+///
+/// Text keys are kept apart from other keys, which are represented by their
+/// [Display](fmt::Display), e.g. the [Integer] key 10 is `10i64`. Thus a text key is never
+/// confused with another key that has the same representation.
+///
+/// Its [Display](fmt::Display) is its syntax in a path. Text keys are quoted (with Rust string
+/// escapes) if they are empty, contain `"`, `.`, `[`, `]`, whitespace, or control characters,
+/// start with `` ` ``, or would otherwise be read as an [Integer], [UnsignedInteger], [Float],
+/// [Boolean], or [Null] key. Such keys are not quoted, e.g. `10i64`, `true`, and `Null`, while
+/// all other keys are in backticks (with the same escapes), e.g. `` `1.5f64` ``, `` `1e400` ``,
+/// or `` `3 bytes` ``.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MapKeyRepresentation {
+    /// [Text] key.
+    Text(ByteString),
+
+    /// Any other key by its [Display](fmt::Display).
+    Other(ByteString),
+}
+
+impl MapKeyRepresentation {
+    /// Constructor.
+    pub fn new<AnnotatedT>(key: &Variant<AnnotatedT>) -> Self {
+        match key {
+            Variant::Text(text) => Self::Text(text.inner.clone()),
+            _ => Self::Other(key.to_string().into()),
+        }
+    }
+
+    /// The key in the map.
+    ///
+    /// Returns the matching key if there is one. Otherwise returns a new key, which is
+    /// [Undefined](Variant::Undefined) if it cannot be constructed from its representation (e.g. a
+    /// [Blob]).
+    pub fn to_map_key<AnnotatedT>(&self, map: &Map<AnnotatedT>) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        match self {
+            Self::Text(key) => key.clone().into(),
+
+            Self::Other(representation) => parse_scalar_key(representation)
+                .or_else(|| {
+                    map.inner
+                        .keys()
+                        .find(|key| !matches!(key, Variant::Text(_)) && (key.to_string() == **representation))
+                        .cloned()
+                })
+                .or_else(|| decimal_parts(representation).map(|_| Number::from(representation.clone()).into()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// From a key as it appears unquoted in a path.
+    ///
+    /// It is an [Other](Self::Other) key if it is the representation of an [Integer],
+    /// [UnsignedInteger], [Float], [Boolean], or [Null] key, otherwise it is a
+    /// [Text](Self::Text) key.
+    pub fn from_unquoted(key: ByteString) -> Self {
+        match parse_scalar_key::<WithoutAnnotations>(&key) {
+            Some(scalar_key) => Self::Other(scalar_key.to_string().into()),
+            None => Self::Text(key),
+        }
+    }
+}
+
+impl fmt::Display for MapKeyRepresentation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(key) => {
+                if must_quote(key) || key.starts_with('`') || parse_scalar_key::<WithoutAnnotations>(key).is_some() {
+                    // Debug will quote the string
+                    fmt::Debug::fmt(key, formatter)
+                } else {
+                    fmt::Display::fmt(key, formatter)
+                }
+            }
+
+            Self::Other(representation) => {
+                if !must_quote(representation)
+                    && parse_scalar_key::<WithoutAnnotations>(representation)
+                        .is_some_and(|key| key.to_string() == **representation)
+                {
+                    fmt::Display::fmt(representation, formatter)
+                } else {
+                    formatter.write_char('`')?;
+                    for c in representation.chars() {
+                        match c {
+                            '`' => formatter.write_str("\\`")?,
+                            _ => write!(formatter, "{}", c.escape_debug())?,
+                        }
+                    }
+                    formatter.write_char('`')
+                }
+            }
+        }
+    }
+}
+
+impl From<ByteString> for MapKeyRepresentation {
+    fn from(key: ByteString) -> Self {
+        Self::Text(key)
+    }
+}
+
+impl From<&str> for MapKeyRepresentation {
+    fn from(key: &str) -> Self {
+        Self::Text(key.into())
+    }
+}
+
+impl<AnnotatedT> From<&Variant<AnnotatedT>> for MapKeyRepresentation {
+    fn from(key: &Variant<AnnotatedT>) -> Self {
+        Self::new(key)
+    }
+}
+
+// Utils
+
+// Keys that would otherwise not be parsed back as they are
+fn must_quote(key: &str) -> bool {
+    key.is_empty() || key.contains(|c: char| matches!(c, '"' | '.' | '[' | ']') || c.is_whitespace() || c.is_control())
+}
+
+// Scalar keys with an unambiguous representation
+fn parse_scalar_key<AnnotatedT>(representation: &str) -> Option<Variant<AnnotatedT>>
+where
+    AnnotatedT: Default,
+{
+    match representation {
+        "true" => Some(true.into()),
+        "false" => Some(false.into()),
+        "Null" => Some(Null::default().into()),
+        _ => {
+            if let Some(integer) = representation.strip_suffix("i64") {
+                integer.parse::<i64>().ok().map(|integer| integer.into())
+            } else if let Some(unsigned_integer) = representation.strip_suffix("u64") {
+                unsigned_integer.parse::<u64>().ok().map(|unsigned_integer| unsigned_integer.into())
+            } else if let Some(float) = representation.strip_suffix("f64") {
+                float.parse::<f64>().ok().map(|float| float.into())
+            } else {
+                None
+            }
+        }
+    }
+}
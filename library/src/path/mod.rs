@@ -1,7 +1,19 @@
+mod errors;
+mod glob;
+mod json_pointer;
+mod json_pointer_traversal;
+mod key;
+mod key_representation;
+mod mutation;
 mod node;
 mod path;
+mod path_traversal;
+mod pattern;
 mod representation;
 mod segment;
 
 #[allow(unused_imports)]
-pub use {node::*, path::*, representation::*, segment::*};
+pub use {
+    errors::*, glob::*, json_pointer::*, json_pointer_traversal::*, key::*, key_representation::*, mutation::*,
+    node::*, path::*, path_traversal::*, pattern::*, representation::*, segment::*,
+};
@@ -0,0 +1,332 @@
+use super::{super::normal::*, errors::*, key::*, representation::*};
+
+use std::{fmt, mem};
+
+//
+// VivifyPolicy
+//
+
+/// Auto-vivification policy for path-based mutation.
+///
+/// See [Variant::set_path].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum VivifyPolicy {
+    /// Missing intermediate nodes are errors.
+    Never,
+
+    /// Create missing intermediate nodes. A list index creates a [List] and any other key creates
+    /// a [Map]. List indexes cannot be past the end of the list.
+    #[default]
+    Create,
+
+    /// Like [Create](VivifyPolicy::Create), but list indexes past the end of the list will pad it
+    /// with [Null] items.
+    CreateAndPad,
+}
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Set a nested variant by path. Return the replaced variant, if there was one.
+    ///
+    /// The path can be a [PathRepresentation] or an iterator of keys (see [PathKey]). Missing
+    /// intermediate nodes are created according to the [VivifyPolicy], in which case [Null] and
+    /// [Undefined](Variant::Undefined) are treated as missing, too. An intermediate node of any
+    /// other non-collection type is an error. A list index equal to the length of the list will
+    /// append to it.
+    ///
+    /// On error this variant is left unchanged. The empty path replaces this variant.
+    pub fn set_path<IteratorT, KeyT>(
+        &mut self,
+        path: IteratorT,
+        variant: Self,
+        policy: VivifyPolicy,
+    ) -> Result<Option<Self>, PathError>
+    where
+        AnnotatedT: Clone + Default,
+        IteratorT: IntoIterator<Item = KeyT>,
+        KeyT: PathKey<AnnotatedT> + fmt::Display,
+    {
+        let keys: Vec<_> = path.into_iter().collect();
+        let Some(key) = keys.last() else {
+            return Ok(Some(mem::replace(self, variant)));
+        };
+
+        let mut path = PathRepresentation::default();
+        match self.vivify_parent(&keys, policy, &mut path)? {
+            Self::Map(map) => {
+                let key = key.to_map_key(map);
+                Ok(map.inner.insert(key, variant))
+            }
+
+            Self::List(list) => {
+                let index = to_list_index(key, &path)?;
+                if let Some(item) = list.inner.get_mut(index) {
+                    Ok(Some(mem::replace(item, variant)))
+                } else {
+                    pad_list(list, index, policy, &path)?;
+                    list.inner.push(variant);
+                    Ok(None)
+                }
+            }
+
+            parent => Err(not_a_collection(parent, path)),
+        }
+    }
+
+    /// Insert a nested variant by path. Return the replaced variant, if there was one.
+    ///
+    /// Same as [Variant::set_path] except that in a list the variant is inserted before the index
+    /// instead of replacing the item at the index.
+    pub fn insert_path<IteratorT, KeyT>(
+        &mut self,
+        path: IteratorT,
+        variant: Self,
+        policy: VivifyPolicy,
+    ) -> Result<Option<Self>, PathError>
+    where
+        AnnotatedT: Clone + Default,
+        IteratorT: IntoIterator<Item = KeyT>,
+        KeyT: PathKey<AnnotatedT> + fmt::Display,
+    {
+        let keys: Vec<_> = path.into_iter().collect();
+        let Some(key) = keys.last() else {
+            return Ok(Some(mem::replace(self, variant)));
+        };
+
+        let mut path = PathRepresentation::default();
+        match self.vivify_parent(&keys, policy, &mut path)? {
+            Self::Map(map) => {
+                let key = key.to_map_key(map);
+                Ok(map.inner.insert(key, variant))
+            }
+
+            Self::List(list) => {
+                let index = to_list_index(key, &path)?;
+                pad_list(list, index, policy, &path)?;
+                list.inner.insert(index, variant);
+                Ok(None)
+            }
+
+            parent => Err(not_a_collection(parent, path)),
+        }
+    }
+
+    /// Remove a nested variant by path. Return the removed variant, if there was one.
+    ///
+    /// The path can be a [PathRepresentation] or an iterator of keys (see [PathKey]). A missing
+    /// node (including a [Null] or [Undefined](Variant::Undefined) intermediate node) is not an
+    /// error, but an intermediate node of any other non-collection type is. List items after the
+    /// removed one are shifted. The root cannot be removed.
+    pub fn remove_path<IteratorT, KeyT>(&mut self, path: IteratorT) -> Result<Option<Self>, PathError>
+    where
+        AnnotatedT: Clone + Default,
+        IteratorT: IntoIterator<Item = KeyT>,
+        KeyT: PathKey<AnnotatedT> + fmt::Display,
+    {
+        let keys: Vec<_> = path.into_iter().collect();
+        let Some((key, parent_keys)) = keys.split_last() else {
+            return Err(PathError::RemoveRoot);
+        };
+
+        let mut path = PathRepresentation::default();
+        let mut variant = self;
+        for key in parent_keys {
+            variant = match variant {
+                Self::Map(map) => {
+                    let key = key.to_map_key(map);
+                    path.push_variant_key(&key);
+                    match map.inner.get_mut(&key) {
+                        Some(variant) => variant,
+                        None => return Ok(None),
+                    }
+                }
+
+                Self::List(list) => {
+                    let index = to_list_index(key, &path)?;
+                    path.push_list_index(index);
+                    match list.inner.get_mut(index) {
+                        Some(variant) => variant,
+                        None => return Ok(None),
+                    }
+                }
+
+                Self::Null(_) | Self::Undefined => return Ok(None),
+
+                variant => return Err(not_a_collection(variant, path)),
+            };
+        }
+
+        match variant {
+            Self::Map(map) => {
+                let key = key.to_map_key(map);
+                Ok(map.inner.remove(&key))
+            }
+
+            Self::List(list) => {
+                let index = to_list_index(key, &path)?;
+                Ok(if index < list.inner.len() { Some(list.inner.remove(index)) } else { None })
+            }
+
+            Self::Null(_) | Self::Undefined => Ok(None),
+
+            parent => Err(not_a_collection(parent, path)),
+        }
+    }
+
+    // Vivifies all nodes up to and including the parent of the last key
+    fn vivify_parent<KeyT>(
+        &mut self,
+        keys: &[KeyT],
+        policy: VivifyPolicy,
+        path: &mut PathRepresentation,
+    ) -> Result<&mut Self, PathError>
+    where
+        AnnotatedT: Clone + Default,
+        KeyT: PathKey<AnnotatedT> + fmt::Display,
+    {
+        let mut variant = self;
+        for (index, key) in keys.iter().enumerate() {
+            variant.vivify(&keys[index..], policy, path)?;
+            if index + 1 < keys.len() {
+                variant = variant.vivify_child(key, &keys[index + 1..], policy, path)?;
+            }
+        }
+        Ok(variant)
+    }
+
+    // Replaces a missing node with a collection
+    fn vivify<KeyT>(&mut self, keys: &[KeyT], policy: VivifyPolicy, path: &PathRepresentation) -> Result<(), PathError>
+    where
+        AnnotatedT: Clone + Default,
+        KeyT: PathKey<AnnotatedT>,
+    {
+        if (policy != VivifyPolicy::Never) && (self.is_null() || self.is_undefined()) {
+            check_vivifiable(keys, policy, path)?;
+            *self = if keys[0].is_list_index() { List::default().into() } else { Map::default().into() };
+        }
+        Ok(())
+    }
+
+    // Gets a child, adding an undefined placeholder if it is missing
+    fn vivify_child<KeyT>(
+        &mut self,
+        key: &KeyT,
+        keys: &[KeyT],
+        policy: VivifyPolicy,
+        path: &mut PathRepresentation,
+    ) -> Result<&mut Self, PathError>
+    where
+        AnnotatedT: Clone + Default,
+        KeyT: PathKey<AnnotatedT> + fmt::Display,
+    {
+        match self {
+            Self::Map(map) => {
+                let key = key.to_map_key(map);
+                path.push_variant_key(&key);
+
+                if !map.inner.contains_key(&key) {
+                    if policy == VivifyPolicy::Never {
+                        return Err(PathError::Missing(path.clone()));
+                    }
+
+                    check_vivifiable(keys, policy, path)?;
+                    map.inner.insert(key.clone(), Self::Undefined);
+                }
+
+                Ok(map.inner.get_mut(&key).expect("get_mut"))
+            }
+
+            Self::List(list) => {
+                let index = to_list_index(key, path)?;
+
+                if index >= list.inner.len() {
+                    if policy == VivifyPolicy::Never {
+                        path.push_list_index(index);
+                        return Err(PathError::Missing(path.clone()));
+                    }
+
+                    if (index > list.inner.len()) && (policy != VivifyPolicy::CreateAndPad) {
+                        path.push_list_index(index);
+                        return Err(PathError::ListIndexOutOfBounds(path.clone()));
+                    }
+
+                    path.push_list_index(index);
+                    check_vivifiable(keys, policy, path)?;
+                    pad_list(list, index, policy, path)?;
+                    list.inner.push(Self::Undefined);
+                } else {
+                    path.push_list_index(index);
+                }
+
+                Ok(list.inner.get_mut(index).expect("get_mut"))
+            }
+
+            variant => Err(not_a_collection(variant, path.clone())),
+        }
+    }
+}
+
+// Utils
+
+// Makes sure that the keys can be applied to newly created collections, so that we never leave
+// partially vivified nodes behind on error
+fn check_vivifiable<AnnotatedT, KeyT>(
+    keys: &[KeyT],
+    policy: VivifyPolicy,
+    path: &PathRepresentation,
+) -> Result<(), PathError>
+where
+    AnnotatedT: Clone + Default,
+    KeyT: PathKey<AnnotatedT>,
+{
+    if policy == VivifyPolicy::CreateAndPad {
+        return Ok(());
+    }
+
+    let mut path = path.clone();
+    for key in keys {
+        if key.is_list_index() {
+            let index = key.to_list_index().unwrap_or_default();
+            path.push_list_index(index);
+            if index > 0 {
+                return Err(PathError::ListIndexOutOfBounds(path));
+            }
+        } else {
+            path.push_variant_key(&key.to_map_key(&Default::default()));
+        }
+    }
+
+    Ok(())
+}
+
+fn pad_list<AnnotatedT>(
+    list: &mut List<AnnotatedT>,
+    index: usize,
+    policy: VivifyPolicy,
+    path: &PathRepresentation,
+) -> Result<(), PathError>
+where
+    AnnotatedT: Clone + Default,
+{
+    let length = list.inner.len();
+    if index > length {
+        if policy != VivifyPolicy::CreateAndPad {
+            let mut path = path.clone();
+            path.push_list_index(index);
+            return Err(PathError::ListIndexOutOfBounds(path));
+        }
+
+        list.inner.extend((length..index).map(|_| Null::default().into()));
+    }
+    Ok(())
+}
+
+fn to_list_index<AnnotatedT, KeyT>(key: &KeyT, path: &PathRepresentation) -> Result<usize, PathError>
+where
+    KeyT: PathKey<AnnotatedT> + fmt::Display,
+{
+    key.to_list_index().ok_or_else(|| PathError::NotAListIndex { path: path.clone(), key: key.to_string() })
+}
+
+fn not_a_collection<AnnotatedT>(variant: &Variant<AnnotatedT>, path: PathRepresentation) -> PathError {
+    PathError::NotACollection { path, type_name: variant.type_name().into() }
+}
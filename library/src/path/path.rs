@@ -103,7 +103,7 @@ impl<'own, AnnotatedT> Path<'own, AnnotatedT> {
             segments: self
                 .nodes
                 .into_iter()
-                .filter_map(|node| node.segment.map(|segment| segment.to_string_keys()))
+                .filter_map(|node| node.segment.map(|segment| segment.to_representation()))
                 .collect(),
         }
     }
@@ -120,7 +120,7 @@ impl<'own, AnnotatedT> Depict for Path<'own, AnnotatedT> {
                     context.theme.write_delimiter(writer, '.')?;
                 }
 
-                segment.depict(writer, context)?;
+                segment.to_representation().depict(writer, context)?;
             }
         }
 
@@ -136,7 +136,7 @@ impl<'own, AnnotatedT> fmt::Display for Path<'own, AnnotatedT> {
                     formatter.write_char('.')?;
                 }
 
-                fmt::Display::fmt(segment, formatter)?;
+                fmt::Display::fmt(&segment.to_representation(), formatter)?;
             }
         }
 
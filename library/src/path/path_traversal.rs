@@ -0,0 +1,54 @@
+use super::{super::normal::*, key::*};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Get a nested variant by path.
+    ///
+    /// The path can be a [PathRepresentation](super::representation::PathRepresentation) or an
+    /// iterator of keys (see [PathKey]).
+    ///
+    /// Any non-collection or missing key will cause the traversal to stop and return [None].
+    pub fn get_path<IteratorT, KeyT>(&self, path: IteratorT) -> Option<&Self>
+    where
+        AnnotatedT: Clone + Default,
+        IteratorT: IntoIterator<Item = KeyT>,
+        KeyT: PathKey<AnnotatedT>,
+    {
+        let mut variant = self;
+        for key in path {
+            variant = match variant {
+                Self::Map(map) => map.inner.get(&key.to_map_key(map))?,
+                Self::List(list) => list.inner.get(key.to_list_index()?)?,
+                _ => return None,
+            };
+        }
+        Some(variant)
+    }
+
+    /// Get a mutable nested variant by path.
+    ///
+    /// The path can be a [PathRepresentation](super::representation::PathRepresentation) or an
+    /// iterator of keys (see [PathKey]).
+    ///
+    /// Any non-collection or missing key will cause the traversal to stop and return [None].
+    pub fn get_path_mut<IteratorT, KeyT>(&mut self, path: IteratorT) -> Option<&mut Self>
+    where
+        AnnotatedT: Clone + Default,
+        IteratorT: IntoIterator<Item = KeyT>,
+        KeyT: PathKey<AnnotatedT>,
+    {
+        let mut variant = self;
+        for key in path {
+            variant = match variant {
+                Self::Map(map) => {
+                    let key = key.to_map_key(map);
+                    map.inner.get_mut(&key)?
+                }
+
+                Self::List(list) => list.inner.get_mut(key.to_list_index()?)?,
+
+                _ => return None,
+            };
+        }
+        Some(variant)
+    }
+}
@@ -0,0 +1,256 @@
+use super::{errors::*, key_representation::*, representation::*, segment::*};
+
+use {
+    kutil::{cli::depict::*, std::iter::*},
+    std::{
+        fmt::{self, Write},
+        io,
+        str::*,
+    },
+};
+
+//
+// PathPattern
+//
+
+/// Path pattern that can match many [PathRepresentation].
+///
+/// The syntax extends that of [PathRepresentation] with wildcards: `*` matches any map key, `[*]`
+/// matches any list index, and `**` matches any number of segments of any kind (including none).
+/// For example, `services.*.image`, `**.password`, and `items[*].name`. A quoted `"*"` is a
+/// literal map key.
+///
+/// See [Variant::glob](super::super::normal::Variant::glob).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PathPattern {
+    /// Pattern segments.
+    pub segments: Vec<PathPatternSegment>,
+}
+
+impl PathPattern {
+    /// True if the path matches the pattern.
+    pub fn matches(&self, path: &PathRepresentation) -> bool {
+        matches(&self.segments, &path.segments)
+    }
+
+    /// True if the path or any of its possible extensions could match the pattern.
+    ///
+    /// Useful for skipping subtrees that cannot contain matches.
+    pub fn matches_prefix(&self, path: &PathRepresentation) -> bool {
+        matches_prefix(&self.segments, &path.segments)
+    }
+}
+
+impl FromStr for PathPattern {
+    type Err = MalformedPathError;
+
+    fn from_str(representation: &str) -> Result<Self, Self::Err> {
+        let mut pattern = Self::default();
+        let mut chars = representation.char_indices().peekable();
+
+        while let Some((position, c)) = chars.peek().cloned() {
+            match c {
+                '[' => {
+                    chars.next();
+                    if chars.next_if(|(_, c)| *c == '*').is_some() {
+                        if chars.next_if(|(_, c)| *c == ']').is_none() {
+                            return Err(MalformedPathError::new("expected ']'".into(), position + 2));
+                        }
+                        pattern.segments.push(PathPatternSegment::AnyListIndex);
+                    } else {
+                        pattern
+                            .segments
+                            .push(PathPatternSegment::ListIndex(parse_path_list_index(&mut chars, position + 1)?));
+                    }
+                }
+
+                '.' if !pattern.segments.is_empty() => {
+                    chars.next();
+                    pattern.segments.push(parse_map_key_pattern(&mut chars, position + 1)?);
+                }
+
+                _ if pattern.segments.is_empty() => {
+                    pattern.segments.push(parse_map_key_pattern(&mut chars, position)?);
+                }
+
+                _ => return Err(MalformedPathError::new(format!("expected '.' or '[', found {:?}", c), position)),
+            }
+        }
+
+        Ok(pattern)
+    }
+}
+
+impl Depict for PathPattern {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        for (segment, first) in IterateWithFirst::new(&self.segments) {
+            if !first && segment.is_map_key() {
+                context.theme.write_delimiter(writer, '.')?;
+            }
+
+            segment.depict(writer, context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for PathPattern {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (segment, first) in IterateWithFirst::new(&self.segments) {
+            if !first && segment.is_map_key() {
+                formatter.write_char('.')?;
+            }
+
+            fmt::Display::fmt(segment, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+//
+// PathPatternSegment
+//
+
+/// [PathPattern] segment.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathPatternSegment {
+    /// List index.
+    ListIndex(usize),
+
+    /// Map key.
+    MapKey(MapKeyRepresentation),
+
+    /// Any list index (`[*]`).
+    AnyListIndex,
+
+    /// Any map key (`*`).
+    AnyMapKey,
+
+    /// Any number of segments of any kind, including none (`**`).
+    AnyDescendants,
+}
+
+impl PathPatternSegment {
+    /// True if the path segment matches.
+    ///
+    /// [AnyDescendants](PathPatternSegment::AnyDescendants) matches any single segment.
+    pub fn matches(&self, segment: &PathSegment<MapKeyRepresentation>) -> bool {
+        match (self, segment) {
+            (Self::ListIndex(index), PathSegment::ListIndex(other_index)) => index == other_index,
+            (Self::MapKey(key), PathSegment::MapKey(other_key)) => key == other_key,
+            (Self::AnyListIndex, PathSegment::ListIndex(_)) | (Self::AnyMapKey, PathSegment::MapKey(_)) => true,
+            (Self::AnyDescendants, _) => true,
+            _ => false,
+        }
+    }
+
+    // Whether it is preceded by "." when not first
+    fn is_map_key(&self) -> bool {
+        matches!(self, Self::MapKey(_) | Self::AnyMapKey | Self::AnyDescendants)
+    }
+}
+
+impl Depict for PathPatternSegment {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        match self {
+            Self::ListIndex(index) => PathSegment::<MapKeyRepresentation>::ListIndex(*index).depict(writer, context),
+            Self::MapKey(MapKeyRepresentation::Text(key)) if is_wildcard(key) => {
+                context.theme.write_string(writer, format!("{:?}", key))
+            }
+            Self::MapKey(key) => PathSegment::MapKey(key).depict(writer, context),
+
+            Self::AnyListIndex => {
+                context.theme.write_delimiter(writer, '[')?;
+                context.theme.write_symbol(writer, '*')?;
+                context.theme.write_delimiter(writer, ']')
+            }
+
+            Self::AnyMapKey => context.theme.write_symbol(writer, '*'),
+            Self::AnyDescendants => context.theme.write_symbol(writer, "**"),
+        }
+    }
+}
+
+impl fmt::Display for PathPatternSegment {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ListIndex(index) => write!(formatter, "[{}]", index),
+            Self::MapKey(MapKeyRepresentation::Text(key)) if is_wildcard(key) => fmt::Debug::fmt(key, formatter),
+            Self::MapKey(key) => fmt::Display::fmt(&PathSegment::MapKey(key), formatter),
+            Self::AnyListIndex => formatter.write_str("[*]"),
+            Self::AnyMapKey => formatter.write_char('*'),
+            Self::AnyDescendants => formatter.write_str("**"),
+        }
+    }
+}
+
+impl From<PathSegment<MapKeyRepresentation>> for PathPatternSegment {
+    fn from(segment: PathSegment<MapKeyRepresentation>) -> Self {
+        match segment {
+            PathSegment::ListIndex(index) => Self::ListIndex(index),
+            PathSegment::MapKey(key) => Self::MapKey(key),
+        }
+    }
+}
+
+impl From<PathRepresentation> for PathPattern {
+    fn from(path: PathRepresentation) -> Self {
+        Self { segments: path.segments.into_iter().map(|segment| segment.into()).collect() }
+    }
+}
+
+// Utils
+
+fn matches(pattern: &[PathPatternSegment], path: &[PathSegment<MapKeyRepresentation>]) -> bool {
+    match pattern.split_first() {
+        Some((PathPatternSegment::AnyDescendants, pattern)) => {
+            (0..=path.len()).any(|skip| matches(pattern, &path[skip..]))
+        }
+
+        Some((segment, pattern)) => match path.split_first() {
+            Some((path_segment, path)) => segment.matches(path_segment) && matches(pattern, path),
+            None => false,
+        },
+
+        None => path.is_empty(),
+    }
+}
+
+fn matches_prefix(pattern: &[PathPatternSegment], path: &[PathSegment<MapKeyRepresentation>]) -> bool {
+    match pattern.split_first() {
+        Some((PathPatternSegment::AnyDescendants, _)) => true,
+
+        Some((segment, pattern)) => match path.split_first() {
+            Some((path_segment, path)) => segment.matches(path_segment) && matches_prefix(pattern, path),
+            None => true,
+        },
+
+        None => path.is_empty(),
+    }
+}
+
+fn parse_map_key_pattern(
+    chars: &mut std::iter::Peekable<CharIndices>,
+    position: usize,
+) -> Result<PathPatternSegment, MalformedPathError> {
+    let quoted = chars.peek().is_some_and(|(_, c)| *c == '"');
+    let key = parse_path_map_key(chars, position)?;
+    Ok(match &key {
+        MapKeyRepresentation::Text(text) if !quoted && (&**text == "*") => PathPatternSegment::AnyMapKey,
+        MapKeyRepresentation::Text(text) if !quoted && (&**text == "**") => PathPatternSegment::AnyDescendants,
+        _ => PathPatternSegment::MapKey(key),
+    })
+}
+
+// Such keys must be quoted
+fn is_wildcard(key: &str) -> bool {
+    (key == "*") || (key == "**")
+}
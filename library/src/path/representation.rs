@@ -1,13 +1,16 @@
-use super::{super::normal::*, path::*, segment::*};
+use super::{super::normal::*, errors::*, key_representation::*, path::*, segment::*};
 
 use {
     kutil::{
         cli::depict::*,
-        std::{iter::*, immutable::*},
+        std::{immutable::*, iter::*},
     },
     std::{
         fmt::{self, Write},
         io,
+        iter::*,
+        slice,
+        str::{CharIndices, FromStr},
     },
 };
 
@@ -18,10 +21,13 @@ use {
 /// Path between two [Variant] nodes.
 ///
 /// This type does not keep the references to the variants. For a version that does, see [Path].
+///
+/// Its [Display](fmt::Display) syntax, e.g. `a.b[0]."c d".10i64`, can be parsed back with
+/// [FromStr]. See [MapKeyRepresentation] for the syntax of map keys.
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct PathRepresentation {
     /// Path segments.
-    pub segments: Vec<PathSegment<ByteString>>,
+    pub segments: Vec<PathSegment<MapKeyRepresentation>>,
 }
 
 impl PathRepresentation {
@@ -50,9 +56,18 @@ impl PathRepresentation {
         self.segments.push(PathSegment::ListIndex(index));
     }
 
-    /// Push a new map key path segment.
+    /// Push a new text map key path segment.
     pub fn push_map_key(&mut self, key: ByteString) {
-        self.segments.push(PathSegment::MapKey(key));
+        self.segments.push(PathSegment::MapKey(key.into()));
+    }
+
+    /// Push a new map key path segment for a [Variant] key.
+    ///
+    /// This is how map entries are addressed when walking, visiting, querying, or diffing
+    /// variants. Text keys are kept as is and other keys by their [Display](fmt::Display)
+    /// representation (see [MapKeyRepresentation]).
+    pub fn push_variant_key<AnnotatedT>(&mut self, key: &Variant<AnnotatedT>) {
+        self.segments.push(PathSegment::MapKey(key.into()));
     }
 
     /// Extend this path with another path.
@@ -61,6 +76,46 @@ impl PathRepresentation {
     }
 }
 
+impl FromStr for PathRepresentation {
+    type Err = MalformedPathError;
+
+    fn from_str(representation: &str) -> Result<Self, Self::Err> {
+        let mut path = Self::default();
+        let mut chars = representation.char_indices().peekable();
+
+        while let Some((position, c)) = chars.peek().cloned() {
+            match c {
+                '[' => {
+                    chars.next();
+                    path.push_list_index(parse_path_list_index(&mut chars, position + 1)?);
+                }
+
+                '.' if !path.segments.is_empty() => {
+                    chars.next();
+                    path.segments.push(PathSegment::MapKey(parse_path_map_key(&mut chars, position + 1)?));
+                }
+
+                _ if path.segments.is_empty() => {
+                    path.segments.push(PathSegment::MapKey(parse_path_map_key(&mut chars, position)?));
+                }
+
+                _ => return Err(MalformedPathError::new(format!("expected '.' or '[', found {:?}", c), position)),
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+impl<'own> IntoIterator for &'own PathRepresentation {
+    type Item = &'own PathSegment<MapKeyRepresentation>;
+    type IntoIter = slice::Iter<'own, PathSegment<MapKeyRepresentation>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments.iter()
+    }
+}
+
 impl Depict for PathRepresentation {
     fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
     where
@@ -91,3 +146,90 @@ impl fmt::Display for PathRepresentation {
         Ok(())
     }
 }
+
+// Utils
+
+pub(crate) fn parse_path_list_index(
+    chars: &mut Peekable<CharIndices>,
+    position: usize,
+) -> Result<usize, MalformedPathError> {
+    let mut index = String::default();
+    loop {
+        match chars.next() {
+            Some((_, ']')) => break,
+            Some((_, c)) if c.is_ascii_digit() => index.push(c),
+            Some((position, c)) => {
+                return Err(MalformedPathError::new(format!("expected digit or ']', found {:?}", c), position));
+            }
+            None => return Err(MalformedPathError::new("unterminated list index".into(), position)),
+        }
+    }
+
+    index.parse().map_err(|_| MalformedPathError::new("invalid list index".into(), position))
+}
+
+pub(crate) fn parse_path_map_key(
+    chars: &mut Peekable<CharIndices>,
+    position: usize,
+) -> Result<MapKeyRepresentation, MalformedPathError> {
+    if chars.next_if(|(_, c)| *c == '"').is_some() {
+        return Ok(MapKeyRepresentation::Text(parse_quoted(chars, '"', position)?.into()));
+    }
+
+    if chars.next_if(|(_, c)| *c == '`').is_some() {
+        return Ok(MapKeyRepresentation::Other(parse_quoted(chars, '`', position)?.into()));
+    }
+
+    let mut key = String::default();
+    while let Some((_, c)) = chars.next_if(|(_, c)| !matches!(c, '.' | '[')) {
+        key.push(c);
+    }
+
+    if key.is_empty() {
+        return Err(MalformedPathError::new("expected map key".into(), position));
+    }
+
+    Ok(MapKeyRepresentation::from_unquoted(key.into()))
+}
+
+// After the opening quote
+fn parse_quoted(chars: &mut Peekable<CharIndices>, quote: char, position: usize) -> Result<String, MalformedPathError> {
+    let mut key = String::default();
+    loop {
+        match chars.next() {
+            Some((_, c)) if c == quote => return Ok(key),
+            Some((position, '\\')) => key.push(parse_escape(chars, position)?),
+            Some((_, c)) => key.push(c),
+            None => return Err(MalformedPathError::new("unterminated quoted map key".into(), position)),
+        }
+    }
+}
+
+// Escapes produced by Debug for strings (and for backticks)
+fn parse_escape(chars: &mut Peekable<CharIndices>, position: usize) -> Result<char, MalformedPathError> {
+    match chars.next().map(|(_, c)| c) {
+        Some('"') => Ok('"'),
+        Some('\'') => Ok('\''),
+        Some('`') => Ok('`'),
+        Some('\\') => Ok('\\'),
+        Some('n') => Ok('\n'),
+        Some('r') => Ok('\r'),
+        Some('t') => Ok('\t'),
+        Some('0') => Ok('\0'),
+
+        Some('u') if chars.next_if(|(_, c)| *c == '{').is_some() => {
+            let mut code = String::default();
+            while let Some((_, c)) = chars.next_if(|(_, c)| *c != '}') {
+                code.push(c);
+            }
+
+            chars
+                .next()
+                .and_then(|_| u32::from_str_radix(&code, 16).ok())
+                .and_then(char::from_u32)
+                .ok_or_else(|| MalformedPathError::new("invalid unicode escape".into(), position))
+        }
+
+        _ => Err(MalformedPathError::new("invalid escape".into(), position)),
+    }
+}
@@ -1,5 +1,7 @@
+use super::{super::normal::*, key_representation::*};
+
 use {
-    kutil::{cli::depict::*, std::immutable::*},
+    kutil::cli::depict::*,
     std::{fmt, io},
 };
 
@@ -32,15 +34,7 @@ where
                 context.theme.write_delimiter(writer, ']')
             }
 
-            Self::MapKey(key) => {
-                let key = key.to_string();
-                let key = if key.contains("\"") || key.contains(char::is_whitespace) {
-                    format!("{:?}", key)
-                } else {
-                    format!("{}", key)
-                };
-                context.theme.write_string(writer, key)
-            }
+            Self::MapKey(key) => context.theme.write_string(writer, key),
         }
     }
 }
@@ -53,28 +47,17 @@ where
         match self {
             Self::ListIndex(index) => write!(formatter, "[{}]", index),
 
-            Self::MapKey(key) => {
-                let key = key.to_string();
-                if key.contains("\"") || key.contains(char::is_whitespace) {
-                    // Debug will quote the string
-                    fmt::Debug::fmt(&key, formatter)
-                } else {
-                    fmt::Display::fmt(&key, formatter)
-                }
-            }
+            Self::MapKey(key) => fmt::Display::fmt(key, formatter),
         }
     }
 }
 
-impl<KeyT> PathSegment<KeyT>
-where
-    KeyT: fmt::Display,
-{
-    /// To string keys.
-    pub fn to_string_keys(&self) -> PathSegment<ByteString> {
+impl<AnnotatedT> PathSegment<&Variant<AnnotatedT>> {
+    /// To [MapKeyRepresentation] keys.
+    pub fn to_representation(&self) -> PathSegment<MapKeyRepresentation> {
         match self {
             Self::ListIndex(index) => PathSegment::ListIndex(*index),
-            Self::MapKey(key) => PathSegment::MapKey(key.to_string().into()),
+            Self::MapKey(key) => PathSegment::MapKey((*key).into()),
         }
     }
 }
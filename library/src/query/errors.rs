@@ -0,0 +1,23 @@
+use thiserror::*;
+
+//
+// QueryError
+//
+
+/// [Query](super::expression::Query) parsing error.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("{message} at position {position}")]
+pub struct QueryError {
+    /// Message.
+    pub message: String,
+
+    /// Character position in the query.
+    pub position: usize,
+}
+
+impl QueryError {
+    /// Constructor.
+    pub fn new(message: String, position: usize) -> Self {
+        Self { message, position }
+    }
+}
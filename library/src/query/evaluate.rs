@@ -0,0 +1,302 @@
+use super::{
+    super::{diff::*, normal::*, path::*},
+    expression::*,
+    matched::*,
+};
+
+use std::cmp::*;
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Find all descendents (or self) matching a [Query], in document order.
+    pub fn query(&self, query: &Query) -> Vec<QueryMatch<'_, AnnotatedT>>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        query.evaluate(self, self, Default::default())
+    }
+}
+
+impl Query {
+    // Evaluate from a starting variant
+    fn evaluate<'own, AnnotatedT>(
+        &self,
+        variant: &'own Variant<AnnotatedT>,
+        root: &'own Variant<AnnotatedT>,
+        path: PathRepresentation,
+    ) -> Vec<QueryMatch<'own, AnnotatedT>>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let mut matches = vec![QueryMatch::new(path, variant)];
+
+        for segment in &self.segments {
+            let mut next_matches = Vec::default();
+
+            for found in matches {
+                if segment.descendant {
+                    for (relative_path, descendent) in found.variant.walk() {
+                        let mut path = found.path.clone();
+                        path.extend(relative_path);
+                        segment.select(descendent, &path, root, &mut next_matches);
+                    }
+                } else {
+                    segment.select(found.variant, &found.path, root, &mut next_matches);
+                }
+            }
+
+            matches = next_matches;
+        }
+
+        matches
+    }
+}
+
+impl QuerySegment {
+    // Select children
+    fn select<'own, AnnotatedT>(
+        &self,
+        variant: &'own Variant<AnnotatedT>,
+        path: &PathRepresentation,
+        root: &'own Variant<AnnotatedT>,
+        matches: &mut Vec<QueryMatch<'own, AnnotatedT>>,
+    ) where
+        AnnotatedT: Clone + Default,
+    {
+        for selector in &self.selectors {
+            selector.select(variant, path, root, matches);
+        }
+    }
+}
+
+impl QuerySelector {
+    // Select children
+    fn select<'own, AnnotatedT>(
+        &self,
+        variant: &'own Variant<AnnotatedT>,
+        path: &PathRepresentation,
+        root: &'own Variant<AnnotatedT>,
+        matches: &mut Vec<QueryMatch<'own, AnnotatedT>>,
+    ) where
+        AnnotatedT: Clone + Default,
+    {
+        let list_item = |index: usize, item| {
+            let mut path = path.clone();
+            path.push_list_index(index);
+            QueryMatch::new(path, item)
+        };
+
+        let map_value = |key: &Variant<AnnotatedT>, value| {
+            let mut path = path.clone();
+            path.push_variant_key(key);
+            QueryMatch::new(path, value)
+        };
+
+        match (self, variant) {
+            (Self::Name(name), Variant::Map(map)) => {
+                if let Some((key, value)) = map.inner.get_key_value(&Text::from(name.clone()).into()) {
+                    matches.push(map_value(key, value));
+                }
+            }
+
+            (Self::Wildcard, Variant::List(list)) => {
+                matches.extend(list.inner.iter().enumerate().map(|(index, item)| list_item(index, item)));
+            }
+
+            (Self::Wildcard, Variant::Map(map)) => {
+                matches.extend(map.inner.iter().map(|(key, value)| map_value(key, value)));
+            }
+
+            (Self::Index(index), Variant::List(list)) => {
+                if let Some(index) = normalize_index(*index, list.inner.len())
+                    && let Some(item) = list.inner.get(index)
+                {
+                    matches.push(list_item(index, item));
+                }
+            }
+
+            (Self::Index(index), Variant::Map(map)) => {
+                matches.extend(
+                    map.inner
+                        .iter()
+                        .filter(|(key, _)| match key {
+                            Variant::Integer(integer) => integer.inner == *index,
+                            Variant::UnsignedInteger(unsigned_integer) => {
+                                i128::from(unsigned_integer.inner) == i128::from(*index)
+                            }
+                            _ => false,
+                        })
+                        .map(|(key, value)| map_value(key, value)),
+                );
+            }
+
+            (Self::Slice { start, end, step }, Variant::List(list)) => {
+                for index in slice_indexes(*start, *end, *step, list.inner.len()) {
+                    matches.push(list_item(index, &list.inner[index]));
+                }
+            }
+
+            (Self::Filter(filter), Variant::List(list)) => {
+                for (index, item) in list.inner.iter().enumerate() {
+                    if filter.test(item, root) {
+                        matches.push(list_item(index, item));
+                    }
+                }
+            }
+
+            (Self::Filter(filter), Variant::Map(map)) => {
+                for (key, value) in map.inner.iter() {
+                    if filter.test(value, root) {
+                        matches.push(map_value(key, value));
+                    }
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+impl QueryFilter {
+    fn test<AnnotatedT>(&self, current: &Variant<AnnotatedT>, root: &Variant<AnnotatedT>) -> bool
+    where
+        AnnotatedT: Clone + Default,
+    {
+        match self {
+            Self::Or(filters) => filters.iter().any(|filter| filter.test(current, root)),
+            Self::And(filters) => filters.iter().all(|filter| filter.test(current, root)),
+            Self::Not(filter) => !filter.test(current, root),
+            Self::Exists(query) => !query.evaluate(current, root).is_empty(),
+
+            Self::Comparison { left, operator, right } => {
+                let left_literal;
+                let left = match left {
+                    QueryComparable::Literal(literal) => {
+                        left_literal = literal.to_variant();
+                        Some(&left_literal)
+                    }
+                    QueryComparable::Query(query) => query.evaluate_singular(current, root),
+                };
+
+                let right_literal;
+                let right = match right {
+                    QueryComparable::Literal(literal) => {
+                        right_literal = literal.to_variant();
+                        Some(&right_literal)
+                    }
+                    QueryComparable::Query(query) => query.evaluate_singular(current, root),
+                };
+
+                match operator {
+                    QueryComparisonOperator::Equal => equal(left, right),
+                    QueryComparisonOperator::NotEqual => !equal(left, right),
+                    QueryComparisonOperator::Less => less(left, right),
+                    QueryComparisonOperator::LessOrEqual => less(left, right) || equal(left, right),
+                    QueryComparisonOperator::Greater => less(right, left),
+                    QueryComparisonOperator::GreaterOrEqual => less(right, left) || equal(left, right),
+                }
+            }
+        }
+    }
+}
+
+impl QueryFilterQuery {
+    fn evaluate<'own, AnnotatedT>(
+        &self,
+        current: &'own Variant<AnnotatedT>,
+        root: &'own Variant<AnnotatedT>,
+    ) -> Vec<QueryMatch<'own, AnnotatedT>>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        self.query.evaluate(if self.relative { current } else { root }, root, Default::default())
+    }
+
+    // None if not exactly one match
+    fn evaluate_singular<'own, AnnotatedT>(
+        &self,
+        current: &'own Variant<AnnotatedT>,
+        root: &'own Variant<AnnotatedT>,
+    ) -> Option<&'own Variant<AnnotatedT>>
+    where
+        AnnotatedT: Clone + Default,
+    {
+        let matches = self.evaluate(current, root);
+        if matches.len() == 1 { Some(matches[0].variant) } else { None }
+    }
+}
+
+impl QueryLiteral {
+    fn to_variant<AnnotatedT>(&self) -> Variant<AnnotatedT>
+    where
+        AnnotatedT: Default,
+    {
+        match self {
+            Self::Null => Null::default().into(),
+            Self::Boolean(boolean) => (*boolean).into(),
+            Self::Integer(integer) => (*integer).into(),
+            Self::Float(float) => (*float).into(),
+            Self::Text(text) => text.clone().into(),
+        }
+    }
+}
+
+// Utils
+
+fn equal<AnnotatedT>(left: Option<&Variant<AnnotatedT>>, right: Option<&Variant<AnnotatedT>>) -> bool {
+    match (left, right) {
+        (None, None) => true,
+        (Some(left), Some(right)) => numbers_eq(left, right).unwrap_or_else(|| left == right),
+        _ => false,
+    }
+}
+
+fn less<AnnotatedT>(left: Option<&Variant<AnnotatedT>>, right: Option<&Variant<AnnotatedT>>) -> bool {
+    match (left, right) {
+        (Some(Variant::Text(left)), Some(Variant::Text(right))) => left.inner < right.inner,
+        (Some(left), Some(right)) => numbers_cmp(left, right) == Some(Ordering::Less),
+        _ => false,
+    }
+}
+
+fn normalize_index(index: i64, length: usize) -> Option<usize> {
+    if index >= 0 {
+        usize::try_from(index).ok()
+    } else {
+        length.checked_sub(usize::try_from(index.unsigned_abs()).ok()?)
+    }
+}
+
+// RFC 9535 section 2.3.4.2.2
+fn slice_indexes(start: Option<i64>, end: Option<i64>, step: Option<i64>, length: usize) -> Vec<usize> {
+    let length = length as i64;
+    let step = step.unwrap_or(1);
+    let normalize = |index: i64| if index >= 0 { index } else { length.saturating_add(index) };
+
+    let mut indexes = Vec::default();
+
+    if step > 0 {
+        let lower = normalize(start.unwrap_or(0)).clamp(0, length);
+        let upper = normalize(end.unwrap_or(length)).clamp(0, length);
+        let mut index = lower;
+        while index < upper {
+            indexes.push(index as usize);
+            let Some(next) = index.checked_add(step) else {
+                break;
+            };
+            index = next;
+        }
+    } else if step < 0 {
+        let upper = normalize(start.unwrap_or(length - 1)).clamp(-1, length - 1);
+        let lower = normalize(end.unwrap_or(-length - 1)).clamp(-1, length - 1);
+        let mut index = upper;
+        while lower < index {
+            indexes.push(index as usize);
+            let Some(next) = index.checked_add(step) else {
+                break;
+            };
+            index = next;
+        }
+    }
+
+    indexes
+}
@@ -0,0 +1,330 @@
+use super::{errors::*, parser::*};
+
+use {
+    kutil::std::immutable::*,
+    std::{fmt, str::*},
+};
+
+//
+// Query
+//
+
+/// JSONPath (RFC 9535) query.
+///
+/// Supported are name selectors (`.name`, `['name']`), wildcards (`.*`, `[*]`), index selectors
+/// (`[0]`, `[-1]`), slices (`[1:5:2]`), filters (`[?@.price < 10 && @.tags]`), and descendant
+/// segments (`..name`, `..*`, `..[0]`). Function extensions are not supported.
+///
+/// A name selector matches only a [Text](super::super::normal::Text) key with that name. Because
+/// map keys are not necessarily text, we support an extension to RFC 9535, in which index
+/// selectors apply only to arrays: an index selector on a map matches
+/// [Integer](super::super::normal::Integer) and [UnsignedInteger](super::super::normal::UnsignedInteger)
+/// keys with that value, e.g. `[10]`. Negative indexes are not counted from the end for maps.
+///
+/// Comparisons in filters compare numbers by value regardless of type. A filter query that is
+/// compared must match exactly one variant, otherwise it is considered to be nothing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Query {
+    /// Segments.
+    pub segments: Vec<QuerySegment>,
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(representation: &str) -> Result<Self, Self::Err> {
+        QueryParser::new(representation).parse()
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "$")?;
+        fmt_segments(&self.segments, formatter)
+    }
+}
+
+//
+// QuerySegment
+//
+
+/// [Query] segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuerySegment {
+    /// Whether to apply the selectors to all descendents (and self) rather than just the children.
+    pub descendant: bool,
+
+    /// Selectors.
+    pub selectors: Vec<QuerySelector>,
+}
+
+impl fmt::Display for QuerySegment {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.descendant {
+            write!(formatter, "..")?;
+        }
+
+        write!(formatter, "[")?;
+        for (index, selector) in self.selectors.iter().enumerate() {
+            if index != 0 {
+                write!(formatter, ",")?;
+            }
+            fmt::Display::fmt(selector, formatter)?;
+        }
+        write!(formatter, "]")
+    }
+}
+
+//
+// QuerySelector
+//
+
+/// [Query] selector.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuerySelector {
+    /// Map key.
+    Name(ByteString),
+
+    /// All list items or map values.
+    Wildcard,
+
+    /// List index. Negative indexes count from the end.
+    ///
+    /// As an extension, also an integer map key (see [Query]).
+    Index(i64),
+
+    /// List slice.
+    Slice {
+        /// Start.
+        start: Option<i64>,
+
+        /// End (exclusive).
+        end: Option<i64>,
+
+        /// Step.
+        step: Option<i64>,
+    },
+
+    /// List items or map values for which the filter is true.
+    Filter(QueryFilter),
+}
+
+impl fmt::Display for QuerySelector {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(formatter, "{}", QueryLiteral::Text(name.clone())),
+            Self::Wildcard => write!(formatter, "*"),
+            Self::Index(index) => write!(formatter, "{}", index),
+
+            Self::Slice { start, end, step } => {
+                if let Some(start) = start {
+                    write!(formatter, "{}", start)?;
+                }
+                write!(formatter, ":")?;
+                if let Some(end) = end {
+                    write!(formatter, "{}", end)?;
+                }
+                if let Some(step) = step {
+                    write!(formatter, ":{}", step)?;
+                }
+                Ok(())
+            }
+
+            Self::Filter(filter) => write!(formatter, "?{}", filter),
+        }
+    }
+}
+
+//
+// QueryFilter
+//
+
+/// [Query] filter expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryFilter {
+    /// True if any is true.
+    Or(Vec<QueryFilter>),
+
+    /// True if all are true.
+    And(Vec<QueryFilter>),
+
+    /// Negation.
+    Not(Box<QueryFilter>),
+
+    /// True if the query matches anything.
+    Exists(QueryFilterQuery),
+
+    /// Comparison.
+    Comparison {
+        /// Left.
+        left: QueryComparable,
+
+        /// Operator.
+        operator: QueryComparisonOperator,
+
+        /// Right.
+        right: QueryComparable,
+    },
+}
+
+impl fmt::Display for QueryFilter {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Or(filters) => fmt_filters(filters, "||", formatter),
+            Self::And(filters) => fmt_filters(filters, "&&", formatter),
+            Self::Not(filter) => write!(formatter, "!({})", filter),
+            Self::Exists(query) => fmt::Display::fmt(query, formatter),
+            Self::Comparison { left, operator, right } => write!(formatter, "{}{}{}", left, operator, right),
+        }
+    }
+}
+
+//
+// QueryFilterQuery
+//
+
+/// [Query] inside a [QueryFilter].
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryFilterQuery {
+    /// Whether the query is relative to the current variant (`@`) rather than the root (`$`).
+    pub relative: bool,
+
+    /// Query.
+    pub query: Query,
+}
+
+impl fmt::Display for QueryFilterQuery {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", if self.relative { "@" } else { "$" })?;
+        fmt_segments(&self.query.segments, formatter)
+    }
+}
+
+//
+// QueryComparable
+//
+
+/// Operand of a [QueryFilter::Comparison].
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryComparable {
+    /// Literal.
+    Literal(QueryLiteral),
+
+    /// Query.
+    Query(QueryFilterQuery),
+}
+
+impl fmt::Display for QueryComparable {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => fmt::Display::fmt(literal, formatter),
+            Self::Query(query) => fmt::Display::fmt(query, formatter),
+        }
+    }
+}
+
+//
+// QueryLiteral
+//
+
+/// [Query] literal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryLiteral {
+    /// Null.
+    Null,
+
+    /// Boolean.
+    Boolean(bool),
+
+    /// Integer.
+    Integer(i64),
+
+    /// Float.
+    Float(f64),
+
+    /// Text.
+    Text(ByteString),
+}
+
+impl fmt::Display for QueryLiteral {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => write!(formatter, "null"),
+            Self::Boolean(boolean) => write!(formatter, "{}", boolean),
+            Self::Integer(integer) => write!(formatter, "{}", integer),
+            Self::Float(float) => write!(formatter, "{:?}", float),
+
+            Self::Text(text) => {
+                write!(formatter, "'")?;
+                for c in text.chars() {
+                    match c {
+                        '\'' => write!(formatter, "\\'")?,
+                        '\\' => write!(formatter, "\\\\")?,
+                        c if c.is_control() => write!(formatter, "\\u{:04x}", c as u32)?,
+                        c => write!(formatter, "{}", c)?,
+                    }
+                }
+                write!(formatter, "'")
+            }
+        }
+    }
+}
+
+//
+// QueryComparisonOperator
+//
+
+/// [QueryFilter::Comparison] operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QueryComparisonOperator {
+    /// `==`
+    Equal,
+
+    /// `!=`
+    NotEqual,
+
+    /// `<`
+    Less,
+
+    /// `<=`
+    LessOrEqual,
+
+    /// `>`
+    Greater,
+
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl fmt::Display for QueryComparisonOperator {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::LessOrEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEqual => ">=",
+        })
+    }
+}
+
+// Utils
+
+fn fmt_segments(segments: &[QuerySegment], formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for segment in segments {
+        fmt::Display::fmt(segment, formatter)?;
+    }
+    Ok(())
+}
+
+fn fmt_filters(filters: &[QueryFilter], operator: &str, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(formatter, "(")?;
+    for (index, filter) in filters.iter().enumerate() {
+        if index != 0 {
+            write!(formatter, "{}", operator)?;
+        }
+        fmt::Display::fmt(filter, formatter)?;
+    }
+    write!(formatter, ")")
+}
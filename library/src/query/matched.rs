@@ -0,0 +1,60 @@
+use super::super::{annotate::*, normal::*, path::*};
+
+use {
+    kutil::cli::depict::*,
+    std::{fmt, io},
+};
+
+//
+// QueryMatch
+//
+
+/// [Query](super::expression::Query) match.
+#[derive(Clone, Debug)]
+pub struct QueryMatch<'own, AnnotatedT> {
+    /// Path from the root.
+    pub path: PathRepresentation,
+
+    /// Matched variant.
+    pub variant: &'own Variant<AnnotatedT>,
+}
+
+impl<'own, AnnotatedT> QueryMatch<'own, AnnotatedT> {
+    /// Constructor.
+    pub fn new(path: PathRepresentation, variant: &'own Variant<AnnotatedT>) -> Self {
+        Self { path, variant }
+    }
+
+    /// [Annotations] of the matched variant.
+    pub fn annotations(&self) -> Option<&'own Annotations>
+    where
+        AnnotatedT: Annotated,
+    {
+        self.variant.annotations()
+    }
+}
+
+impl<'own, AnnotatedT> Depict for QueryMatch<'own, AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.separate(writer)?;
+        self.path.depict(writer, context)?;
+        context.theme.write_delimiter(writer, ':')?;
+
+        // Collections are compact so that each match is a single line
+        let format = if self.variant.is_collection() { DepictionFormat::Compact } else { DepictionFormat::Optimized };
+        let context = context.child().with_separator(true).with_format(format);
+        AnnotatedDepiction::new(self.variant, AnnotatedDepictionMode::Inline).depict(writer, &context)
+    }
+}
+
+impl<'own, AnnotatedT> fmt::Display for QueryMatch<'own, AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: {}", self.path, self.variant)
+    }
+}
@@ -0,0 +1,8 @@
+mod errors;
+mod evaluate;
+mod expression;
+mod matched;
+mod parser;
+
+#[allow(unused_imports)]
+pub use {errors::*, evaluate::*, expression::*, matched::*};
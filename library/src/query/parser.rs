@@ -0,0 +1,404 @@
+use super::{errors::*, expression::*};
+
+//
+// QueryParser
+//
+
+// Recursive descent parser for RFC 9535 syntax
+pub(crate) struct QueryParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl QueryParser {
+    pub(crate) fn new(representation: &str) -> Self {
+        Self { chars: representation.chars().collect(), position: 0 }
+    }
+
+    pub(crate) fn parse(mut self) -> Result<Query, QueryError> {
+        self.expect('$')?;
+        let query = self.parse_segments()?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(query),
+            Some(c) => Err(self.error(format!("unexpected {:?}", c))),
+        }
+    }
+
+    fn parse_segments(&mut self) -> Result<Query, QueryError> {
+        let mut query = Query::default();
+
+        loop {
+            // Whitespace is allowed between segments, but we must not consume it at the end
+            let position = self.position;
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('[') => {
+                    let selectors = self.parse_bracketed_selectors()?;
+                    query.segments.push(QuerySegment { descendant: false, selectors });
+                }
+
+                Some('.') => {
+                    self.position += 1;
+                    if self.peek() == Some('.') {
+                        self.position += 1;
+                        let selectors = match self.peek() {
+                            Some('[') => self.parse_bracketed_selectors()?,
+                            _ => vec![self.parse_shorthand_selector()?],
+                        };
+                        query.segments.push(QuerySegment { descendant: true, selectors });
+                    } else {
+                        let selector = self.parse_shorthand_selector()?;
+                        query.segments.push(QuerySegment { descendant: false, selectors: vec![selector] });
+                    }
+                }
+
+                _ => {
+                    self.position = position;
+                    return Ok(query);
+                }
+            }
+        }
+    }
+
+    fn parse_shorthand_selector(&mut self) -> Result<QuerySelector, QueryError> {
+        if self.next_if('*') {
+            return Ok(QuerySelector::Wildcard);
+        }
+
+        let start = self.position;
+        while let Some(c) = self.peek() {
+            if c.is_alphabetic() || (c == '_') || !c.is_ascii() || ((self.position != start) && c.is_ascii_digit()) {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+
+        if self.position == start {
+            Err(self.error("expected name or \"*\"".into()))
+        } else {
+            Ok(QuerySelector::Name(self.chars[start..self.position].iter().collect::<String>().into()))
+        }
+    }
+
+    fn parse_bracketed_selectors(&mut self) -> Result<Vec<QuerySelector>, QueryError> {
+        self.expect('[')?;
+        let mut selectors = Vec::default();
+
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.parse_selector()?);
+            self.skip_whitespace();
+
+            if self.next_if(']') {
+                return Ok(selectors);
+            }
+
+            self.expect(',')?;
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<QuerySelector, QueryError> {
+        match self.peek() {
+            Some('\'' | '"') => Ok(QuerySelector::Name(self.parse_string()?.into())),
+
+            Some('*') => {
+                self.position += 1;
+                Ok(QuerySelector::Wildcard)
+            }
+
+            Some('?') => {
+                self.position += 1;
+                self.skip_whitespace();
+                Ok(QuerySelector::Filter(self.parse_or()?))
+            }
+
+            _ => {
+                let start = self.parse_optional_integer()?;
+                self.skip_whitespace();
+
+                if !self.next_if(':') {
+                    return match start {
+                        Some(index) => Ok(QuerySelector::Index(index)),
+                        None => Err(self.error("expected selector".into())),
+                    };
+                }
+
+                self.skip_whitespace();
+                let end = self.parse_optional_integer()?;
+                self.skip_whitespace();
+
+                let step = if self.next_if(':') {
+                    self.skip_whitespace();
+                    self.parse_optional_integer()?
+                } else {
+                    None
+                };
+
+                Ok(QuerySelector::Slice { start, end, step })
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<QueryFilter, QueryError> {
+        let mut filters = vec![self.parse_and()?];
+        loop {
+            self.skip_whitespace();
+            if self.next_str("||") {
+                self.skip_whitespace();
+                filters.push(self.parse_and()?);
+            } else {
+                break;
+            }
+        }
+        Ok(if filters.len() == 1 { filters.remove(0) } else { QueryFilter::Or(filters) })
+    }
+
+    fn parse_and(&mut self) -> Result<QueryFilter, QueryError> {
+        let mut filters = vec![self.parse_basic()?];
+        loop {
+            self.skip_whitespace();
+            if self.next_str("&&") {
+                self.skip_whitespace();
+                filters.push(self.parse_basic()?);
+            } else {
+                break;
+            }
+        }
+        Ok(if filters.len() == 1 { filters.remove(0) } else { QueryFilter::And(filters) })
+    }
+
+    fn parse_basic(&mut self) -> Result<QueryFilter, QueryError> {
+        if self.next_if('!') {
+            self.skip_whitespace();
+            return Ok(QueryFilter::Not(Box::new(self.parse_basic()?)));
+        }
+
+        if self.next_if('(') {
+            self.skip_whitespace();
+            let filter = self.parse_or()?;
+            self.skip_whitespace();
+            self.expect(')')?;
+            return Ok(filter);
+        }
+
+        let position = self.position;
+        let left = self.parse_comparable()?;
+        self.skip_whitespace();
+
+        match self.parse_comparison_operator() {
+            Some(operator) => {
+                self.skip_whitespace();
+                let right = self.parse_comparable()?;
+                Ok(QueryFilter::Comparison { left, operator, right })
+            }
+
+            None => match left {
+                QueryComparable::Query(query) => Ok(QueryFilter::Exists(query)),
+                QueryComparable::Literal(_) => {
+                    self.position = position;
+                    Err(self.error("expected query or comparison".into()))
+                }
+            },
+        }
+    }
+
+    fn parse_comparable(&mut self) -> Result<QueryComparable, QueryError> {
+        match self.peek() {
+            Some(c @ ('@' | '$')) => {
+                self.position += 1;
+                let query = self.parse_segments()?;
+                Ok(QueryComparable::Query(QueryFilterQuery { relative: c == '@', query }))
+            }
+
+            Some('\'' | '"') => Ok(QueryComparable::Literal(QueryLiteral::Text(self.parse_string()?.into()))),
+
+            Some(c) if (c == '-') || c.is_ascii_digit() => Ok(QueryComparable::Literal(self.parse_number()?)),
+
+            _ => {
+                for (word, literal) in [
+                    ("true", QueryLiteral::Boolean(true)),
+                    ("false", QueryLiteral::Boolean(false)),
+                    ("null", QueryLiteral::Null),
+                ] {
+                    if self.next_str(word) {
+                        return Ok(QueryComparable::Literal(literal));
+                    }
+                }
+
+                Err(self.error("expected query or literal".into()))
+            }
+        }
+    }
+
+    fn parse_comparison_operator(&mut self) -> Option<QueryComparisonOperator> {
+        for (operator, comparison_operator) in [
+            ("==", QueryComparisonOperator::Equal),
+            ("!=", QueryComparisonOperator::NotEqual),
+            ("<=", QueryComparisonOperator::LessOrEqual),
+            (">=", QueryComparisonOperator::GreaterOrEqual),
+            ("<", QueryComparisonOperator::Less),
+            (">", QueryComparisonOperator::Greater),
+        ] {
+            if self.next_str(operator) {
+                return Some(comparison_operator);
+            }
+        }
+        None
+    }
+
+    fn parse_string(&mut self) -> Result<String, QueryError> {
+        let quote = self.next().ok_or_else(|| self.error("expected string".into()))?;
+        let mut string = String::default();
+
+        loop {
+            match self.next() {
+                None => return Err(self.error("unterminated string".into())),
+
+                Some(c) if c == quote => return Ok(string),
+
+                Some('\\') => match self.next() {
+                    Some('b') => string.push('\u{08}'),
+                    Some('f') => string.push('\u{0C}'),
+                    Some('n') => string.push('\n'),
+                    Some('r') => string.push('\r'),
+                    Some('t') => string.push('\t'),
+                    Some('u') => string.push(self.parse_unicode_escape()?),
+                    Some(c @ ('/' | '\\' | '\'' | '"')) => string.push(c),
+                    _ => return Err(self.error("invalid escape".into())),
+                },
+
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, QueryError> {
+        let high = self.parse_hex4()?;
+        if (0xD800..0xDC00).contains(&high) {
+            // Surrogate pair
+            if self.next_str("\\u") {
+                let low = self.parse_hex4()?;
+                if (0xDC00..0xE000).contains(&low) {
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    return char::from_u32(code).ok_or_else(|| self.error("invalid escape".into()));
+                }
+            }
+            return Err(self.error("invalid surrogate pair".into()));
+        }
+        char::from_u32(high).ok_or_else(|| self.error("invalid escape".into()))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, QueryError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self.next().and_then(|c| c.to_digit(16)).ok_or_else(|| self.error("invalid escape".into()))?;
+            code = (code << 4) | digit;
+        }
+        Ok(code)
+    }
+
+    fn parse_number(&mut self) -> Result<QueryLiteral, QueryError> {
+        let start = self.position;
+        self.next_if('-');
+        self.skip_digits();
+
+        let mut float = false;
+        if self.next_if('.') {
+            float = true;
+            self.skip_digits();
+        }
+        if self.next_if('e') || self.next_if('E') {
+            float = true;
+            if !self.next_if('-') {
+                self.next_if('+');
+            }
+            self.skip_digits();
+        }
+
+        let number: String = self.chars[start..self.position].iter().collect();
+        let literal = if float {
+            number.parse().ok().map(QueryLiteral::Float)
+        } else {
+            number.parse().ok().filter(is_exact_integer).map(QueryLiteral::Integer)
+        };
+
+        literal.ok_or_else(|| QueryError::new(format!("invalid number: {:?}", number), start))
+    }
+
+    fn parse_optional_integer(&mut self) -> Result<Option<i64>, QueryError> {
+        let start = self.position;
+        self.next_if('-');
+        self.skip_digits();
+
+        if self.position == start {
+            return Ok(None);
+        }
+
+        let integer: String = self.chars[start..self.position].iter().collect();
+        match integer.parse().ok().filter(is_exact_integer) {
+            Some(integer) => Ok(Some(integer)),
+            None => Err(QueryError::new(format!("invalid integer: {:?}", integer), start)),
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn next_if(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next_str(&mut self, string: &str) -> bool {
+        let end = self.position + string.chars().count();
+        if (end <= self.chars.len()) && self.chars[self.position..end].iter().copied().eq(string.chars()) {
+            self.position = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), QueryError> {
+        if self.next_if(c) { Ok(()) } else { Err(self.error(format!("expected {:?}", c))) }
+    }
+
+    fn error(&self, message: String) -> QueryError {
+        QueryError::new(message, self.position)
+    }
+}
+
+// Utils
+
+// RFC 9535 section 2.1: integers must be within the I-JSON range
+const MAX_EXACT_INTEGER: i64 = (1 << 53) - 1;
+
+fn is_exact_integer(integer: &i64) -> bool {
+    (-MAX_EXACT_INTEGER..=MAX_EXACT_INTEGER).contains(integer)
+}
@@ -1,6 +1,8 @@
 use super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
+    reference::*,
     resolve::*,
 };
 
@@ -28,3 +30,51 @@ where
         }))
     }
 }
+
+impl<InnerT, AnnotatedT> ResolveRef<Annotate<InnerT, AnnotatedT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Default,
+    Variant<AnnotatedT>: ResolveRef<InnerT, AnnotatedT>,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Annotate<InnerT, AnnotatedT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(self.resolve_ref_with_errors(errors)?.map(|inner| {
+            let annotate = Annotate::new(inner);
+            match self.annotations() {
+                Some(annotations) => annotate.with_annotations(annotations.clone()),
+                None => annotate,
+            }
+        }))
+    }
+}
+
+impl<InnerT, ContextT, AnnotatedT> ResolveWithContext<Annotate<InnerT, AnnotatedT>, ContextT, AnnotatedT>
+    for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Default,
+    Variant<AnnotatedT>: ResolveWithContext<InnerT, ContextT, AnnotatedT>,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Annotate<InnerT, AnnotatedT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let annotations = self.annotations().cloned();
+        Ok(self.resolve_with_context(context, errors)?.map(|inner| {
+            let annotate = Annotate::new(inner);
+            match annotations {
+                Some(annotations) => annotate.with_annotations(annotations),
+                None => annotate,
+            }
+        }))
+    }
+}
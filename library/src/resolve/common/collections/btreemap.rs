@@ -1,8 +1,10 @@
 use super::super::{
     super::{
         super::{annotate::*, normal::*},
+        context::*,
         errors::*,
         iterator::*,
+        reference::*,
         resolve::*,
     },
     iterate::*,
@@ -37,3 +39,57 @@ where
         Ok(Some(resolved))
     }
 }
+
+impl<KeyT, ValueT, AnnotatedT> ResolveRef<BTreeMap<KeyT, ValueT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    KeyT: Hash + Eq + Ord,
+    Variant<AnnotatedT>: ResolveRef<KeyT, AnnotatedT> + ResolveRef<ValueT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<BTreeMap<KeyT, ValueT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = BTreeMap::default();
+
+        if let Some(mut iterator) = ResolvingKeyValuePairRefIterator::new_from(self, errors)? {
+            while let Some((key, value)) = iterator.resolve_next(errors)? {
+                resolved.insert(key, value);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
+
+impl<KeyT, ValueT, ContextT, AnnotatedT> ResolveWithContext<BTreeMap<KeyT, ValueT>, ContextT, AnnotatedT>
+    for Variant<AnnotatedT>
+where
+    KeyT: Hash + Eq + Ord,
+    Variant<AnnotatedT>:
+        ResolveWithContext<KeyT, ContextT, AnnotatedT> + ResolveWithContext<ValueT, ContextT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<BTreeMap<KeyT, ValueT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = BTreeMap::default();
+
+        if let Some(mut iterator) = ResolvingKeyValuePairIterator::new_from(self, errors)? {
+            while let Some((key, value)) = iterator.resolve_next_with_context(context, errors)? {
+                resolved.insert(key, value);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
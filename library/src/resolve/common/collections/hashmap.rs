@@ -1,8 +1,10 @@
 use super::super::{
     super::{
         super::{annotate::*, normal::*},
+        context::*,
         errors::*,
         iterator::*,
+        reference::*,
         resolve::*,
     },
     iterate::*,
@@ -39,3 +41,60 @@ where
         Ok(Some(resolved))
     }
 }
+
+impl<KeyT, ValueT, BuildHasherT, AnnotatedT> ResolveRef<HashMap<KeyT, ValueT, BuildHasherT>, AnnotatedT>
+    for Variant<AnnotatedT>
+where
+    KeyT: Hash + Eq,
+    Variant<AnnotatedT>: ResolveRef<KeyT, AnnotatedT> + ResolveRef<ValueT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+    BuildHasherT: BuildHasher + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<HashMap<KeyT, ValueT, BuildHasherT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = HashMap::default();
+
+        if let Some(mut iterator) = ResolvingKeyValuePairRefIterator::new_from(self, errors)? {
+            while let Some((key, value)) = iterator.resolve_next(errors)? {
+                resolved.insert(key, value);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
+
+impl<KeyT, ValueT, BuildHasherT, ContextT, AnnotatedT>
+    ResolveWithContext<HashMap<KeyT, ValueT, BuildHasherT>, ContextT, AnnotatedT> for Variant<AnnotatedT>
+where
+    KeyT: Hash + Eq,
+    Variant<AnnotatedT>:
+        ResolveWithContext<KeyT, ContextT, AnnotatedT> + ResolveWithContext<ValueT, ContextT, AnnotatedT>,
+    AnnotatedT: 'static + Annotated + Clone + Default,
+    BuildHasherT: BuildHasher + Default,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<HashMap<KeyT, ValueT, BuildHasherT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = HashMap::default();
+
+        if let Some(mut iterator) = ResolvingKeyValuePairIterator::new_from(self, errors)? {
+            while let Some((key, value)) = iterator.resolve_next_with_context(context, errors)? {
+                resolved.insert(key, value);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
@@ -1,4 +1,4 @@
-use super::super::super::{super::normal::*, errors::*, resolve::*};
+use super::super::super::{super::normal::*, context::*, errors::*, reference::*, resolve::*};
 
 use kutil::std::error::*;
 
@@ -30,3 +30,60 @@ where
         )
     }
 }
+
+impl<'own, FirstT, SecondT, AnnotatedT> Resolve<(FirstT, SecondT), AnnotatedT>
+    for (&'own Variant<AnnotatedT>, &'own Variant<AnnotatedT>)
+where
+    Variant<AnnotatedT>: ResolveRef<FirstT, AnnotatedT> + ResolveRef<SecondT, AnnotatedT>,
+{
+    fn resolve_with_errors<ErrorRecipientT>(
+        self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<(FirstT, SecondT), AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let first = self.0.resolve_ref_with_errors(errors)?;
+        let second = self.1.resolve_ref_with_errors(errors)?;
+
+        Ok(
+            if let Some(first) = first
+                && let Some(second) = second
+            {
+                Some((first, second))
+            } else {
+                None
+            },
+        )
+    }
+}
+
+impl<FirstT, SecondT, ContextT, AnnotatedT> ResolveWithContext<(FirstT, SecondT), ContextT, AnnotatedT>
+    for (Variant<AnnotatedT>, Variant<AnnotatedT>)
+where
+    Variant<AnnotatedT>:
+        ResolveWithContext<FirstT, ContextT, AnnotatedT> + ResolveWithContext<SecondT, ContextT, AnnotatedT>,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<(FirstT, SecondT), AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let first = self.0.resolve_with_context(context, errors)?;
+        let second = self.1.resolve_with_context(context, errors)?;
+
+        Ok(
+            if let Some(first) = first
+                && let Some(second) = second
+            {
+                Some((first, second))
+            } else {
+                None
+            },
+        )
+    }
+}
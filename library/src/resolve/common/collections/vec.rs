@@ -1,8 +1,10 @@
 use super::super::{
     super::{
         super::{annotate::*, normal::*},
+        context::*,
         errors::*,
         iterator::*,
+        reference::*,
         resolve::*,
     },
     iterate::*,
@@ -30,3 +32,53 @@ where
         Ok(Some(resolved))
     }
 }
+
+impl<ItemT, AnnotatedT> ResolveRef<Vec<ItemT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveRef<ItemT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Vec<ItemT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = Vec::default();
+
+        if let Some(mut iterator) = ResolvingVariantRefIterator::new_from(self, errors)? {
+            while let Some(item) = iterator.resolve_next(errors)? {
+                resolved.push(item);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
+
+impl<ItemT, ContextT, AnnotatedT> ResolveWithContext<Vec<ItemT>, ContextT, AnnotatedT> for Variant<AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveWithContext<ItemT, ContextT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Vec<ItemT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        let mut resolved = Vec::default();
+
+        if let Some(mut iterator) = ResolvingVariantIterator::new_from(self, errors)? {
+            while let Some(item) = iterator.resolve_next_with_context(context, errors)? {
+                resolved.push(item);
+            }
+        }
+
+        Ok(Some(resolved))
+    }
+}
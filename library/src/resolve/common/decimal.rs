@@ -0,0 +1,99 @@
+use super::super::{
+    super::{annotate::*, normal::*},
+    context::*,
+    errors::*,
+    reference::*,
+    resolve::*,
+};
+
+use kutil::std::error::*;
+
+#[cfg(feature = "rust_decimal")]
+impl<AnnotatedT> Resolve<rust_decimal::Decimal, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_with_errors<ErrorRecipientT>(
+        self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<rust_decimal::Decimal, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_decimal(&self, errors)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<AnnotatedT> Resolve<bigdecimal::BigDecimal, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_with_errors<ErrorRecipientT>(
+        self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<bigdecimal::BigDecimal, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_decimal(&self, errors)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<AnnotatedT> ResolveRef<rust_decimal::Decimal, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<rust_decimal::Decimal, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_decimal(self, errors)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<AnnotatedT> ResolveRef<bigdecimal::BigDecimal, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<bigdecimal::BigDecimal, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_decimal(self, errors)
+    }
+}
+#[cfg(feature = "rust_decimal")]
+impl ContextFree for rust_decimal::Decimal {}
+
+#[cfg(feature = "bigdecimal")]
+impl ContextFree for bigdecimal::BigDecimal {}
+
+// Utils
+
+fn resolve_decimal<'own, DecimalT, AnnotatedT, ErrorRecipientT>(
+    variant: &'own Variant<AnnotatedT>,
+    errors: &mut ErrorRecipientT,
+) -> ResolveResult<DecimalT, AnnotatedT>
+where
+    &'own Variant<AnnotatedT>: TryInto<DecimalT, Error = ConversionError<AnnotatedT>>,
+    AnnotatedT: Annotated + Clone + Default,
+    ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+{
+    Ok(match variant.try_into() {
+        Ok(decimal) => Some(decimal),
+
+        Err(error) => {
+            errors.give(error.with_annotations_from(variant))?;
+            None
+        }
+    })
+}
@@ -1,6 +1,8 @@
 use super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
+    reference::*,
     resolve::*,
 };
 
@@ -14,6 +16,20 @@ pub fn resolve_from_str<FromStrT, AnnotatedT, ErrorRecipientT>(
     variant: Variant<AnnotatedT>,
     errors: &mut ErrorRecipientT,
 ) -> ResolveResult<FromStrT, AnnotatedT>
+where
+    FromStrT: FromStr,
+    FromStrT::Err: fmt::Display,
+    AnnotatedT: Annotated + Clone + Default,
+    ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+{
+    resolve_ref_from_str(&variant, errors)
+}
+
+/// Resolve a [Variant] reference into a [FromStr].
+pub fn resolve_ref_from_str<FromStrT, AnnotatedT, ErrorRecipientT>(
+    variant: &Variant<AnnotatedT>,
+    errors: &mut ErrorRecipientT,
+) -> ResolveResult<FromStrT, AnnotatedT>
 where
     FromStrT: FromStr,
     FromStrT::Err: fmt::Display,
@@ -26,20 +42,20 @@ where
 
             Err(error) => {
                 errors.give(
-                    MalformedError::new(tynm::type_name::<FromStrT>(), error.to_string()).with_annotations_from(&text),
+                    MalformedError::new(tynm::type_name::<FromStrT>(), error.to_string()).with_annotations_from(text),
                 )?;
                 None
             }
         },
 
         _ => {
-            errors.give(IncompatibleVariantTypeError::new_from(&variant, &["text"]))?;
+            errors.give(IncompatibleVariantTypeError::new_from(variant, &["text"]))?;
             None
         }
     })
 }
 
-/// Implement [Resolve] for a [FromStr].
+/// Implement [Resolve], [ResolveRef], and [ContextFree] for a [FromStr].
 #[macro_export]
 macro_rules! impl_resolve_from_str {
     ( $type:ident $(,)? ) => {
@@ -57,6 +73,23 @@ macro_rules! impl_resolve_from_str {
                 $crate::resolve::resolve_from_str(self, errors)
             }
         }
+
+        impl<AnnotatedT> $crate::resolve::ResolveRef<$type, AnnotatedT> for $crate::normal::Variant<AnnotatedT>
+        where
+            AnnotatedT: $crate::annotate::Annotated + ::std::clone::Clone + ::std::default::Default,
+        {
+            fn resolve_ref_with_errors<ErrorRecipientT>(
+                &self,
+                errors: &mut ErrorRecipientT,
+            ) -> $crate::resolve::ResolveResult<$type, AnnotatedT>
+            where
+                ErrorRecipientT: ::kutil::std::error::ErrorRecipient<$crate::resolve::ResolveError<AnnotatedT>>,
+            {
+                $crate::resolve::resolve_ref_from_str(self, errors)
+            }
+        }
+
+        impl $crate::resolve::ContextFree for $type {}
     };
 }
 
@@ -96,6 +129,25 @@ where
     }
 }
 
+impl<InnerT, AnnotatedT> ResolveRef<ResolveFromStr<InnerT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    InnerT: FromStr,
+    InnerT::Err: fmt::Display,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolveFromStr<InnerT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_ref_from_str(self, errors).map(|resolved| resolved.map(ResolveFromStr::new))
+    }
+}
+
+impl<InnerT> ContextFree for ResolveFromStr<InnerT> {}
+
 impl<InnerT> AsRef<InnerT> for ResolveFromStr<InnerT> {
     fn as_ref(&self) -> &InnerT {
         &self.inner
@@ -1,7 +1,9 @@
 use super::super::super::{
     super::{annotate::*, kv::*, normal::*},
+    context::*,
     errors::*,
     iterator::*,
+    reference::*,
     resolve::*,
 };
 
@@ -79,3 +81,109 @@ where
         }
     }
 }
+
+impl<'own, KeyT, ValueT, ContextT, AnnotatedT> ResolvingIteratorWithContext<(KeyT, ValueT), ContextT, AnnotatedT>
+    for ResolvingKeyValuePairIterator<'own, AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveWithContext<KeyT, ContextT, AnnotatedT>,
+    Variant<AnnotatedT>: ResolveWithContext<ValueT, ContextT, AnnotatedT>,
+    AnnotatedT: Annotated + Default,
+    ContextT: ?Sized,
+{
+    fn resolve_next_with_context<ErrorRecipientT>(
+        &mut self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<(KeyT, ValueT), AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        // Repeat until we get a non-error
+        loop {
+            match self.inner.next() {
+                Ok(next) => {
+                    return Ok(match next {
+                        Some(pair) => pair.resolve_with_context(context, errors)?,
+                        None => None,
+                    });
+                }
+
+                Err((error, cause)) => errors.give(error.with_annotations_from(&cause))?,
+            }
+        }
+    }
+}
+
+//
+// ResolvingKeyValuePairRefIterator
+//
+
+/// Resolves a [KeyValuePairIterator] by reference, one key-value pair at a time.
+///
+/// Both keys and values are resolved.
+///
+/// Note that the implementation relies on `dyn` to support different [KeyValuePairIterator]
+/// implementations.
+///
+/// Useful for implementing [ResolveRef] for map-like collections, such as
+/// [HashMap](std::collections::HashMap).
+pub struct ResolvingKeyValuePairRefIterator<'own, AnnotatedT> {
+    /// Inner key-value pair iterator.
+    pub inner: Box<dyn KeyValuePairIterator<AnnotatedT> + 'own>,
+}
+
+impl<'own, AnnotatedT> ResolvingKeyValuePairRefIterator<'own, AnnotatedT> {
+    /// Constructor.
+    pub fn new(inner: Box<dyn KeyValuePairIterator<AnnotatedT> + 'own>) -> Self {
+        Self { inner }
+    }
+
+    /// Constructor.
+    pub fn new_from<ErrorRecipientT>(
+        variant: &'own Variant<AnnotatedT>,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Self, AnnotatedT>
+    where
+        AnnotatedT: 'own + Annotated + Clone + Default,
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        match variant.key_value_iterator() {
+            Some(iterator) => Ok(Some(Self::new(iterator))),
+
+            None => {
+                errors.give(IncompatibleVariantTypeError::new_from(variant, &["map", "list"]))?;
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<'own, KeyT, ValueT, AnnotatedT> ResolvingIterator<(KeyT, ValueT), AnnotatedT>
+    for ResolvingKeyValuePairRefIterator<'own, AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveRef<KeyT, AnnotatedT>,
+    Variant<AnnotatedT>: ResolveRef<ValueT, AnnotatedT>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_next<ErrorRecipientT>(
+        &mut self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<(KeyT, ValueT), AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        // Repeat until we get a non-error
+        loop {
+            match self.inner.next() {
+                Ok(next) => {
+                    return Ok(match next {
+                        Some(pair) => pair.resolve_with_errors(errors)?,
+                        None => None,
+                    });
+                }
+
+                Err((error, cause)) => errors.give(error.with_annotations_from(cause))?,
+            }
+        }
+    }
+}
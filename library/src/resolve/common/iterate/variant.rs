@@ -1,11 +1,16 @@
 use super::super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
     iterator::*,
+    reference::*,
     resolve::*,
 };
 
-use {kutil::std::error::*, std::vec};
+use {
+    kutil::std::error::*,
+    std::{slice, vec},
+};
 
 //
 // ResolvingVariantIterator
@@ -78,3 +83,99 @@ where
         })
     }
 }
+
+impl<ResolvedT, ContextT, InnerT, AnnotatedT> ResolvingIteratorWithContext<ResolvedT, ContextT, AnnotatedT>
+    for ResolvingVariantIterator<InnerT, AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveWithContext<ResolvedT, ContextT, AnnotatedT>,
+    InnerT: Iterator<Item = Variant<AnnotatedT>>,
+    ContextT: ?Sized,
+{
+    fn resolve_next_with_context<ErrorRecipientT>(
+        &mut self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(match self.inner.next() {
+            Some(next) => next.resolve_with_context(context, errors)?,
+            None => None,
+        })
+    }
+}
+
+//
+// ResolvingVariantRefIterator
+//
+
+/// Resolves an [Iterator] of [Variant] references, one item at a time.
+///
+/// Can be used directly on a `&`[List].
+///
+/// Useful for implementing [ResolveRef] for list-like collections, such as [Vec].
+pub struct ResolvingVariantRefIterator<'own, InnerT, AnnotatedT>
+where
+    InnerT: Iterator<Item = &'own Variant<AnnotatedT>>,
+    AnnotatedT: 'own,
+{
+    /// Inner.
+    pub inner: InnerT,
+}
+
+impl<'own, InnerT, AnnotatedT> ResolvingVariantRefIterator<'own, InnerT, AnnotatedT>
+where
+    InnerT: Iterator<Item = &'own Variant<AnnotatedT>>,
+{
+    /// Constructor.
+    pub fn new(inner: InnerT) -> Self {
+        Self { inner }
+    }
+
+    /// Constructor.
+    pub fn new_for<IterableT>(iterable: IterableT) -> Self
+    where
+        IterableT: IntoIterator<IntoIter = InnerT>,
+    {
+        Self::new(iterable.into_iter())
+    }
+}
+
+impl<'own, AnnotatedT> ResolvingVariantRefIterator<'own, slice::Iter<'own, Variant<AnnotatedT>>, AnnotatedT> {
+    /// Constructor.
+    pub fn new_from<ErrorRecipientT>(
+        variant: &'own Variant<AnnotatedT>,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Self, AnnotatedT>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        match variant {
+            Variant::List(list) => return Ok(Some(Self::new(list.inner.iter()))),
+
+            _ => errors.give(IncompatibleVariantTypeError::new_from(variant, &["list"]))?,
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'own, ResolvedT, InnerT, AnnotatedT> ResolvingIterator<ResolvedT, AnnotatedT>
+    for ResolvingVariantRefIterator<'own, InnerT, AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveRef<ResolvedT, AnnotatedT>,
+    InnerT: Iterator<Item = &'own Variant<AnnotatedT>>,
+    AnnotatedT: 'own,
+{
+    fn resolve_next<ErrorRecipientT>(&mut self, errors: &mut ErrorRecipientT) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(match self.inner.next() {
+            Some(next) => next.resolve_ref_with_errors(errors)?,
+            None => None,
+        })
+    }
+}
@@ -1,5 +1,7 @@
 mod annotate;
 mod collections;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+mod decimal;
 mod from_str;
 mod iterate;
 mod native;
@@ -1,6 +1,8 @@
 use super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
+    reference::*,
     resolve::*,
 };
 
@@ -14,11 +16,13 @@ use {
 #[duplicate_item(
   ResolvedT;
   [isize];
+  [i128];
   [i64];
   [i32];
   [i16];
   [i8];
   [usize];
+  [u128];
   [u64];
   [u32];
   [u16];
@@ -51,6 +55,72 @@ where
     }
 }
 
+#[duplicate_item(
+  ResolvedT;
+  [isize];
+  [i128];
+  [i64];
+  [i32];
+  [i16];
+  [i8];
+  [usize];
+  [u128];
+  [u64];
+  [u32];
+  [u16];
+  [u8];
+  [f64];
+  [f32];
+  [bool];
+  [ByteString];
+  [String];
+  [Bytes];
+)]
+impl<AnnotatedT> ResolveRef<ResolvedT, AnnotatedT> for Variant<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(match self.try_into() {
+            Ok(native) => Some(native),
+
+            Err(error) => {
+                errors.give(error.with_annotations_from(self))?;
+                None
+            }
+        })
+    }
+}
+
+#[duplicate_item(
+  ResolvedT;
+  [isize];
+  [i128];
+  [i64];
+  [i32];
+  [i16];
+  [i8];
+  [usize];
+  [u128];
+  [u64];
+  [u32];
+  [u16];
+  [u8];
+  [f64];
+  [f32];
+  [bool];
+  [ByteString];
+  [String];
+  [Bytes];
+)]
+impl ContextFree for ResolvedT {}
+
 // Failed attempt at blanket generic:
 //
 // impl<'own, ResolvedT, ContextT, ErrorT> Resolve<ResolvedT, ContextT, ErrorT> for Value
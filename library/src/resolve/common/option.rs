@@ -1,4 +1,4 @@
-use super::super::{super::normal::*, errors::*, resolve::*};
+use super::super::{super::normal::*, context::*, errors::*, reference::*, resolve::*};
 
 use kutil::std::error::*;
 
@@ -19,3 +19,36 @@ where
         Ok(Some(self.resolve_with_errors(errors)?))
     }
 }
+
+impl<OptionalT, AnnotatedT> ResolveRef<Option<OptionalT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveRef<OptionalT, AnnotatedT>,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Option<OptionalT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(Some(self.resolve_ref_with_errors(errors)?))
+    }
+}
+
+impl<OptionalT, ContextT, AnnotatedT> ResolveWithContext<Option<OptionalT>, ContextT, AnnotatedT>
+    for Variant<AnnotatedT>
+where
+    Variant<AnnotatedT>: ResolveWithContext<OptionalT, ContextT, AnnotatedT>,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Option<OptionalT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        Ok(Some(self.resolve_with_context(context, errors)?))
+    }
+}
@@ -1,7 +1,9 @@
 use super::{
     super::{
         super::{annotate::*, normal::*},
+        context::*,
         errors::*,
+        reference::*,
         resolve::*,
     },
     from_str::*,
@@ -49,6 +51,24 @@ where
     }
 }
 
+impl<InnerT, ParseStrT, AnnotatedT> ResolveRef<ResolveParseStr<InnerT, ParseStrT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    ParseStrT: ParseStr<InnerT>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolveParseStr<InnerT, ParseStrT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_ref_from_str(self, errors)
+    }
+}
+
+impl<InnerT, ParseStrT> ContextFree for ResolveParseStr<InnerT, ParseStrT> {}
+
 impl<InnerT, ParseStrT> AsRef<InnerT> for ResolveParseStr<InnerT, ParseStrT> {
     fn as_ref(&self) -> &InnerT {
         &self.inner
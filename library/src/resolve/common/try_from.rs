@@ -1,6 +1,8 @@
 use super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
+    reference::*,
     resolve::*,
 };
 
@@ -49,6 +51,43 @@ where
     })
 }
 
+/// Resolve a [Variant] reference into a [TryFrom] via an intermediate.
+pub fn resolve_ref_try_from<'own, TryFromT, IntermediateT, AnnotatedT, ErrorRecipientT>(
+    variant: &'own Variant<AnnotatedT>,
+    errors: &mut ErrorRecipientT,
+) -> ResolveResult<TryFromT, AnnotatedT>
+where
+    &'own Variant<AnnotatedT>: TryInto<IntermediateT>,
+    <&'own Variant<AnnotatedT> as TryInto<IntermediateT>>::Error: fmt::Display,
+    TryFromT: TryFrom<IntermediateT>,
+    TryFromT::Error: fmt::Display,
+    AnnotatedT: Annotated + Clone + Default,
+    ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+{
+    let intermediate: IntermediateT = match variant.try_into() {
+        Ok(intermediate) => intermediate,
+
+        Err(error) => {
+            errors.give(
+                MalformedError::new(tynm::type_name::<IntermediateT>(), error.to_string())
+                    .with_annotations_from(variant),
+            )?;
+            return Ok(None);
+        }
+    };
+
+    Ok(match intermediate.try_into() {
+        Ok(resolved) => Some(resolved),
+
+        Err(error) => {
+            errors.give(
+                MalformedError::new(tynm::type_name::<TryFromT>(), error.to_string()).with_annotations_from(variant),
+            )?;
+            None
+        }
+    })
+}
+
 //
 // ResolveTryFrom
 //
@@ -90,6 +129,28 @@ where
     }
 }
 
+impl<InnerT, IntermediateT, AnnotatedT> ResolveRef<ResolveTryFrom<InnerT, IntermediateT>, AnnotatedT>
+    for Variant<AnnotatedT>
+where
+    for<'own> &'own Variant<AnnotatedT>: TryInto<IntermediateT>,
+    for<'own> <&'own Variant<AnnotatedT> as TryInto<IntermediateT>>::Error: fmt::Display,
+    InnerT: TryFrom<IntermediateT>,
+    InnerT::Error: fmt::Display,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolveTryFrom<InnerT, IntermediateT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        resolve_ref_try_from(self, errors).map(|resolved| resolved.map(ResolveTryFrom::new))
+    }
+}
+
+impl<InnerT, IntermediateT> ContextFree for ResolveTryFrom<InnerT, IntermediateT> {}
+
 impl<InnerT, IntermediateT> AsRef<InnerT> for ResolveTryFrom<InnerT, IntermediateT> {
     fn as_ref(&self) -> &InnerT {
         &self.inner
@@ -1,6 +1,8 @@
 use super::super::{
     super::{annotate::*, normal::*},
+    context::*,
     errors::*,
+    reference::*,
     resolve::*,
 };
 
@@ -21,3 +23,22 @@ where
         Ok(Some(self.into_annotated()))
     }
 }
+
+impl<ResolvedAnnotationsT, AnnotatedT> ResolveRef<Variant<ResolvedAnnotationsT>, AnnotatedT> for Variant<AnnotatedT>
+where
+    ResolvedAnnotationsT: Annotated + Default,
+    AnnotatedT: Annotated + Clone,
+{
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        _errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<Variant<ResolvedAnnotationsT>, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        // We must clone, because this is how we end up with a Variant
+        Ok(Some(self.clone().into_annotated()))
+    }
+}
+
+impl<AnnotatedT> ContextFree for Variant<AnnotatedT> {}
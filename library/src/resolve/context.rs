@@ -0,0 +1,71 @@
+use super::{errors::*, resolve::*};
+
+use kutil::std::error::*;
+
+//
+// ResolveWithContext
+//
+
+/// Resolve one type into another with access to a context.
+///
+/// The context can be anything the resolver needs, e.g. a base directory for relative paths, a
+/// registry of named types, feature flags, or the document root for cross-references.
+///
+/// [Resolve] implementations for [ContextFree] types are also [ResolveWithContext]
+/// implementations for any context (which they simply ignore).
+///
+/// The collection implementations (e.g. for [Vec]) pass the context on to their items, so they
+/// can contain types that need the context.
+pub trait ResolveWithContext<ResolvedT, ContextT, AnnotatedT>: Sized
+where
+    ContextT: ?Sized,
+{
+    /// Resolve one type into another with access to a context.
+    ///
+    /// See [Resolve::resolve_with_errors].
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+
+    /// Resolve one type into another with access to a context.
+    ///
+    /// See [Resolve::resolve].
+    fn resolve_in_context(self, context: &ContextT) -> Result<ResolvedT, ResolveError<AnnotatedT>> {
+        self.resolve_with_context(context, &mut FailFastErrorRecipient)?.ok_or(ResolveError::Missing)
+    }
+}
+
+impl<ResolvableT, ResolvedT, ContextT, AnnotatedT> ResolveWithContext<ResolvedT, ContextT, AnnotatedT> for ResolvableT
+where
+    ResolvableT: Resolve<ResolvedT, AnnotatedT>,
+    ResolvedT: ContextFree,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context<ErrorRecipientT>(
+        self,
+        _context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolve_with_errors(errors)
+    }
+}
+
+//
+// ContextFree
+//
+
+/// Marks a resolved type as not needing a context, so that its [Resolve] implementations are also
+/// [ResolveWithContext] implementations.
+///
+/// `#[derive(Resolve)]` implements it unless `#[resolve(context = ...)]` is used.
+///
+/// (We can't do without this marker: a blanket [ResolveWithContext] implementation for all
+/// [Resolve] implementations would conflict with any other [ResolveWithContext] implementation.)
+pub trait ContextFree {}
@@ -0,0 +1,70 @@
+use super::super::super::{annotate::*, normal::*};
+
+use {
+    kutil::{cli::depict::*, std::immutable::*},
+    std::{fmt, io},
+    thiserror::*,
+};
+
+//
+// DeprecatedKeyError
+//
+
+/// Deprecated key.
+///
+/// This is a warning rather than an error. See [ResolveError::severity](super::resolve::ResolveError::severity).
+#[derive(Debug, Error)]
+pub struct DeprecatedKeyError<AnnotatedT> {
+    /// Key.
+    pub key: Variant<AnnotatedT>,
+
+    /// Optional replacement key.
+    pub replacement: Option<ByteString>,
+}
+
+impl<AnnotatedT> DeprecatedKeyError<AnnotatedT> {
+    /// Constructor.
+    pub fn new(key: Variant<AnnotatedT>, replacement: Option<ByteString>) -> Self {
+        Self { key, replacement }
+    }
+
+    /// Into different [Annotated] implementation.
+    pub fn into_annotated<NewAnnotationsT>(self) -> DeprecatedKeyError<NewAnnotationsT>
+    where
+        AnnotatedT: Annotated,
+        NewAnnotationsT: Annotated + Default,
+    {
+        DeprecatedKeyError::new(self.key.into_annotated(), self.replacement)
+    }
+}
+
+impl_annotated!(DeprecatedKeyError, key);
+
+impl<AnnotatedT> Depict for DeprecatedKeyError<AnnotatedT> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        let key = format!("{:?}", self.key.to_string());
+        write!(writer, "deprecated key: {}", context.theme.error(key))?;
+
+        if let Some(replacement) = &self.replacement {
+            let replacement = format!("{:?}", replacement.to_string());
+            write!(writer, ", use {} instead", context.theme.name(replacement))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<AnnotatedT> fmt::Display for DeprecatedKeyError<AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{:?}", self.key.to_string())?;
+
+        if let Some(replacement) = &self.replacement {
+            write!(formatter, ", use {:?} instead", replacement.to_string())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 mod aliases;
+mod deprecated_key;
 mod invalid_key;
 mod missing_required_key;
 mod resolve;
 
 #[allow(unused_imports)]
-pub use {aliases::*, invalid_key::*, missing_required_key::*, resolve::*};
+pub use {aliases::*, deprecated_key::*, invalid_key::*, missing_required_key::*, resolve::*};
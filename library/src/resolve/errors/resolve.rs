@@ -1,10 +1,15 @@
 use super::{
     super::super::{annotate::*, normal::*},
+    deprecated_key::*,
     invalid_key::*,
     missing_required_key::*,
 };
 
-use {kutil::cli::depict::*, std::fmt, thiserror::*};
+use {
+    kutil::{cli::depict::*, std::error::*},
+    std::{borrow::*, fmt},
+    thiserror::*,
+};
 
 //
 // ResolveError
@@ -34,6 +39,11 @@ pub enum ResolveError<AnnotatedT> {
     #[depict(as(depict))]
     InvalidKey(#[from] InvalidKeyError<AnnotatedT>),
 
+    /// Deprecated key (a warning).
+    #[error("deprecated key: {0}")]
+    #[depict(as(depict))]
+    DeprecatedKey(#[from] DeprecatedKeyError<AnnotatedT>),
+
     /// Conversion.
     #[error("conversion: {0}")]
     #[depict(as(depict))]
@@ -44,6 +54,21 @@ pub enum ResolveError<AnnotatedT> {
     #[depict(as(depict))]
     Malformed(#[from] MalformedError<AnnotatedT>),
 
+    /// Too many errors.
+    #[error("{0}")]
+    #[depict(as(display))]
+    TooManyErrors(#[from] TooManyErrorsError),
+
+    /// Warning.
+    #[error("{0}")]
+    #[depict(as(depict))]
+    Warning(AnnotatedMessageError<AnnotatedT>),
+
+    /// Info.
+    #[error("{0}")]
+    #[depict(as(depict))]
+    Info(AnnotatedMessageError<AnnotatedT>),
+
     /// Other.
     #[error("{0}")]
     #[depict(as(dyn_depict))]
@@ -51,6 +76,55 @@ pub enum ResolveError<AnnotatedT> {
 }
 
 impl<AnnotatedT> ResolveError<AnnotatedT> {
+    /// Warning constructor.
+    pub fn warning(message: String) -> Self
+    where
+        AnnotatedT: Default,
+    {
+        Self::Warning(AnnotatedMessageError::new(message))
+    }
+
+    /// Info constructor.
+    pub fn info(message: String) -> Self
+    where
+        AnnotatedT: Default,
+    {
+        Self::Info(AnnotatedMessageError::new(message))
+    }
+
+    /// Severity.
+    ///
+    /// [DeprecatedKey](Self::DeprecatedKey) and [Warning](Self::Warning) are warnings and
+    /// [Info](Self::Info) is info. All others are errors.
+    pub fn severity(&self) -> DiagnosticSeverity {
+        match self {
+            Self::DeprecatedKey(_) | Self::Warning(_) => DiagnosticSeverity::Warning,
+            Self::Info(_) => DiagnosticSeverity::Info,
+            _ => DiagnosticSeverity::Error,
+        }
+    }
+
+    /// True if [severity](Self::severity) is [DiagnosticSeverity::Error].
+    pub fn is_error(&self) -> bool {
+        self.severity() == DiagnosticSeverity::Error
+    }
+
+    /// Give to an [ErrorRecipient].
+    ///
+    /// Unlike errors, warnings and infos are never fatal. If the recipient rejects them (e.g.
+    /// [FailFastErrorRecipient]) they are dropped.
+    pub fn give_to<ErrorRecipientT>(self, errors: &mut ErrorRecipientT) -> Result<(), Self>
+    where
+        ErrorRecipientT: ErrorRecipient<Self>,
+    {
+        if self.is_error() {
+            errors.give_error(self)
+        } else {
+            _ = errors.give_error(self);
+            Ok(())
+        }
+    }
+
     /// Into different [Annotated] implementation.
     pub fn into_annotated<NewAnnotationsT>(self) -> ResolveError<NewAnnotationsT>
     where
@@ -64,8 +138,12 @@ impl<AnnotatedT> ResolveError<AnnotatedT> {
             }
             Self::MissingRequiredKey(missing_required_key) => missing_required_key.into_annotated().into(),
             Self::InvalidKey(invalid_key) => invalid_key.into_annotated().into(),
+            Self::DeprecatedKey(deprecated_key) => deprecated_key.into_annotated().into(),
             Self::Conversion(conversion) => conversion.into_annotated().into(),
             Self::Malformed(malformed) => malformed.into_annotated().into(),
+            Self::TooManyErrors(too_many_errors) => ResolveError::TooManyErrors(too_many_errors),
+            Self::Warning(warning) => ResolveError::Warning(warning.into_annotated()),
+            Self::Info(info) => ResolveError::Info(info.into_annotated()),
             Self::Other(other) => ResolveError::Other(other),
         }
     }
@@ -87,8 +165,12 @@ where
             Self::IncompatibleVariantType(incompatible_value_type) => incompatible_value_type.annotations(),
             Self::MissingRequiredKey(missing_required_key) => missing_required_key.annotations(),
             Self::InvalidKey(invalid_key) => invalid_key.annotations(),
+            Self::DeprecatedKey(deprecated_key) => deprecated_key.annotations(),
             Self::Conversion(conversion) => conversion.annotations(),
             Self::Malformed(malformed) => malformed.annotations(),
+            Self::TooManyErrors(_) => None,
+            Self::Warning(warning) => warning.annotations(),
+            Self::Info(info) => info.annotations(),
             Self::Other(other) => other.dyn_annotations(),
         }
     }
@@ -99,13 +181,34 @@ where
             Self::IncompatibleVariantType(incompatible_value_type) => incompatible_value_type.annotations_mut(),
             Self::MissingRequiredKey(missing_required_key) => missing_required_key.annotations_mut(),
             Self::InvalidKey(invalid_key) => invalid_key.annotations_mut(),
+            Self::DeprecatedKey(deprecated_key) => deprecated_key.annotations_mut(),
             Self::Conversion(conversion) => conversion.annotations_mut(),
             Self::Malformed(malformed) => malformed.annotations_mut(),
+            Self::TooManyErrors(_) => None,
+            Self::Warning(warning) => warning.annotations_mut(),
+            Self::Info(info) => info.annotations_mut(),
             Self::Other(other) => other.dyn_annotations_mut(),
         }
     }
 }
 
+impl<AnnotatedT> ReportDiagnostic for ResolveError<AnnotatedT>
+where
+    AnnotatedT: Annotated,
+{
+    fn diagnostic_message(&self) -> String {
+        self.to_string()
+    }
+
+    fn diagnostic_severity(&self) -> DiagnosticSeverity {
+        self.severity()
+    }
+
+    fn diagnostic_annotations(&self) -> Option<Cow<'_, Annotations>> {
+        self.annotations().map(Cow::Borrowed)
+    }
+}
+
 impl<AnnotatedT> From<String> for ResolveError<AnnotatedT>
 where
     AnnotatedT: 'static + Annotated + fmt::Debug + Default + Send + Sync,
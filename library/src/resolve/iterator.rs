@@ -13,3 +13,20 @@ pub trait ResolvingIterator<ResolvedT, AnnotatedT> {
     where
         ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
 }
+
+/// Iterator that resolves one item at a time with access to a context.
+pub trait ResolvingIteratorWithContext<ResolvedT, ContextT, AnnotatedT>
+where
+    ContextT: ?Sized,
+{
+    /// Resolve next with access to a context.
+    ///
+    /// See [ResolvingIterator::resolve_next].
+    fn resolve_next_with_context<ErrorRecipientT>(
+        &mut self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+}
@@ -6,13 +6,15 @@ A lot like [TryFrom], except that:
 */
 
 mod common;
+mod context;
 mod errors;
 mod iterator;
 mod parser;
+mod reference;
 mod resolve;
 
 #[allow(unused_imports)]
-pub use {common::*, errors::*, iterator::*, parser::*, resolve::*};
+pub use {common::*, context::*, errors::*, iterator::*, parser::*, reference::*, resolve::*};
 
 #[cfg(feature = "derive")]
 #[allow(unused_imports)]
@@ -0,0 +1,184 @@
+use super::{context::*, errors::*, resolve::*};
+
+use {kutil::std::error::*, std::marker::*};
+
+//
+// ResolveRef
+//
+
+/// Resolve one type into another without consuming it.
+///
+/// Useful for resolving the same data into several types, or for keeping the original data, e.g.
+/// for error reporting, without having to clone it first.
+///
+/// All [ResolveRef] implementations are also [Resolve] implementations for a reference.
+pub trait ResolveRef<ResolvedT, AnnotatedT> {
+    /// Resolve one type into another without consuming it.
+    ///
+    /// See [Resolve::resolve_with_errors].
+    fn resolve_ref_with_errors<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+
+    /// Resolve one type into another without consuming it.
+    ///
+    /// See [Resolve::resolve].
+    fn resolve_ref(&self) -> Result<ResolvedT, ResolveError<AnnotatedT>> {
+        self.resolve_ref_with_errors(&mut FailFastErrorRecipient)?.ok_or(ResolveError::Missing)
+    }
+}
+
+impl<ResolvableT, ResolvedT, AnnotatedT> Resolve<ResolvedT, AnnotatedT> for &ResolvableT
+where
+    ResolvableT: ResolveRef<ResolvedT, AnnotatedT>,
+{
+    fn resolve_with_errors<ErrorRecipientT>(self, errors: &mut ErrorRecipientT) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolve_ref_with_errors(errors)
+    }
+}
+
+//
+// ResolveRefOrClone
+//
+
+// Used by `#[derive(Resolve)]` to resolve by reference if the resolvable type supports it, and
+// otherwise from a clone.
+//
+// This is autoref specialization: the by-reference traits are implemented for the struct and the
+// by-clone traits for a reference to it, so when calling the method on a reference to the struct
+// the compiler picks the by-reference implementation if its bounds are satisfied. Note that this
+// only works when the types are known (not generic).
+
+#[doc(hidden)]
+pub struct ResolveRefOrClone<'own, ResolvableT, ResolvedT, AnnotatedT> {
+    pub resolvable: &'own ResolvableT,
+    resolved: PhantomData<(ResolvedT, AnnotatedT)>,
+}
+
+impl<'own, ResolvableT, ResolvedT, AnnotatedT> ResolveRefOrClone<'own, ResolvableT, ResolvedT, AnnotatedT> {
+    pub fn new(resolvable: &'own ResolvableT) -> Self {
+        Self { resolvable, resolved: PhantomData }
+    }
+}
+
+#[doc(hidden)]
+pub trait ResolveByRef<ResolvedT, AnnotatedT> {
+    fn resolve_ref_or_clone<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+}
+
+impl<ResolvableT, ResolvedT, AnnotatedT> ResolveByRef<ResolvedT, AnnotatedT>
+    for ResolveRefOrClone<'_, ResolvableT, ResolvedT, AnnotatedT>
+where
+    ResolvableT: ResolveRef<ResolvedT, AnnotatedT>,
+{
+    fn resolve_ref_or_clone<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolvable.resolve_ref_with_errors(errors)
+    }
+}
+
+#[doc(hidden)]
+pub trait ResolveByClone<ResolvedT, AnnotatedT> {
+    fn resolve_ref_or_clone<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+}
+
+impl<ResolvableT, ResolvedT, AnnotatedT> ResolveByClone<ResolvedT, AnnotatedT>
+    for &ResolveRefOrClone<'_, ResolvableT, ResolvedT, AnnotatedT>
+where
+    ResolvableT: Clone + Resolve<ResolvedT, AnnotatedT>,
+{
+    fn resolve_ref_or_clone<ErrorRecipientT>(
+        &self,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolvable.clone().resolve_with_errors(errors)
+    }
+}
+
+#[doc(hidden)]
+pub trait ResolveWithContextByRef<ResolvedT, ContextT, AnnotatedT>
+where
+    ContextT: ?Sized,
+{
+    fn resolve_with_context_ref_or_clone<ErrorRecipientT>(
+        &self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+}
+
+impl<'own, ResolvableT, ResolvedT, ContextT, AnnotatedT> ResolveWithContextByRef<ResolvedT, ContextT, AnnotatedT>
+    for ResolveRefOrClone<'own, ResolvableT, ResolvedT, AnnotatedT>
+where
+    &'own ResolvableT: ResolveWithContext<ResolvedT, ContextT, AnnotatedT>,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context_ref_or_clone<ErrorRecipientT>(
+        &self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolvable.resolve_with_context(context, errors)
+    }
+}
+
+#[doc(hidden)]
+pub trait ResolveWithContextByClone<ResolvedT, ContextT, AnnotatedT>
+where
+    ContextT: ?Sized,
+{
+    fn resolve_with_context_ref_or_clone<ErrorRecipientT>(
+        &self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>;
+}
+
+impl<ResolvableT, ResolvedT, ContextT, AnnotatedT> ResolveWithContextByClone<ResolvedT, ContextT, AnnotatedT>
+    for &ResolveRefOrClone<'_, ResolvableT, ResolvedT, AnnotatedT>
+where
+    ResolvableT: Clone + ResolveWithContext<ResolvedT, ContextT, AnnotatedT>,
+    ContextT: ?Sized,
+{
+    fn resolve_with_context_ref_or_clone<ErrorRecipientT>(
+        &self,
+        context: &ContextT,
+        errors: &mut ErrorRecipientT,
+    ) -> ResolveResult<ResolvedT, AnnotatedT>
+    where
+        ErrorRecipientT: ErrorRecipient<ResolveError<AnnotatedT>>,
+    {
+        self.resolvable.clone().resolve_with_context(context, errors)
+    }
+}
@@ -13,6 +13,10 @@ pub trait Resolve<ResolvedT, AnnotatedT>: Sized {
     /// Errors can be reported as usual by [Err] *but also* by the [ErrorRecipient]. Callers should
     /// thus check that `errors` is empty even when the function returns [Ok].
     ///
+    /// Warnings and infos (see [ResolveError::severity]) are given to the same [ErrorRecipient]
+    /// but are never fatal. Use [PartitionedErrors](super::super::annotate::PartitionedErrors) to
+    /// keep them apart from errors.
+    ///
     /// The function may return [Some] partially resolved result even if there are errors.
     fn resolve_with_errors<ErrorRecipientT>(self, errors: &mut ErrorRecipientT) -> ResolveResult<ResolvedT, AnnotatedT>
     where
@@ -24,6 +28,8 @@ pub trait Resolve<ResolvedT, AnnotatedT>: Sized {
     /// [ResolveError::None] instead of [None].
     ///
     /// If you want all the errors use [resolve](Resolve::resolve) instead.
+    ///
+    /// Warnings and infos (see [ResolveError::severity]) are ignored.
     fn resolve(self) -> Result<ResolvedT, ResolveError<AnnotatedT>> {
         self.resolve_with_errors(&mut FailFastErrorRecipient)?.ok_or(ResolveError::Missing)
     }
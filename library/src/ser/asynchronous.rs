@@ -0,0 +1,55 @@
+use super::{
+    super::{annotate::*, normal::*},
+    errors::*,
+    modal::*,
+    mode::*,
+    serializer::*,
+};
+
+use {
+    serde::Serialize,
+    tokio::io::{AsyncWrite, AsyncWriteExt},
+};
+
+const ASYNC_BUFFER_CAPACITY: usize = 1024;
+
+impl Serializer {
+    /// Asynchronously serializes the provided value to the writer according to
+    /// [Serializer::format](Serializer).
+    ///
+    /// Note that the value is first serialized into memory and only then written. The writer is
+    /// flushed at the end.
+    pub async fn write_async<WriteT, SerializableT>(
+        &self,
+        value: &SerializableT,
+        writer: &mut WriteT,
+    ) -> Result<(), SerializeError>
+    where
+        WriteT: AsyncWrite + Unpin,
+        SerializableT: Serialize,
+    {
+        let mut buffer = Vec::with_capacity(ASYNC_BUFFER_CAPACITY);
+        self.write(value, &mut buffer)?;
+        writer.write_all(&buffer).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+
+    /// Asynchronously serializes the provided value to the writer according to
+    /// [Serializer::format](Serializer).
+    ///
+    /// See [Serializer::write_async].
+    pub async fn write_modal_async<WriteT, AnnotatedT>(
+        &self,
+        value: &Variant<AnnotatedT>,
+        mode: &SerializationMode,
+        writer: &mut WriteT,
+    ) -> Result<(), SerializeError>
+    where
+        WriteT: AsyncWrite + Unpin,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let value = value.modal(mode, self);
+        self.write_async(&value, writer).await
+    }
+}
@@ -1,9 +1,10 @@
 #[cfg(feature = "cbor")]
 mod cbor;
 mod errors;
+mod transcode;
 
 #[allow(unused_imports)]
-pub use errors::*;
+pub use {errors::*, transcode::*};
 
 #[cfg(feature = "cbor")]
 #[allow(unused_imports)]
@@ -0,0 +1,19 @@
+use super::{super::super::parse::*, errors::*};
+
+use thiserror::*;
+
+//
+// TranscodeError
+//
+
+/// Compris transcoding error.
+#[derive(Debug, Error)]
+pub enum TranscodeError {
+    /// Parse.
+    #[error("parse: {0}")]
+    Parse(#[from] ParseError),
+
+    /// Serialize.
+    #[error("serialize: {0}")]
+    Serialize(#[from] SerializeError),
+}
@@ -1,4 +1,4 @@
-use super::super::{errors::*, serializer::*};
+use super::super::{super::normal::*, errors::*, normal::*, serializer::*};
 
 use {
     borc::{basic::streaming::*, errors::*},
@@ -9,6 +9,9 @@ use {
 impl Serializer {
     /// Serializes the provided value to the writer as CBOR.
     ///
+    /// [Number](super::super::super::normal::Number) is written as a bignum (tags 2 and 3) or a
+    /// decimal fraction (tag 4).
+    ///
     /// Is affected by [Serializer::base64](super::super::Serializer::base64).
     pub fn write_cbor<WriteT, SerializableT>(
         &self,
@@ -56,6 +59,52 @@ where
         tracing::trace!("{:?}", event);
         self.encoder.feed_event(event)
     }
+
+    fn number(&mut self, number: &str) -> Result<(), CborWriteError> {
+        let (negative, digits, exponent) = decimal_parts(number)
+            .ok_or_else(|| <CborWriteError as ser::Error>::custom(format!("malformed number: {}", number)))?;
+
+        if exponent != 0 {
+            // Decimal fraction
+            self.event(Event::Tag(4))?;
+            self.event(Event::Array(2))?;
+            self.event(Event::create_signed(exponent))?;
+        }
+
+        self.integer(negative, &digits)
+    }
+
+    fn integer(&mut self, negative: bool, digits: &str) -> Result<(), CborWriteError> {
+        let mut bytes = decimal_to_big_endian(digits)
+            .ok_or_else(|| <CborWriteError as ser::Error>::custom(format!("malformed integer: {}", digits)))?;
+
+        // Negative zero is just zero
+        let negative = negative && !bytes.is_empty();
+
+        if negative {
+            // CBOR negative integers are encoded as -1 - n
+            for byte in bytes.iter_mut().rev() {
+                let borrow = *byte == 0;
+                *byte = byte.wrapping_sub(1);
+                if !borrow {
+                    break;
+                }
+            }
+            let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+            bytes.drain(..zeros);
+        }
+
+        if bytes.len() <= 8 {
+            let mut integer = [0u8; 8];
+            integer[8 - bytes.len()..].copy_from_slice(&bytes);
+            let integer = u64::from_be_bytes(integer);
+            Ok(self.event(if negative { Event::Signed(integer) } else { Event::Unsigned(integer) })?)
+        } else {
+            // Bignum
+            self.event(Event::Tag(if negative { 3 } else { 2 }))?;
+            Ok(self.event(Event::ByteString(bytes.into()))?)
+        }
+    }
 }
 
 impl<'own, WriteT> ser::Serializer for &'own mut CborSerializer<WriteT>
@@ -160,12 +209,18 @@ where
 
     fn serialize_newtype_struct<SerializableT>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &SerializableT,
     ) -> Result<Self::Ok, Self::Error>
     where
         SerializableT: ?Sized + Serialize,
     {
+        if name == NUMBER_NEWTYPE_NAME
+            && let Some(number) = number_text(value)
+        {
+            return self.number(&number);
+        }
+
         value.serialize(self)
     }
 
@@ -1,13 +1,16 @@
-use super::super::{errors::*, serializer::*};
+use super::super::{errors::*, normal::*, serializer::*};
 
 use {
-    serde::Serialize,
+    serde::{Serialize, ser},
     std::io,
     struson::{serde::*, writer::*},
 };
 
 impl Serializer {
     /// Serializes the provided value to the writer as JSON.
+    ///
+    /// [Number](super::super::super::normal::Number) is written verbatim if it is a valid JSON
+    /// number, otherwise as a string.
     pub fn write_json<WriteT, SerializableT>(
         &self,
         value: &SerializableT,
@@ -26,15 +29,649 @@ impl Serializer {
             struson::writer::JsonStreamWriter::new(writer.by_ref())
         };
 
-        let mut json_stream_writer = StyledJsonWriter::new(json_stream_writer);
+        let mut serializer = JsonSerializer::new(StyledJsonWriter::new(json_stream_writer));
 
-        json_stream_writer.serialize_value(&value)?;
-        json_stream_writer.finish_document()?;
+        value.serialize(&mut serializer)?;
+        serializer.writer.finish_document()?;
 
         if self.pretty { Self::write_newline(writer) } else { Ok(()) }
     }
 }
 
+//
+// JsonSerializer
+//
+
+// Like Struson's JsonWriterSerializer, but also recognizes Number
+
+struct JsonSerializer<JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    writer: JsonWriterT,
+}
+
+impl<JsonWriterT> JsonSerializer<JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    fn new(writer: JsonWriterT) -> Self {
+        Self { writer }
+    }
+
+    fn struson(&mut self) -> JsonWriterSerializer<'_, JsonWriterT> {
+        JsonWriterSerializer::new(&mut self.writer)
+    }
+
+    fn number(&mut self, number: &str) -> Result<(), SerializerError> {
+        match self.writer.number_value_from_string(number) {
+            Ok(()) => Ok(()),
+            // Not a valid JSON number (could have come from another format)
+            Err(JsonNumberError::InvalidNumber(_)) => Ok(self.writer.string_value(number)?),
+            Err(JsonNumberError::IoError(error)) => Err(error.into()),
+        }
+    }
+
+    fn compound(&mut self, array: bool, variant: bool) -> JsonCompoundSerializer<'_, JsonWriterT> {
+        JsonCompoundSerializer { serializer: self, array, variant }
+    }
+}
+
+impl<'own, JsonWriterT> ser::Serializer for &'own mut JsonSerializer<JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+    type SerializeSeq = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeTuple = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeTupleStruct = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeTupleVariant = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeMap = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeStruct = JsonCompoundSerializer<'own, JsonWriterT>;
+    type SerializeStructVariant = JsonCompoundSerializer<'own, JsonWriterT>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_i128(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_u64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_none()
+    }
+
+    fn serialize_some<SerializableT>(self, value: &SerializableT) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.struson().serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<SerializableT>(
+        self,
+        name: &'static str,
+        value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        if name == NUMBER_NEWTYPE_NAME
+            && let Some(number) = number_text(value)
+        {
+            return self.number(&number);
+        }
+
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<SerializableT>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.writer.begin_object()?;
+        self.writer.name(variant)?;
+        value.serialize(&mut *self)?;
+        Ok(self.writer.end_object()?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.writer.begin_array()?;
+        Ok(self.compound(true, false))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.writer.begin_array()?;
+        Ok(self.compound(true, false))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.writer.begin_array()?;
+        Ok(self.compound(true, false))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.writer.begin_object()?;
+        self.writer.name(variant)?;
+        self.writer.begin_array()?;
+        Ok(self.compound(true, true))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.writer.begin_object()?;
+        Ok(self.compound(false, false))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.writer.begin_object()?;
+        Ok(self.compound(false, false))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.writer.begin_object()?;
+        self.writer.name(variant)?;
+        self.writer.begin_object()?;
+        Ok(self.compound(false, true))
+    }
+}
+
+//
+// JsonCompoundSerializer
+//
+
+// Arrays and objects, optionally wrapped in a single-member object for enum variants
+
+pub struct JsonCompoundSerializer<'own, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    serializer: &'own mut JsonSerializer<JsonWriterT>,
+    array: bool,
+    variant: bool,
+}
+
+impl<JsonWriterT> JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    fn element<SerializableT>(&mut self, value: &SerializableT) -> Result<(), SerializerError>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn field<SerializableT>(&mut self, key: &str, value: &SerializableT) -> Result<(), SerializerError>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.serializer.writer.name(key)?;
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), SerializerError> {
+        let writer = &mut self.serializer.writer;
+        if self.array {
+            writer.end_array()?;
+        } else {
+            writer.end_object()?;
+        }
+        if self.variant {
+            writer.end_object()?;
+        }
+        Ok(())
+    }
+}
+
+impl<JsonWriterT> ser::SerializeSeq for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_element<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeTuple for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_element<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeTupleStruct for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_field<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeTupleVariant for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_field<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeMap for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_key<SerializableT>(&mut self, key: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        let key = key.serialize(JsonKeySerializer)?;
+        Ok(self.serializer.writer.name(&key)?)
+    }
+
+    fn serialize_value<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeStruct for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_field<SerializableT>(&mut self, key: &'static str, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+impl<JsonWriterT> ser::SerializeStructVariant for JsonCompoundSerializer<'_, JsonWriterT>
+where
+    JsonWriterT: JsonWriter,
+{
+    type Ok = ();
+    type Error = SerializerError;
+
+    fn serialize_field<SerializableT>(&mut self, key: &'static str, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        JsonCompoundSerializer::end(self)
+    }
+}
+
+//
+// JsonKeySerializer
+//
+
+// JSON object member names must be strings; like Struson, we stringify scalars (including Number)
+
+struct JsonKeySerializer;
+
+impl JsonKeySerializer {
+    fn finite_number<NumberT>(number: NumberT) -> Result<String, SerializerError>
+    where
+        NumberT: FiniteNumber,
+    {
+        let mut key = String::default();
+        number.use_json_number(|number| {
+            key = number.into();
+            Ok(())
+        })?;
+        Ok(key)
+    }
+
+    fn floating_point_number<NumberT>(number: NumberT) -> Result<String, SerializerError>
+    where
+        NumberT: FloatingPointNumber,
+    {
+        let mut key = String::default();
+        number
+            .use_json_number(|number| {
+                key = number.into();
+                Ok(())
+            })
+            .map_err(|error| match error {
+                JsonNumberError::InvalidNumber(message) => SerializerError::InvalidNumber(message),
+                JsonNumberError::IoError(error) => SerializerError::IoError(error),
+            })?;
+        Ok(key)
+    }
+}
+
+impl ser::Serializer for JsonKeySerializer {
+    type Ok = String;
+    type Error = SerializerError;
+    type SerializeSeq = ser::Impossible<String, SerializerError>;
+    type SerializeTuple = ser::Impossible<String, SerializerError>;
+    type SerializeTupleStruct = ser::Impossible<String, SerializerError>;
+    type SerializeTupleVariant = ser::Impossible<String, SerializerError>;
+    type SerializeMap = ser::Impossible<String, SerializerError>;
+    type SerializeStruct = ser::Impossible<String, SerializerError>;
+    type SerializeStructVariant = ser::Impossible<String, SerializerError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        Self::finite_number(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Self::floating_point_number(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Self::floating_point_number(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_some<SerializableT>(self, value: &SerializableT) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<SerializableT>(
+        self,
+        _name: &'static str,
+        value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<SerializableT>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(SerializerError::MapKeyNotString)
+    }
+}
+
 //
 // StyledJsonWriter
 //
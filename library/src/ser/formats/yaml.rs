@@ -1,9 +1,20 @@
-use super::super::{errors::*, serializer::*};
+use super::super::{super::normal::*, errors::*, normal::*, serializer::*};
 
-use {serde::Serialize, std::io};
+use {
+    serde::{Serialize, ser},
+    serde_yml::{
+        State,
+        libyml::emitter::{Scalar, ScalarStyle},
+        modules::error::{self, ErrorImpl},
+    },
+    std::{fmt, io},
+};
 
 impl Serializer {
     /// Serializes the provided value to the writer as YAML.
+    ///
+    /// [Number](super::super::super::normal::Number) is written verbatim as a plain scalar if it
+    /// is a valid decimal number, otherwise as a string.
     pub fn write_yaml<WriteT, SerializableT>(
         &self,
         value: &SerializableT,
@@ -16,6 +27,413 @@ impl Serializer {
         // TODO: Broken for complex keys
         let config = serde_yml::ser::SerializerConfig { tag_unit_variants: true };
         let mut serializer = serde_yml::Serializer::new_with_config(writer, config);
-        Ok(value.serialize(&mut serializer)?)
+        Ok(value.serialize(YamlSerializer::new(&mut serializer))?)
+    }
+}
+
+//
+// YamlSerializer
+//
+
+// Wraps serde_yml's serializer in order to recognize Number (serde_yml ignores newtype struct
+// names). Everything else is delegated, including serde_yml's handling of enum tags.
+
+struct YamlSerializer<'own, WriteT>
+where
+    WriteT: io::Write,
+{
+    inner: &'own mut serde_yml::Serializer<WriteT>,
+}
+
+impl<'own, WriteT> YamlSerializer<'own, WriteT>
+where
+    WriteT: io::Write,
+{
+    fn new(inner: &'own mut serde_yml::Serializer<WriteT>) -> Self {
+        Self { inner }
+    }
+
+    fn compound(inner: &'own mut serde_yml::Serializer<WriteT>) -> YamlCompoundSerializer<'own, WriteT> {
+        YamlCompoundSerializer { inner }
+    }
+}
+
+impl<'own, WriteT> ser::Serializer for YamlSerializer<'own, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+    type SerializeSeq = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeTuple = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeTupleStruct = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeTupleVariant = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeMap = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeStruct = YamlCompoundSerializer<'own, WriteT>;
+    type SerializeStructVariant = YamlCompoundSerializer<'own, WriteT>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i128(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<SerializableT>(self, value: &SerializableT) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<SerializableT>(
+        self,
+        name: &'static str,
+        value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        if name == NUMBER_NEWTYPE_NAME
+            && let Some(number) = number_text(value)
+            && decimal_parts(&number).is_some()
+        {
+            return self.inner.emit_scalar(Scalar { tag: None, value: &number, style: ScalarStyle::Plain });
+        }
+
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<SerializableT>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        // As serde_yml does
+        if let State::FoundTag(_) = self.inner.state {
+            return Err(error::new(ErrorImpl::SerializeNestedEnum));
+        }
+        self.inner.state = State::FoundTag(variant.into());
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_seq(len)?))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_tuple(len)?))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_tuple_struct(name, len)?))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_tuple_variant(name, variant_index, variant, len)?))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_map(len)?))
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_struct(name, len)?))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(Self::compound(self.inner.serialize_struct_variant(name, variant_index, variant, len)?))
+    }
+
+    fn collect_str<DisplayT>(self, value: &DisplayT) -> Result<Self::Ok, Self::Error>
+    where
+        DisplayT: ?Sized + fmt::Display,
+    {
+        // serde_yml uses it to detect tags
+        self.inner.collect_str(value)
+    }
+}
+
+//
+// YamlCompoundSerializer
+//
+
+// Serializes the items with YamlSerializer, otherwise as serde_yml does
+
+struct YamlCompoundSerializer<'own, WriteT>
+where
+    WriteT: io::Write,
+{
+    inner: &'own mut serde_yml::Serializer<WriteT>,
+}
+
+impl<WriteT> YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    fn item<SerializableT>(&mut self, value: &SerializableT) -> Result<(), serde_yml::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        value.serialize(YamlSerializer::new(self.inner))
+    }
+
+    fn field<SerializableT>(&mut self, key: &'static str, value: &SerializableT) -> Result<(), serde_yml::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        ser::Serializer::serialize_str(&mut *self.inner, key)?;
+        self.item(value)
+    }
+}
+
+impl<WriteT> ser::SerializeSeq for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_element<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeTuple for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_element<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeTuple::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeTupleStruct for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_field<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeTupleStruct::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeTupleVariant for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_field<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeTupleVariant::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeMap for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_key<SerializableT>(&mut self, key: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.inner.flush_mapping_start()?;
+        self.item(key)
+    }
+
+    fn serialize_value<SerializableT>(&mut self, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn serialize_entry<KeyT, ValueT>(&mut self, key: &KeyT, value: &ValueT) -> Result<(), Self::Error>
+    where
+        KeyT: ?Sized + Serialize,
+        ValueT: ?Sized + Serialize,
+    {
+        // As serde_yml does (the key might be an enum tag)
+        self.item(key)?;
+        let tagged = matches!(self.inner.state, State::FoundTag(_));
+        self.item(value)?;
+        if tagged {
+            self.inner.state = State::AlreadyTagged;
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeMap::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeStruct for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_field<SerializableT>(&mut self, key: &'static str, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeStruct::end(self.inner)
+    }
+}
+
+impl<WriteT> ser::SerializeStructVariant for YamlCompoundSerializer<'_, WriteT>
+where
+    WriteT: io::Write,
+{
+    type Ok = ();
+    type Error = serde_yml::Error;
+
+    fn serialize_field<SerializableT>(&mut self, key: &'static str, value: &SerializableT) -> Result<(), Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeStructVariant::end(self.inner)
     }
 }
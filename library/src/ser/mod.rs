@@ -1,9 +1,12 @@
+#[cfg(feature = "async")]
+mod asynchronous;
 mod errors;
 mod formats;
 mod mode;
 mod normal;
 mod serializer;
 mod modal;
+mod transcode;
 
 #[allow(unused_imports)]
 pub use {errors::*, formats::*, mode::*, serializer::*, modal::*};
@@ -5,11 +5,13 @@ mod integer;
 mod list;
 mod map;
 mod null;
+mod number;
 mod text;
 mod unsigned_integer;
 mod variant;
 
 #[allow(unused_imports)]
 pub use {
-    blob::*, boolean::*, float::*, integer::*, list::*, map::*, null::*, text::*, unsigned_integer::*, variant::*,
+    blob::*, boolean::*, float::*, integer::*, list::*, map::*, null::*, number::*, text::*, unsigned_integer::*,
+    variant::*,
 };
@@ -0,0 +1,228 @@
+use super::super::super::normal::*;
+
+use {
+    serde::ser::*,
+    std::{error, fmt},
+};
+
+/// Newtype struct name used for [Number].
+///
+/// Our own CBOR, JSON, and YAML writers recognize it and write the number verbatim. Other
+/// serializers will just write the inner string.
+pub(crate) const NUMBER_NEWTYPE_NAME: &str = "$compris::Number";
+
+impl<AnnotatedT> Serialize for Number<AnnotatedT> {
+    fn serialize<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        serializer.serialize_newtype_struct(NUMBER_NEWTYPE_NAME, self.as_str())
+    }
+}
+
+// Utils
+
+/// The numeric text of a [Number] newtype struct value.
+pub(crate) fn number_text<SerializableT>(value: &SerializableT) -> Option<String>
+where
+    SerializableT: ?Sized + Serialize,
+{
+    value.serialize(NumberTextSerializer).ok()
+}
+
+//
+// NumberTextSerializer
+//
+
+// Accepts only a string
+
+struct NumberTextSerializer;
+
+impl Serializer for NumberTextSerializer {
+    type Ok = String;
+    type Error = NotNumberTextError;
+
+    type SerializeSeq = Impossible<String, NotNumberTextError>;
+    type SerializeTuple = Impossible<String, NotNumberTextError>;
+    type SerializeTupleStruct = Impossible<String, NotNumberTextError>;
+    type SerializeTupleVariant = Impossible<String, NotNumberTextError>;
+    type SerializeMap = Impossible<String, NotNumberTextError>;
+    type SerializeStruct = Impossible<String, NotNumberTextError>;
+    type SerializeStructVariant = Impossible<String, NotNumberTextError>;
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_some<SerializableT>(self, _value: &SerializableT) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_newtype_struct<SerializableT>(
+        self,
+        _name: &'static str,
+        _value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_newtype_variant<SerializableT>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &SerializableT,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        SerializableT: ?Sized + Serialize,
+    {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(NotNumberTextError)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(NotNumberTextError)
+    }
+}
+
+//
+// NotNumberTextError
+//
+
+#[derive(Debug)]
+struct NotNumberTextError;
+
+impl fmt::Display for NotNumberTextError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("not number text")
+    }
+}
+
+impl error::Error for NotNumberTextError {}
+
+impl Error for NotNumberTextError {
+    fn custom<DisplayT>(_message: DisplayT) -> Self
+    where
+        DisplayT: fmt::Display,
+    {
+        Self
+    }
+}
@@ -18,6 +18,7 @@ impl<AnnotatedT> Serialize for Variant<AnnotatedT> {
             Self::Integer(integer) => integer.serialize(serializer),
             Self::UnsignedInteger(unsigned_integer) => unsigned_integer.serialize(serializer),
             Self::Float(float) => float.serialize(serializer),
+            Self::Number(number) => number.serialize(serializer),
             Self::Boolean(boolean) => boolean.serialize(serializer),
             Self::Text(text) => text.serialize(serializer),
             Self::Blob(blob) => blob.serialize(serializer),
@@ -46,6 +47,7 @@ where
             Variant::Integer(integer) => integer.modal(mode).serialize(serializer),
             Variant::UnsignedInteger(unsigned_integer) => unsigned_integer.modal(mode).serialize(serializer),
             Variant::Float(float) => float.modal(mode).serialize(serializer),
+            Variant::Number(number) => number.serialize(serializer),
             Variant::Boolean(boolean) => boolean.serialize(serializer),
             Variant::Text(text) => text.serialize(serializer),
             Variant::Blob(blob) => blob.modal(mode).serialize(serializer),
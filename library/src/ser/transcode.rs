@@ -0,0 +1,404 @@
+use super::{
+    super::{
+        annotate::*,
+        hints::*,
+        normal::*,
+        parse::{builder::*, *},
+        *,
+    },
+    errors::*,
+    modal::*,
+    mode::*,
+    serializer::Serializer as ComprisSerializer,
+};
+
+use {
+    serde::ser::*,
+    std::{cell::*, io, iter::*},
+};
+
+impl ComprisSerializer {
+    /// Parses from the reader and serializes to the writer according to
+    /// [Serializer::format](super::Serializer) without first building a [Variant].
+    ///
+    /// XJSON hints are processed if the parser's format is XJSON.
+    ///
+    /// See [Serializer::write_events](super::Serializer::write_events).
+    pub fn transcode<ReadT, WriteT>(
+        &self,
+        parser: &Parser,
+        reader: &mut ReadT,
+        mode: Option<&SerializationMode>,
+        writer: &mut WriteT,
+    ) -> Result<(), TranscodeError>
+    where
+        ReadT: io::Read,
+        WriteT: io::Write,
+    {
+        let hints = if parser.format == Format::XJSON { Some(Hints::xjson()) } else { None };
+        let events = parser.events::<_, WithoutAnnotations>(reader)?;
+        self.write_events(events, mode, hints.as_ref(), writer)
+    }
+
+    /// Serializes [Event]s to the writer according to [Serializer::format](super::Serializer).
+    ///
+    /// Events are serialized as they are pulled. A subtree is buffered into a [Variant] only when
+    /// it must be seen in full:
+    ///
+    /// * maps that start with a hint key (when hints are provided)
+    /// * maps when the mode is [MapSerializationMode::AsSeqIfNonTextKey]
+    /// * non-text keys when the mode is [MapSerializationMode::SerializeKeys] or
+    ///   [MapSerializationMode::SerializeKeysIfNonText]
+    ///
+    /// Note that container lengths are not known in advance. Writers that require them (e.g.
+    /// MessagePack) will buffer containers internally, while CBOR containers are written with
+    /// indefinite lengths.
+    pub fn write_events<WriteT, EventsT, AnnotatedT>(
+        &self,
+        events: EventsT,
+        mode: Option<&SerializationMode>,
+        hints: Option<&Hints>,
+        writer: &mut WriteT,
+    ) -> Result<(), TranscodeError>
+    where
+        WriteT: io::Write,
+        EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let transcoder = Transcoder::new(events, mode, hints, self);
+        match self.write(&transcoder, writer) {
+            Ok(_) => Ok(()),
+
+            // The serializer only knows the parse error's message, so we prefer the original
+            Err(error) => Err(match transcoder.error.take() {
+                Some(error) => error.into(),
+                None => error.into(),
+            }),
+        }
+    }
+}
+
+//
+// Transcoder
+//
+
+struct Transcoder<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+{
+    events: RefCell<Peekable<EventsT>>,
+    mode: Option<&'own SerializationMode>,
+    hints: Option<&'own Hints>,
+    serializer: &'own ComprisSerializer,
+    error: RefCell<Option<ParseError>>,
+}
+
+impl<'own, EventsT, AnnotatedT> Transcoder<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn new(
+        events: EventsT,
+        mode: Option<&'own SerializationMode>,
+        hints: Option<&'own Hints>,
+        serializer: &'own ComprisSerializer,
+    ) -> Self {
+        Self { events: RefCell::new(events.peekable()), mode, hints, serializer, error: Default::default() }
+    }
+
+    fn serialize_next<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        let event = self.next()?;
+        self.serialize_event(event, serializer)
+    }
+
+    fn serialize_event<SerializerT>(
+        &self,
+        event: Event<AnnotatedT>,
+        serializer: SerializerT,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        match event {
+            Event::Key(variant) | Event::Scalar(variant) => self.serialize_variant(&variant, serializer),
+
+            Event::StartList { .. } => {
+                let mut seq = serializer.serialize_seq(None)?;
+                while !self.peek_end()? {
+                    seq.serialize_element(&TranscoderItem::new(self, TranscoderItemKind::Value))?;
+                }
+                self.next()?;
+                seq.end()
+            }
+
+            Event::StartMap { .. } => {
+                if self.peek_hint() {
+                    tracing::trace!("buffering map that might be a hint");
+                    let variant = self.buffer(event)?;
+                    return self.serialize_variant(&variant, serializer);
+                }
+
+                match self.mode.map(|mode| &mode.map) {
+                    None | Some(MapSerializationMode::AsMap) => {
+                        self.serialize_map(serializer, TranscoderItemKind::Value)
+                    }
+
+                    Some(MapSerializationMode::AsSeq(hint)) => match hint {
+                        Some(hint) => {
+                            tracing::trace!("map as seq wrapped in single-key map with key: {}", hint);
+                            let mut map = serializer.serialize_map(Some(1))?;
+                            map.serialize_entry(hint, &TranscoderItem::new(self, TranscoderItemKind::Entries))?;
+                            map.end()
+                        }
+
+                        None => {
+                            tracing::trace!("map as seq");
+                            self.serialize_entries(serializer)
+                        }
+                    },
+
+                    Some(MapSerializationMode::AsSeqIfNonTextKey(_)) => {
+                        // We can't know in advance whether there is a non-text key
+                        tracing::trace!("buffering map to check for non-text keys");
+                        let variant = self.buffer(event)?;
+                        self.serialize_variant(&variant, serializer)
+                    }
+
+                    Some(MapSerializationMode::SerializeKeys) => {
+                        self.serialize_map(serializer, TranscoderItemKind::StringifiedKey(true))
+                    }
+
+                    Some(MapSerializationMode::SerializeKeysIfNonText) => {
+                        self.serialize_map(serializer, TranscoderItemKind::StringifiedKey(false))
+                    }
+                }
+            }
+
+            Event::End => Err(self.fail(ParseError::Malformed("unexpected end of container".into()))),
+        }
+    }
+
+    fn serialize_map<SerializerT>(
+        &self,
+        serializer: SerializerT,
+        key_kind: TranscoderItemKind,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        while !self.peek_end()? {
+            map.serialize_key(&TranscoderItem::new(self, key_kind))?;
+            map.serialize_value(&TranscoderItem::new(self, TranscoderItemKind::Value))?;
+        }
+        self.next()?;
+        map.end()
+    }
+
+    fn serialize_entries<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        while !self.peek_end()? {
+            seq.serialize_element(&TranscoderItem::new(self, TranscoderItemKind::Entry))?;
+        }
+        self.next()?;
+        seq.end()
+    }
+
+    fn serialize_entry<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&TranscoderItem::new(self, TranscoderItemKind::Value))?;
+        tuple.serialize_element(&TranscoderItem::new(self, TranscoderItemKind::Value))?;
+        tuple.end()
+    }
+
+    fn serialize_stringified_key<SerializerT>(
+        &self,
+        serializer: SerializerT,
+        always: bool,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        let key = match self.next()? {
+            Event::Key(key) | Event::Scalar(key) => {
+                if !always && matches!(key, Variant::Text(_)) {
+                    return self.serialize_variant(&key, serializer);
+                }
+                key
+            }
+
+            event => {
+                tracing::trace!("buffering non-text key");
+                self.buffer(event)?
+            }
+        };
+
+        match self.serializer.clone().with_pretty(false).stringify(&key) {
+            Ok(key) => serializer.serialize_str(&key),
+            Err(error) => Err(SerializerT::Error::custom(error)),
+        }
+    }
+
+    fn serialize_variant<SerializerT>(
+        &self,
+        variant: &Variant<AnnotatedT>,
+        serializer: SerializerT,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        match self.mode {
+            Some(mode) => variant.modal(mode, self.serializer).serialize(serializer),
+            None => variant.serialize(serializer),
+        }
+    }
+
+    // Builds a variant from the event and (if it's a container) all events until its end
+    fn buffer<ErrorT>(&self, event: Event<AnnotatedT>) -> Result<Variant<AnnotatedT>, ErrorT>
+    where
+        ErrorT: Error,
+    {
+        let mut builder = VariantBuilder::new(None);
+        let mut depth = 0;
+        let mut event = event;
+
+        loop {
+            match event {
+                Event::StartMap { .. } | Event::StartList { .. } => depth += 1,
+                Event::End => depth -= 1,
+                _ => {}
+            }
+
+            builder.add_event(event, self.hints).map_err(|error| self.fail(error))?;
+
+            if depth == 0 {
+                return Ok(builder.finalize());
+            }
+
+            event = self.next()?;
+        }
+    }
+
+    fn next<ErrorT>(&self) -> Result<Event<AnnotatedT>, ErrorT>
+    where
+        ErrorT: Error,
+    {
+        match self.events.borrow_mut().next() {
+            Some(Ok(event)) => Ok(event),
+            Some(Err(error)) => Err(self.fail(error)),
+            None => Err(self.fail(ParseError::Malformed("unexpected end of events".into()))),
+        }
+    }
+
+    fn peek_end<ErrorT>(&self) -> Result<bool, ErrorT>
+    where
+        ErrorT: Error,
+    {
+        match self.events.borrow_mut().peek() {
+            Some(Ok(event)) => Ok(matches!(event, Event::End)),
+
+            // Errors and the end of events will be reported by next()
+            _ => Ok(false),
+        }
+    }
+
+    fn peek_hint(&self) -> bool {
+        match (self.hints, self.events.borrow_mut().peek()) {
+            (Some(hints), Some(Ok(Event::Key(Variant::Text(key))))) => hints.is_hint(key.as_str()),
+            _ => false,
+        }
+    }
+
+    fn fail<ErrorT>(&self, error: ParseError) -> ErrorT
+    where
+        ErrorT: Error,
+    {
+        let serialize_error = ErrorT::custom(&error);
+        self.error.replace(Some(error));
+        serialize_error
+    }
+}
+
+impl<'own, EventsT, AnnotatedT> Serialize for Transcoder<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn serialize<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        self.serialize_next(serializer)
+    }
+}
+
+//
+// TranscoderItem
+//
+
+struct TranscoderItem<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+{
+    transcoder: &'own Transcoder<'own, EventsT, AnnotatedT>,
+    kind: TranscoderItemKind,
+}
+
+impl<'own, EventsT, AnnotatedT> TranscoderItem<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+{
+    fn new(transcoder: &'own Transcoder<'own, EventsT, AnnotatedT>, kind: TranscoderItemKind) -> Self {
+        Self { transcoder, kind }
+    }
+}
+
+impl<'own, EventsT, AnnotatedT> Serialize for TranscoderItem<'own, EventsT, AnnotatedT>
+where
+    EventsT: Iterator<Item = Result<Event<AnnotatedT>, ParseError>>,
+    AnnotatedT: Annotated + Clone + Default,
+{
+    fn serialize<SerializerT>(&self, serializer: SerializerT) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        match self.kind {
+            TranscoderItemKind::Value => self.transcoder.serialize_next(serializer),
+            TranscoderItemKind::StringifiedKey(always) => self.transcoder.serialize_stringified_key(serializer, always),
+            TranscoderItemKind::Entry => self.transcoder.serialize_entry(serializer),
+            TranscoderItemKind::Entries => {
+                // The StartMap event has already been consumed
+                self.transcoder.serialize_entries(serializer)
+            }
+        }
+    }
+}
+
+//
+// TranscoderItemKind
+//
+
+#[derive(Clone, Copy)]
+enum TranscoderItemKind {
+    // The next value
+    Value,
+
+    // The next map key, stringified if it's not text (or always if true)
+    StringifiedKey(bool),
+
+    // The next map key and value as a 2-tuple
+    Entry,
+
+    // The remaining map entries as a sequence of 2-tuples
+    Entries,
+}
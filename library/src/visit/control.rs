@@ -0,0 +1,17 @@
+//
+// Visit
+//
+
+/// What to do after visiting a [Variant](super::super::normal::Variant).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Visit {
+    /// Continue into the children, if there are any.
+    #[default]
+    Continue,
+
+    /// Skip the children, but continue with the siblings.
+    SkipChildren,
+
+    /// Stop visiting. This is not an error.
+    Stop,
+}
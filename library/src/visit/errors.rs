@@ -0,0 +1,29 @@
+use super::super::{annotate::*, path::*};
+
+/// Gives the error the [Annotations] and the [PathRepresentation] if it doesn't already have
+/// [Annotations].
+pub(crate) fn annotate_error<ErrorT>(
+    error: ErrorT,
+    annotations: Option<&Annotations>,
+    path: &PathRepresentation,
+) -> ErrorT
+where
+    ErrorT: Annotated,
+{
+    if !ErrorT::can_have_annotations() || error.has_annotations() {
+        return error;
+    }
+
+    let mut error = match annotations {
+        Some(annotations) => error.with_annotations(annotations.clone()),
+        None => error,
+    };
+
+    if let Some(annotations) = error.annotations_mut()
+        && annotations.path.is_none()
+    {
+        annotations.path = Some(path.clone());
+    }
+
+    error
+}
@@ -0,0 +1,30 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    control::*,
+};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Fold this variant and its descendents in depth-first pre-order into an accumulated value.
+    ///
+    /// For map entries only the values are folded.
+    ///
+    /// Errors returned by the function abort the fold. If they don't already have [Annotations]
+    /// they will be given the [Annotations] of the folded variant and its [PathRepresentation].
+    pub fn fold<AccumulatorT, ErrorT, FoldT>(
+        &self,
+        accumulator: AccumulatorT,
+        mut fold: FoldT,
+    ) -> Result<AccumulatorT, ErrorT>
+    where
+        AnnotatedT: Annotated,
+        ErrorT: Annotated,
+        FoldT: FnMut(AccumulatorT, &Self, &PathRepresentation) -> Result<AccumulatorT, ErrorT>,
+    {
+        let mut accumulator = Some(accumulator);
+        self.visit(&mut |variant: &Self, path: &PathRepresentation| {
+            accumulator = Some(fold(accumulator.take().expect("accumulator"), variant, path)?);
+            Ok(Visit::Continue)
+        })?;
+        Ok(accumulator.expect("accumulator"))
+    }
+}
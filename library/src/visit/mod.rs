@@ -0,0 +1,10 @@
+mod control;
+mod errors;
+mod fold;
+mod transform;
+mod visitor;
+mod visitor_mut;
+mod walk;
+
+#[allow(unused_imports)]
+pub use {control::*, fold::*, transform::*, visitor::*, visitor_mut::*, walk::*};
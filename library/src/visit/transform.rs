@@ -0,0 +1,65 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    errors::*,
+};
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Transform this variant and its descendents in depth-first post-order, meaning that the
+    /// function receives a variant after its children have already been transformed.
+    ///
+    /// For map entries only the values are transformed.
+    ///
+    /// Errors returned by the function abort the transformation. If they don't already have
+    /// [Annotations] they will be given the [Annotations] of the transformed variant and its
+    /// [PathRepresentation].
+    pub fn transform<ErrorT, TransformT>(self, mut transform: TransformT) -> Result<Self, ErrorT>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+        ErrorT: Annotated,
+        TransformT: FnMut(Self, &PathRepresentation) -> Result<Self, ErrorT>,
+    {
+        self.transform_at(&mut transform, &mut Default::default())
+    }
+
+    fn transform_at<ErrorT, TransformT>(
+        self,
+        transform: &mut TransformT,
+        path: &mut PathRepresentation,
+    ) -> Result<Self, ErrorT>
+    where
+        AnnotatedT: Annotated + Clone + Default,
+        ErrorT: Annotated,
+        TransformT: FnMut(Self, &PathRepresentation) -> Result<Self, ErrorT>,
+    {
+        let variant = match self {
+            Self::List(list) => {
+                let mut new_list = List::new_with_capacity(list.inner.len()).with_annotations_from(&list);
+                for (index, item) in list.into_iter().enumerate() {
+                    path.push_list_index(index);
+                    let item = item.transform_at(transform, path);
+                    path.segments.pop();
+                    new_list.inner.push(item?);
+                }
+                new_list.into()
+            }
+
+            Self::Map(map) => {
+                let mut new_map = Map::default().with_annotations_from(&map);
+                for (key, value) in map.into_iter() {
+                    path.push_variant_key(&key);
+                    let value = value.transform_at(transform, path);
+                    path.segments.pop();
+                    new_map.inner.insert(key, value?);
+                }
+                new_map.into()
+            }
+
+            variant => variant,
+        };
+
+        // The variant is moved into the function, so we need to keep the annotations for errors
+        let annotations = if ErrorT::can_have_annotations() { variant.annotations().cloned() } else { None };
+
+        transform(variant, path).map_err(|error| annotate_error(error, annotations.as_ref(), path))
+    }
+}
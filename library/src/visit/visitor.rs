@@ -0,0 +1,104 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    control::*,
+    errors::*,
+};
+
+//
+// VariantVisitor
+//
+
+/// [Variant] visitor.
+///
+/// See [Variant::visit].
+pub trait VariantVisitor<AnnotatedT> {
+    /// Error.
+    type Error;
+
+    /// Called before visiting the children.
+    fn enter(&mut self, variant: &Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error>;
+
+    /// Called after visiting the children (or instead of them if they were skipped).
+    ///
+    /// [Visit::SkipChildren] is treated as [Visit::Continue].
+    #[allow(unused_variables)]
+    fn leave(&mut self, variant: &Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error> {
+        Ok(Visit::Continue)
+    }
+}
+
+impl<AnnotatedT, ErrorT, FunctionT> VariantVisitor<AnnotatedT> for FunctionT
+where
+    FunctionT: FnMut(&Variant<AnnotatedT>, &PathRepresentation) -> Result<Visit, ErrorT>,
+{
+    type Error = ErrorT;
+
+    fn enter(&mut self, variant: &Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error> {
+        self(variant, path)
+    }
+}
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Visit this variant and its descendents in depth-first order.
+    ///
+    /// For map entries only the values are visited.
+    ///
+    /// Errors returned by the visitor abort the visit. If they don't already have [Annotations]
+    /// they will be given the [Annotations] of the visited variant and its [PathRepresentation].
+    pub fn visit<VariantVisitorT>(&self, visitor: &mut VariantVisitorT) -> Result<(), VariantVisitorT::Error>
+    where
+        AnnotatedT: Annotated,
+        VariantVisitorT: VariantVisitor<AnnotatedT>,
+        VariantVisitorT::Error: Annotated,
+    {
+        self.visit_at(visitor, &mut Default::default()).map(|_| ())
+    }
+
+    fn visit_at<VariantVisitorT>(
+        &self,
+        visitor: &mut VariantVisitorT,
+        path: &mut PathRepresentation,
+    ) -> Result<Visit, VariantVisitorT::Error>
+    where
+        AnnotatedT: Annotated,
+        VariantVisitorT: VariantVisitor<AnnotatedT>,
+        VariantVisitorT::Error: Annotated,
+    {
+        match visitor.enter(self, path).map_err(|error| annotate_error(error, self.annotations(), path))? {
+            Visit::Stop => return Ok(Visit::Stop),
+
+            Visit::SkipChildren => {}
+
+            Visit::Continue => match self {
+                Self::List(list) => {
+                    for (index, item) in list.inner.iter().enumerate() {
+                        path.push_list_index(index);
+                        let visit = item.visit_at(visitor, path);
+                        path.segments.pop();
+                        if visit? == Visit::Stop {
+                            return Ok(Visit::Stop);
+                        }
+                    }
+                }
+
+                Self::Map(map) => {
+                    for (key, value) in map.inner.iter() {
+                        path.push_variant_key(key);
+                        let visit = value.visit_at(visitor, path);
+                        path.segments.pop();
+                        if visit? == Visit::Stop {
+                            return Ok(Visit::Stop);
+                        }
+                    }
+                }
+
+                _ => {}
+            },
+        }
+
+        match visitor.leave(self, path).map_err(|error| annotate_error(error, self.annotations(), path))? {
+            Visit::Stop => Ok(Visit::Stop),
+            _ => Ok(Visit::Continue),
+        }
+    }
+}
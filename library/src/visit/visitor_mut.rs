@@ -0,0 +1,112 @@
+use super::{
+    super::{annotate::*, normal::*, path::*},
+    control::*,
+    errors::*,
+};
+
+//
+// VariantVisitorMut
+//
+
+/// Mutable [Variant] visitor.
+///
+/// See [Variant::visit_mut].
+pub trait VariantVisitorMut<AnnotatedT> {
+    /// Error.
+    type Error;
+
+    /// Called before visiting the children.
+    ///
+    /// The variant may be modified or replaced (e.g. `*variant = replacement`), in which case the
+    /// children of the *new* variant will be visited.
+    fn enter(&mut self, variant: &mut Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error>;
+
+    /// Called after visiting the children (or instead of them if they were skipped).
+    ///
+    /// The variant may be modified or replaced. [Visit::SkipChildren] is treated as
+    /// [Visit::Continue].
+    #[allow(unused_variables)]
+    fn leave(&mut self, variant: &mut Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error> {
+        Ok(Visit::Continue)
+    }
+}
+
+impl<AnnotatedT, ErrorT, FunctionT> VariantVisitorMut<AnnotatedT> for FunctionT
+where
+    FunctionT: FnMut(&mut Variant<AnnotatedT>, &PathRepresentation) -> Result<Visit, ErrorT>,
+{
+    type Error = ErrorT;
+
+    fn enter(&mut self, variant: &mut Variant<AnnotatedT>, path: &PathRepresentation) -> Result<Visit, Self::Error> {
+        self(variant, path)
+    }
+}
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Visit this variant and its descendents in depth-first order, allowing the visitor to modify
+    /// or replace them.
+    ///
+    /// For map entries only the values are visited (keys cannot be modified in place).
+    ///
+    /// Errors returned by the visitor abort the visit. If they don't already have [Annotations]
+    /// they will be given the [Annotations] of the visited variant and its [PathRepresentation].
+    pub fn visit_mut<VariantVisitorMutT>(
+        &mut self,
+        visitor: &mut VariantVisitorMutT,
+    ) -> Result<(), VariantVisitorMutT::Error>
+    where
+        AnnotatedT: Annotated + Clone,
+        VariantVisitorMutT: VariantVisitorMut<AnnotatedT>,
+        VariantVisitorMutT::Error: Annotated,
+    {
+        self.visit_mut_at(visitor, &mut Default::default()).map(|_| ())
+    }
+
+    fn visit_mut_at<VariantVisitorMutT>(
+        &mut self,
+        visitor: &mut VariantVisitorMutT,
+        path: &mut PathRepresentation,
+    ) -> Result<Visit, VariantVisitorMutT::Error>
+    where
+        AnnotatedT: Annotated + Clone,
+        VariantVisitorMutT: VariantVisitorMut<AnnotatedT>,
+        VariantVisitorMutT::Error: Annotated,
+    {
+        match visitor.enter(self, path).map_err(|error| annotate_error(error, self.annotations(), path))? {
+            Visit::Stop => return Ok(Visit::Stop),
+
+            Visit::SkipChildren => {}
+
+            Visit::Continue => match self {
+                Self::List(list) => {
+                    for (index, item) in list.inner.iter_mut().enumerate() {
+                        path.push_list_index(index);
+                        let visit = item.visit_mut_at(visitor, path);
+                        path.segments.pop();
+                        if visit? == Visit::Stop {
+                            return Ok(Visit::Stop);
+                        }
+                    }
+                }
+
+                Self::Map(map) => {
+                    for (key, value) in map.inner.iter_mut() {
+                        path.push_variant_key(key);
+                        let visit = value.visit_mut_at(visitor, path);
+                        path.segments.pop();
+                        if visit? == Visit::Stop {
+                            return Ok(Visit::Stop);
+                        }
+                    }
+                }
+
+                _ => {}
+            },
+        }
+
+        match visitor.leave(self, path).map_err(|error| annotate_error(error, self.annotations(), path))? {
+            Visit::Stop => Ok(Visit::Stop),
+            _ => Ok(Visit::Continue),
+        }
+    }
+}
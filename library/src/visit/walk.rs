@@ -0,0 +1,135 @@
+use super::super::{normal::*, path::*};
+
+//
+// WalkOrder
+//
+
+/// Order for [Walk].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum WalkOrder {
+    /// Parents before their children.
+    #[default]
+    PreOrder,
+
+    /// Children before their parents.
+    PostOrder,
+}
+
+//
+// Walk
+//
+
+/// Depth-first [Iterator] over a [Variant] and all its descendents.
+///
+/// Items are the [PathRepresentation] from the root and the [Variant]. The root's path is empty.
+///
+/// See [Variant::walk].
+pub struct Walk<'own, AnnotatedT> {
+    /// Order.
+    pub order: WalkOrder,
+
+    /// Whether to include map keys.
+    ///
+    /// Note that keys are not marked as such: a key has the same path as its value and comes right
+    /// before it in pre-order (and right after it in post-order). Keys that are collections are
+    /// *not* descended into.
+    pub include_keys: bool,
+
+    // Path, variant, and whether it is expanded (or a key, which is never expanded)
+    stack: Vec<(PathRepresentation, &'own Variant<AnnotatedT>, bool)>,
+}
+
+impl<'own, AnnotatedT> Walk<'own, AnnotatedT> {
+    /// Constructor.
+    pub fn new(variant: &'own Variant<AnnotatedT>) -> Self {
+        Self { order: Default::default(), include_keys: false, stack: vec![(Default::default(), variant, false)] }
+    }
+
+    /// Set order.
+    pub fn with_order(mut self, order: WalkOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set whether to include map keys.
+    pub fn with_keys(mut self, include_keys: bool) -> Self {
+        self.include_keys = include_keys;
+        self
+    }
+
+    // Pushes the children in reverse so that they will be popped in order
+    fn push_children(&mut self, path: &PathRepresentation, variant: &'own Variant<AnnotatedT>) {
+        match variant {
+            Variant::List(list) => {
+                for (index, item) in list.inner.iter().enumerate().rev() {
+                    let mut path = path.clone();
+                    path.push_list_index(index);
+                    self.stack.push((path, item, false));
+                }
+            }
+
+            Variant::Map(map) => {
+                for (key, value) in map.inner.iter().rev() {
+                    let mut path = path.clone();
+                    path.push_variant_key(key);
+
+                    match self.order {
+                        WalkOrder::PreOrder => {
+                            self.stack.push((path.clone(), value, false));
+                            if self.include_keys {
+                                self.stack.push((path, key, true));
+                            }
+                        }
+
+                        WalkOrder::PostOrder => {
+                            if self.include_keys {
+                                self.stack.push((path.clone(), key, true));
+                            }
+                            self.stack.push((path, value, false));
+                        }
+                    }
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+impl<'own, AnnotatedT> Iterator for Walk<'own, AnnotatedT> {
+    type Item = (PathRepresentation, &'own Variant<AnnotatedT>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (path, variant, expanded) = self.stack.pop()?;
+
+            match self.order {
+                WalkOrder::PreOrder => {
+                    if !expanded {
+                        self.push_children(&path, variant);
+                    }
+                    return Some((path, variant));
+                }
+
+                WalkOrder::PostOrder => {
+                    if expanded || !variant.is_collection() {
+                        return Some((path, variant));
+                    }
+
+                    self.stack.push((path.clone(), variant, true));
+                    self.push_children(&path, variant);
+                }
+            }
+        }
+    }
+}
+
+impl<AnnotatedT> Variant<AnnotatedT> {
+    /// Depth-first [Iterator] over this variant and all its descendents, with their
+    /// [PathRepresentation].
+    ///
+    /// Defaults to [WalkOrder::PreOrder] without map keys.
+    pub fn walk(&self) -> Walk<'_, AnnotatedT> {
+        Walk::new(self)
+    }
+}
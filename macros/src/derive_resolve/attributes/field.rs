@@ -27,6 +27,22 @@ pub struct FieldAttribute {
     #[deluxe(default)]
     pub required: bool,
 
+    /// Whether this field is deprecated.
+    ///
+    /// Will report a (non-fatal) [DeprecatedKeyError] warning if the key is in the map.
+    ///
+    /// [DeprecatedKeyError]: https://docs.rs/compris/latest/compris/resolve/struct.DeprecatedKeyError.html
+    #[deluxe(default)]
+    pub deprecated: bool,
+
+    /// Deprecated key that can be used instead of [FieldAttribute::key].
+    ///
+    /// Will report a (non-fatal) [DeprecatedKeyError] warning if it is in the map. If both keys are
+    /// in the map then the value of the deprecated key is ignored.
+    ///
+    /// [DeprecatedKeyError]: https://docs.rs/compris/latest/compris/resolve/struct.DeprecatedKeyError.html
+    pub deprecated_key: Option<syn::Expr>,
+
     /// Whether to ignore null values instead of reporting an error.
     ///
     /// The field will simply stay at its default value.
@@ -67,6 +83,8 @@ impl FieldAttribute {
         if self.annotations {
             if self.key.is_some()
                 || self.required
+                || self.deprecated
+                || self.deprecated_key.is_some()
                 || self.ignore_null
                 || self.null.is_some()
                 || self.single
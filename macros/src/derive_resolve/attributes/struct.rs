@@ -25,4 +25,21 @@ pub struct StructAttribute {
     /// new parameter, "_AnnotatedT".
     #[deluxe(default)]
     pub annotated_parameter: Option<syn::Ident>,
+
+    /// If set will implement [ResolveWithContext] with this context type instead of [Resolve].
+    /// The context will be passed on when resolving the fields.
+    ///
+    /// [ResolveWithContext]: https://docs.rs/compris/latest/compris/resolve/trait.ResolveWithContext.html
+    /// [Resolve]: https://docs.rs/compris/latest/compris/resolve/trait.Resolve.html
+    #[deluxe(default)]
+    pub context: Option<syn::Type>,
+
+    /// Whether to also implement [ResolveRef] (or [ResolveWithContext] for a reference).
+    ///
+    /// Requires all the fields to be resolvable by reference.
+    ///
+    /// [ResolveRef]: https://docs.rs/compris/latest/compris/resolve/trait.ResolveRef.html
+    /// [ResolveWithContext]: https://docs.rs/compris/latest/compris/resolve/trait.ResolveWithContext.html
+    #[deluxe(default)]
+    pub by_reference: bool,
 }
@@ -24,6 +24,8 @@ pub struct VariantAttribute {
     /// Will try to resolve to just this variant first as a "single" notation.
     ///
     /// Can only be used on one variant.
+    ///
+    /// The variant is resolved by reference if its type supports it, otherwise from a clone.
     #[deluxe(default)]
     pub single: bool,
 }
@@ -0,0 +1,84 @@
+use super::generator::*;
+
+use {proc_macro2::*, quote::*};
+
+impl EnumGenerator {
+    /// Generate the implemented trait and the type for which it is implemented.
+    pub fn generate_resolve_trait(
+        &self,
+        resolved_type: &TokenStream,
+        annotated_parameter: &TokenStream,
+    ) -> (TokenStream, TokenStream) {
+        match &self.enum_attribute.context {
+            Some(context) => (
+                quote! {
+                    ::compris::resolve::ResolveWithContext<#resolved_type, #context, #annotated_parameter>
+                },
+                self.generate_ref_type(quote! { ::compris::normal::Variant<#annotated_parameter> }),
+            ),
+
+            None => (
+                if self.by_reference {
+                    quote! {
+                        ::compris::resolve::ResolveRef<#resolved_type, #annotated_parameter>
+                    }
+                } else {
+                    quote! {
+                        ::compris::resolve::Resolve<#resolved_type, #annotated_parameter>
+                    }
+                },
+                quote! { ::compris::normal::Variant<#annotated_parameter> },
+            ),
+        }
+    }
+
+    /// Generate the implemented function name, its self parameter, and its context parameter.
+    pub fn generate_resolve_function(&self) -> (TokenStream, TokenStream, TokenStream) {
+        match &self.enum_attribute.context {
+            Some(context) => (quote! { resolve_with_context }, quote! { self }, quote! { context: &#context, }),
+
+            None => {
+                if self.by_reference {
+                    (quote! { resolve_ref_with_errors }, quote! { &self }, Default::default())
+                } else {
+                    (quote! { resolve_with_errors }, quote! { self }, Default::default())
+                }
+            }
+        }
+    }
+
+    /// Generate resolving a value, passing on the context if we have one.
+    ///
+    /// The value can be a reference.
+    pub fn generate_resolve_value(&self, value: TokenStream, errors: TokenStream) -> TokenStream {
+        match &self.enum_attribute.context {
+            Some(_) => quote! {
+                ::compris::resolve::ResolveWithContext::resolve_with_context(#value, context, #errors)
+            },
+
+            None => quote! {
+                ::compris::resolve::Resolve::resolve_with_errors(#value, #errors)
+            },
+        }
+    }
+
+    /// Generate `impl ContextFree` (unless we have a context).
+    pub fn generate_impl_context_free(&self) -> TokenStream {
+        if self.enum_attribute.context.is_some() {
+            return Default::default();
+        }
+
+        let enum_name = &self.enum_name;
+        let (impl_generics, type_generics, where_clause) = self.enum_generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl
+                #impl_generics ::compris::resolve::ContextFree
+                for #enum_name #type_generics
+                #where_clause
+            {
+            }
+        }
+    }
+}
@@ -26,13 +26,23 @@ pub struct EnumGenerator {
 
     /// Human-readable variant key list.
     pub human_readable_key_list: String,
+
+    /// Whether we are generating the by-reference implementation.
+    pub by_reference: bool,
 }
 
 impl EnumGenerator {
     /// Generate.
     pub fn generate(input: &mut syn::DeriveInput) -> syn::Result<TokenStream> {
-        let generator = Self::new(input)?;
-        let stream = generator.generate_impl_resolve();
+        let mut generator = Self::new(input)?;
+        let mut stream = generator.generate_impl_resolve();
+
+        if generator.enum_attribute.by_reference {
+            generator.by_reference = true;
+            stream.extend(generator.generate_impl_resolve());
+        }
+
+        stream.extend(generator.generate_impl_context_free());
         Ok(stream)
     }
 
@@ -70,6 +80,7 @@ impl EnumGenerator {
 
                         let variant_attribute: VariantAttribute = extract_attributes(variant)?;
                         let variant_name = &variant.ident;
+                        let field_type = variant.fields.iter().next().map(|field| field.ty.to_token_stream());
 
                         let key = match &variant_attribute.key {
                             Some(key) => key.to_token_stream(),
@@ -86,7 +97,7 @@ impl EnumGenerator {
                                 generator.single_variant = Some(Variant {
                                     key: key.clone(),
                                     name: variant_name.to_token_stream(),
-                                    has_fields: !variant.fields.is_empty(),
+                                    field_type: field_type.clone(),
                                 });
                             }
                         }
@@ -99,7 +110,7 @@ impl EnumGenerator {
                         generator.select_variants.push(Variant {
                             key,
                             name: variant_name.to_token_stream(),
-                            has_fields: !variant.fields.is_empty(),
+                            field_type,
                         });
                     }
                 }
@@ -124,6 +135,6 @@ pub struct Variant {
     /// Variant name.
     pub name: TokenStream,
 
-    /// Whether the variant has fields.
-    pub has_fields: bool,
+    /// Type of the variant's field, if it has one.
+    pub field_type: Option<TokenStream>,
 }
@@ -7,12 +7,32 @@ impl EnumGenerator {
     pub fn generate_handle_single_variant(&self, variant: &Variant) -> TokenStream {
         let enum_name = &self.enum_name;
         let variant_name = &variant.name;
+        let field_type = variant.field_type.clone().unwrap_or(quote! { _ });
+        let annotated_parameter = self.annotated_parameter();
 
-        // TODO: is there any way we can avoid cloning self?
+        // We resolve a reference so that we can continue with self if it fails (we clone self only
+        // if the field type cannot be resolved by reference)
+        let self_ref = self.generate_ref(quote! { self });
+        let resolve_self = match &self.enum_attribute.context {
+            Some(_) => quote! {
+                {
+                    use ::compris::resolve::{ResolveWithContextByClone as _, ResolveWithContextByRef as _};
+                    (&::compris::resolve::ResolveRefOrClone::<_, #field_type, #annotated_parameter>::new(#self_ref))
+                        .resolve_with_context_ref_or_clone(context, &mut ::kutil::std::error::FailFastErrorRecipient)
+                }
+            },
+
+            None => quote! {
+                {
+                    use ::compris::resolve::{ResolveByClone as _, ResolveByRef as _};
+                    (&::compris::resolve::ResolveRefOrClone::<_, #field_type, #annotated_parameter>::new(#self_ref))
+                        .resolve_ref_or_clone(&mut ::kutil::std::error::FailFastErrorRecipient)
+                }
+            },
+        };
 
         quote! {
-            if let ::compris::resolve::ResolveResult::Ok(::std::option::Option::Some(resolved)) =
-                ::compris::resolve::Resolve::resolve_with_errors(self.clone(), &mut ::kutil::std::error::FailFastErrorRecipient)
+            if let ::compris::resolve::ResolveResult::Ok(::std::option::Option::Some(resolved)) = #resolve_self
             {
                 return ::compris::resolve::ResolveResult::Ok(
                     ::std::option::Option::Some(#enum_name::#variant_name(resolved))
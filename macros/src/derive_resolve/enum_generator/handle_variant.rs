@@ -9,10 +9,12 @@ impl EnumGenerator {
         let variant_name = &select_variant.name;
         let enum_name = &self.enum_name;
 
-        if select_variant.has_fields {
+        if select_variant.field_type.is_some() {
+            let resolve_value = self.generate_resolve_value(quote! { value }, quote! { errors });
+
             quote! {
                 #key =>
-                    ::compris::resolve::Resolve::resolve_with_errors(value, errors)?
+                    #resolve_value?
                     .map(|value| #enum_name::#variant_name(value)),
             }
         } else {
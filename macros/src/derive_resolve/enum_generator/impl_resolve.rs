@@ -23,28 +23,38 @@ impl EnumGenerator {
             segments.push(self.generate_handle_variant(select_variant));
         }
 
+        let (resolve_trait, resolvable_type) =
+            self.generate_resolve_trait(&quote! { #enum_name #type_generics }, &annotated_parameter);
+        let (resolve_function, self_parameter, context_parameter) = self.generate_resolve_function();
+        let self_ref = self.generate_ref(quote! { self });
+        let key_value_pair_function = if self.by_reference {
+            quote! { to_key_value_pair }
+        } else {
+            quote! { into_key_value_pair }
+        };
+
         quote! {
             #[automatically_derived]
             impl
                 #impl_generics
-                Resolve<#enum_name #type_generics, #annotated_parameter>
-                for ::compris::normal::Variant<#annotated_parameter>
+                #resolve_trait
+                for #resolvable_type
                 #where_clause
             {
-                fn resolve_with_errors<ErrorRecipientT>(self, errors: &mut ErrorRecipientT) ->
+                fn #resolve_function<ErrorRecipientT>(#self_parameter, #context_parameter errors: &mut ErrorRecipientT) ->
                     ::compris::resolve::ResolveResult<#enum_name #type_generics, #annotated_parameter>
                     where ErrorRecipientT:
                         ::kutil::std::error::ErrorRecipient<::compris::resolve::ResolveError<#annotated_parameter>>
                 {
-                    let maybe_annotations = ::compris::annotate::Annotated::maybe_annotations(&self);
+                    let maybe_annotations = ::compris::annotate::Annotated::maybe_annotations(#self_ref);
                     let type_name = self.type_name();
 
                     #handle_single_variant
 
                     ::compris::resolve::ResolveResult::Ok(
-                        match self.into_key_value_pair() {
+                        match self.#key_value_pair_function() {
                             ::std::option::Option::Some((key, value)) => match key {
-                                Self::Text(text) => match text.as_str() {
+                                ::compris::normal::Variant::Text(text) => match text.as_str() {
                                     #(#segments)*
 
                                     key => {
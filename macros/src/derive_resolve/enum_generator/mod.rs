@@ -1,7 +1,9 @@
+mod context;
 mod generator;
 mod generics;
 mod handle_single_variant;
 mod handle_variant;
 mod impl_resolve;
+mod reference;
 
 pub use generator::*;
@@ -0,0 +1,24 @@
+use super::generator::*;
+
+use {proc_macro2::*, quote::*};
+
+impl EnumGenerator {
+    /// Generate a type, as a reference if we are generating by reference.
+    pub fn generate_ref_type(&self, type_: TokenStream) -> TokenStream {
+        if self.by_reference {
+            quote! { &#type_ }
+        } else {
+            type_
+        }
+    }
+
+    /// Generate a reference to a variable, which is already a reference if we are generating by
+    /// reference.
+    pub fn generate_ref(&self, variable: TokenStream) -> TokenStream {
+        if self.by_reference {
+            variable
+        } else {
+            quote! { &#variable }
+        }
+    }
+}
@@ -32,15 +32,25 @@ pub struct StructGenerator {
 
     /// All keys used by all resolved fields.
     pub declared_keys: Vec<TokenStream>,
+
+    /// Whether we are generating the by-reference implementation.
+    pub by_reference: bool,
 }
 
 impl StructGenerator {
     /// Generate.
     pub fn generate(input: &mut syn::DeriveInput) -> syn::Result<TokenStream> {
-        let generator = Self::new(input)?;
+        let mut generator = Self::new(input)?;
 
         let mut stream = generator.generate_impl_resolve();
 
+        if generator.struct_attribute.by_reference {
+            generator.by_reference = true;
+            stream.extend(generator.generate_impl_resolve());
+        }
+
+        stream.extend(generator.generate_impl_context_free());
+
         if generator.annotations_field.is_some() {
             stream.extend(generator.generate_impl_annotated());
             // stream.extend(generator.generate_impl_citable_fields());
@@ -121,6 +131,11 @@ impl StructGenerator {
                             None => (&field_name.to_string()).to_token_stream(), // will add quotation marks
                         };
 
+                        // By reference the deprecated key stays in the map, so we must declare it
+                        if let Some(deprecated_key) = &field_attribute.deprecated_key {
+                            generator.declared_keys.push(deprecated_key.to_token_stream());
+                        }
+
                         generator.resolve_fields.push((
                             Field { name: field_name.to_token_stream(), attribute: field_attribute },
                             key.clone(),
@@ -10,12 +10,19 @@ impl StructGenerator {
         key: &TokenStream,
         annotated_parameter: &TokenStream,
     ) -> TokenStream {
+        let entry_function = if self.by_reference {
+            quote! { get_entry }
+        } else {
+            quote! { into_remove_entry }
+        };
+
         let handle_annotations = if let Some(annotations_field_name) = &self.annotations_field {
             let quoted_field_name = field.name.to_string().to_token_stream();
+            let value_ref = self.generate_ref(quote! { value });
             quote! {
                 if #annotated_parameter::can_have_annotations()
                     && let ::std::option::Option::Some(annotations) =
-                    ::compris::annotate::Annotated::annotations(&value)
+                    ::compris::annotate::Annotated::annotations(#value_ref)
                 {
                     resolved.#annotations_field_name.insert(
                         #quoted_field_name.into(),
@@ -27,7 +34,7 @@ impl StructGenerator {
             Default::default()
         };
 
-        let handle_null = Self::generate_handle_null(field, false);
+        let handle_null = self.generate_handle_null(field, false);
 
         let handle_required = if field.attribute.required {
             quote! {
@@ -45,15 +52,55 @@ impl StructGenerator {
             Default::default()
         };
 
+        let handle_deprecated_key = if let Some(deprecated_key) = &field.attribute.deprecated_key {
+            quote! {
+                if let ::std::option::Option::Some((deprecated_key, value)) = map.#entry_function(#deprecated_key) {
+                    ::compris::resolve::ResolveError::give_to(
+                        ::compris::resolve::DeprecatedKeyError::new(
+                            deprecated_key.clone(),
+                            ::std::option::Option::Some(key.into()),
+                        ).into(),
+                        errors,
+                    )?;
+
+                    if entry.is_none() {
+                        entry = ::std::option::Option::Some((deprecated_key, value));
+                    }
+                }
+            }
+        } else {
+            Default::default()
+        };
+
+        let (entry_key, handle_deprecated) = if field.attribute.deprecated {
+            let owned_entry_key = self.generate_owned(quote! { entry_key });
+
+            (
+                quote! { entry_key },
+                quote! {
+                    ::compris::resolve::ResolveError::give_to(
+                        ::compris::resolve::DeprecatedKeyError::new(#owned_entry_key, ::std::option::Option::None).into(),
+                        errors,
+                    )?;
+                },
+            )
+        } else {
+            (quote! { _ }, Default::default())
+        };
+
         let field_name = &field.name;
+        let resolve_value = self.generate_resolve_value(quote! { value }, quote! { errors });
 
         quote! {
             let key = #key;
-            if let ::std::option::Option::Some(value) = map.into_remove(key) {
+            #[allow(unused_mut)]
+            let mut entry = map.#entry_function(key);
+            #handle_deprecated_key
+            if let ::std::option::Option::Some((#entry_key, value)) = entry {
+                #handle_deprecated
                 #handle_annotations
                 #handle_null
-                if let ::std::option::Option::Some(value) =
-                    ::compris::resolve::Resolve::resolve_with_errors(value, errors)?
+                if let ::std::option::Option::Some(value) = #resolve_value?
                 {
                     resolved.#field_name = value;
                 }
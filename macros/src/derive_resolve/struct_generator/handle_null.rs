@@ -4,7 +4,7 @@ use {proc_macro2::*, quote::*};
 
 impl StructGenerator {
     /// Generate null handler.
-    pub fn generate_handle_null(field: &Field, insert: bool) -> TokenStream {
+    pub fn generate_handle_null(&self, field: &Field, insert: bool) -> TokenStream {
         if field.attribute.ignore_null {
             quote! {
                 if value.is_null() {} else
@@ -15,9 +15,11 @@ impl StructGenerator {
                     let field_name = &field.name;
 
                     if insert {
+                        let resolve_key = self.generate_resolve_value(quote! { key }, quote! { errors });
+
                         quote! {
                             if value.is_null() {
-                                if let Some(key) = ::compris::resolve::Resolve::resolve_with_errors(key, errors)? {
+                                if let Some(key) = #resolve_key? {
                                     resolved.#field_name.insert(key, #null);
                                 }
                             } else
@@ -5,20 +5,28 @@ use {proc_macro2::*, quote::*};
 impl StructGenerator {
     /// Generate other keys handler.
     pub fn generate_handle_other_keys(&self) -> TokenStream {
+        let map_inner = if self.by_reference {
+            quote! { &map.inner }
+        } else {
+            quote! { map.inner }
+        };
+        let key_ref = self.generate_ref(quote! { key });
         // Optimization possibility: a key that was resolved successfully in the field segments
         // above is already known to be known, we don't need to retest it here
 
         let handle_other_keys = match &self.other_keys_field {
             Some(other_keys_field) => {
                 let other_keys_field_name = &other_keys_field.name;
-                let handle_null = Self::generate_handle_null(other_keys_field, true);
+                let handle_null = self.generate_handle_null(other_keys_field, true);
+                let resolve_key = self.generate_resolve_value(quote! { key }, quote! { errors });
+                let resolve_value = self.generate_resolve_value(quote! { value }, quote! { errors });
 
                 quote! {
-                    for (key, value) in map.inner {
-                        if !declared_keys.contains(&key) {
+                    for (key, value) in #map_inner {
+                        if !declared_keys.contains(#key_ref) {
                             #handle_null
-                            if let Some(key) = ::compris::resolve::Resolve::resolve_with_errors(key, errors)?
-                                && let Some(value) = ::compris::resolve::Resolve::resolve_with_errors(value, errors)?
+                            if let Some(key) = #resolve_key?
+                                && let Some(value) = #resolve_value?
                             {
                                 resolved.#other_keys_field_name.insert(key, value);
                             }
@@ -28,13 +36,13 @@ impl StructGenerator {
             }
 
             None => quote! {
-                for (key, _) in map.inner {
-                    if !declared_keys.contains(&key) {
+                for (key, _) in #map_inner {
+                    if !declared_keys.contains(#key_ref) {
                         ::kutil::std::error::ErrorRecipient::give_error(
                             errors,
                             ::compris::annotate::Annotated::with_annotations_from(
                                 ::compris::resolve::InvalidKeyError::new(key.clone()).into(),
-                                &key,
+                                #key_ref,
                             )
                         )?;
                     }
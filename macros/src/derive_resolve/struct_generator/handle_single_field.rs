@@ -7,12 +7,10 @@ impl StructGenerator {
     pub fn generate_handle_single_field(&self) -> TokenStream {
         if let Some(single_field) = &self.single_field {
             let field_name = &single_field.name;
-
-            // TODO: is there any way we can avoid cloning self?
+            let resolve_self = self.generate_resolve_value(quote! { self }, quote! { errors });
 
             quote! {
-                if let ::std::option::Option::Some(value) =
-                    ::compris::resolve::Resolve::resolve_with_errors(self, errors)?
+                if let ::std::option::Option::Some(value) = #resolve_self?
                 {
                     resolved.#field_name = value;
                 }
@@ -22,11 +20,13 @@ impl StructGenerator {
                 );
             }
         } else {
+            let self_ref = self.generate_ref(quote! { self });
+
             quote! {
                 ::kutil::std::error::ErrorRecipient::give_error(
                     errors,
                     ::compris::normal::IncompatibleVariantTypeError::new_from(
-                        &self,
+                        #self_ref,
                         &["map"],
                     ).into(),
                 )?;
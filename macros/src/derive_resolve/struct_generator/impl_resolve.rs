@@ -6,6 +6,7 @@ impl StructGenerator {
     /// Generate `impl Resolve`.
     pub fn generate_impl_resolve(&self) -> TokenStream {
         let annotated_parameter = self.annotated_parameter();
+        let map_ref = self.generate_ref(quote! { map });
 
         let mut segments = Vec::default();
 
@@ -14,7 +15,7 @@ impl StructGenerator {
             segments.push(quote! {
                 if #annotated_parameter::can_have_annotations()
                     && let ::std::option::Option::Some(annotations) =
-                    ::compris::annotate::Annotated::annotations(&map)
+                    ::compris::annotate::Annotated::annotations(#map_ref)
                 {
                     resolved.#annotations_field_name.insert(
                         "".into(),
@@ -37,23 +38,33 @@ impl StructGenerator {
         let struct_name = &self.struct_name;
         let (impl_generics, type_generics, where_clause) = self.generics(&annotated_parameter);
 
+        let (resolve_trait, resolvable_type) =
+            self.generate_resolve_trait(&quote! { #struct_name #type_generics }, &annotated_parameter);
+        let (resolve_function, self_parameter, context_parameter) = self.generate_resolve_function();
+        let self_ref = self.generate_ref(quote! { self });
+        let map_mut = if self.by_reference {
+            Default::default()
+        } else {
+            quote! { mut }
+        };
+
         quote! {
             #[automatically_derived]
             impl
                 #impl_generics
-                Resolve<#struct_name #type_generics, #annotated_parameter>
-                for ::compris::normal::Variant<#annotated_parameter>
+                #resolve_trait
+                for #resolvable_type
                 #where_clause
             {
-                fn resolve_with_errors<ErrorRecipientT>(mut self, errors: &mut ErrorRecipientT) ->
+                fn #resolve_function<ErrorRecipientT>(#self_parameter, #context_parameter errors: &mut ErrorRecipientT) ->
                     ::compris::resolve::ResolveResult<#struct_name #type_generics, #annotated_parameter>
                     where ErrorRecipientT:
                         ::kutil::std::error::ErrorRecipient<::compris::resolve::ResolveError<#annotated_parameter>>
                 {
                     let mut resolved: #struct_name #type_generics = ::std::default::Default::default();
-                    let maybe_annotations = ::compris::annotate::Annotated::maybe_annotations(&self);
+                    let maybe_annotations = ::compris::annotate::Annotated::maybe_annotations(#self_ref);
 
-                    let mut map = match self {
+                    let #map_mut map = match self {
                         ::compris::normal::Variant::Map(map) => map,
                         _ => {
                             #single_field
@@ -1,3 +1,4 @@
+mod context;
 mod generator;
 mod generics;
 mod handle_field;
@@ -6,5 +7,6 @@ mod handle_other_keys;
 mod handle_single_field;
 mod impl_annotated;
 mod impl_resolve;
+mod reference;
 
 pub use generator::*;